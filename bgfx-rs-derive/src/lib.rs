@@ -0,0 +1,223 @@
+//! Derive macros for [bgfx-rs](https://crates.io/crates/bgfx-rs). Enable the `derive` feature of
+//! bgfx-rs to use them, rather than depending on this crate directly.
+
+use proc_macro2::TokenStream;
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields, Ident, LitInt, Result};
+
+/// Implements `bgfx::Vertex` for a `#[repr(C)]` struct. See the `Vertex` trait for the
+/// supported `#[attrib(...)]` options.
+#[proc_macro_derive(Vertex, attributes(attrib))]
+pub fn derive_vertex(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    derive(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+/// Options of a field from its `#[attrib(...)]` attribute.
+struct Attrib {
+    attrib: Ident,
+    ty: Option<Ident>,
+    num: Option<LitInt>,
+    normalized: bool,
+    as_int: bool,
+}
+
+fn parse_attrib(attr: &syn::Attribute) -> Result<Attrib> {
+    let mut attrib = None;
+    let mut ty = None;
+    let mut num = None;
+    let mut normalized = false;
+    let mut as_int = false;
+
+    attr.parse_nested_meta(|meta| {
+        if meta.path.is_ident("normalized") {
+            normalized = true;
+        } else if meta.path.is_ident("as_int") {
+            as_int = true;
+        } else if meta.path.is_ident("ty") {
+            ty = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("num") {
+            num = Some(meta.value()?.parse()?);
+        } else if let (None, Some(ident)) = (&attrib, meta.path.get_ident()) {
+            attrib = Some(ident.clone());
+        } else {
+            return Err(meta.error("expected `normalized`, `as_int`, `ty = ...` or `num = ...`"));
+        }
+        Ok(())
+    })?;
+
+    let attrib = attrib.ok_or_else(|| {
+        Error::new(
+            attr.span(),
+            "expected the attribute first, such as `#[attrib(Position)]`",
+        )
+    })?;
+    Ok(Attrib {
+        attrib,
+        ty,
+        num,
+        normalized,
+        as_int,
+    })
+}
+
+fn is_repr_c(input: &DeriveInput) -> Result<bool> {
+    let mut repr_c = false;
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("repr")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("C") || meta.path.is_ident("packed") {
+                repr_c = true;
+            }
+            if meta.input.peek(syn::token::Paren) {
+                // Skip arguments such as `packed(2)` or `align(4)`.
+                let content;
+                syn::parenthesized!(content in meta.input);
+                content.parse::<TokenStream>()?;
+            }
+            Ok(())
+        })?;
+    }
+    Ok(repr_c)
+}
+
+fn derive(input: &DeriveInput) -> Result<TokenStream> {
+    let name = &input.ident;
+
+    if !input.generics.params.is_empty() {
+        return Err(Error::new(
+            input.generics.span(),
+            "Vertex can't be derived for generic structs",
+        ));
+    }
+    if !is_repr_c(input)? {
+        return Err(Error::new(
+            name.span(),
+            "Vertex can only be derived for #[repr(C)] or #[repr(packed)] structs",
+        ));
+    }
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            Fields::Unnamed(fields) => &fields.unnamed,
+            Fields::Unit => {
+                return Err(Error::new(
+                    name.span(),
+                    "Vertex can't be derived for unit structs",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new(
+                name.span(),
+                "Vertex can only be derived for structs",
+            ))
+        }
+    };
+
+    let bgfx = quote!(::bgfx_rs::bgfx);
+    let mut adds = Vec::new();
+    let mut sizes = Vec::new();
+    let mut checks = Vec::new();
+    let field_tys: Vec<_> = fields.iter().map(|field| &field.ty).collect();
+
+    for field in fields {
+        let field_ty = &field.ty;
+        let span = field_ty.span();
+        let attrs: Vec<_> = field
+            .attrs
+            .iter()
+            .filter(|a| a.path().is_ident("attrib"))
+            .collect();
+
+        match attrs.as_slice() {
+            [] => {
+                adds.push(quote_spanned! {span=>
+                    .skip(::std::mem::size_of::<#field_ty>() as u8)
+                });
+                sizes.push(quote_spanned! {span=> ::std::mem::size_of::<#field_ty>() });
+            }
+            [attr] => {
+                let Attrib {
+                    attrib,
+                    ty,
+                    num,
+                    normalized,
+                    as_int,
+                } = parse_attrib(attr)?;
+                let type_r = match ty {
+                    Some(ty) => quote!(#bgfx::AttribType::#ty),
+                    None => quote_spanned!(span=> <#field_ty as #bgfx::VertexAttribute>::TYPE),
+                };
+                let num = match num {
+                    Some(num) => quote!(#num),
+                    None => quote_spanned!(span=> <#field_ty as #bgfx::VertexAttribute>::NUM),
+                };
+                adds.push(quote! {
+                    .add(
+                        #bgfx::Attrib::#attrib,
+                        #num,
+                        #type_r,
+                        #bgfx::AddArgs {
+                            normalized: #normalized,
+                            as_int: #as_int,
+                        },
+                    )
+                });
+                sizes.push(quote!(#bgfx::vertex_attrib_size(#type_r, #num)));
+                checks.push(quote_spanned! {span=>
+                    const _: () = assert!(
+                        !#bgfx::vertex_attrib_size_varies(#type_r, #num),
+                        "3 Int16 or Half elements are padded to 8 bytes on some renderers",
+                    );
+                    const _: () = assert!(
+                        ::std::mem::size_of::<#field_ty>()
+                            == #bgfx::vertex_attrib_size(#type_r, #num),
+                        "size of field doesn't match the size of its attribute",
+                    );
+                });
+            }
+            [_, attr, ..] => {
+                return Err(Error::new(attr.span(), "duplicate #[attrib] attribute"));
+            }
+        }
+    }
+
+    Ok(quote! {
+        impl #bgfx::Vertex for #name {
+            fn layout(renderer_type: #bgfx::RendererType) -> #bgfx::BuiltVertexLayout {
+                let layout = #bgfx::VertexLayoutBuilder::begin(renderer_type)
+                    #(#adds)*
+                    .end();
+                debug_assert_eq!(
+                    layout.stride() as usize,
+                    ::std::mem::size_of::<#name>(),
+                    "layout of vertex doesn't match its size for the renderer",
+                );
+                layout
+            }
+        }
+
+        // Any bit pattern is valid for the vertex, as it is for each of its fields.
+        unsafe impl #bgfx::Pod for #name {}
+
+        const _: fn() = || {
+            fn assert_pod<T: #bgfx::Pod>() {}
+            #(assert_pod::<#field_tys>();)*
+        };
+
+        #(#checks)*
+
+        // With the size of every field matching its attribute, this rejects padding between
+        // the fields.
+        const _: () = {
+            let stride = 0 #(+ #sizes)*;
+            assert!(
+                stride == ::std::mem::size_of::<#name>(),
+                "size of vertex doesn't match the stride of its layout",
+            );
+        };
+    })
+}
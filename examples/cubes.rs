@@ -1,9 +1,8 @@
 use bgfx::*;
 use bgfx_rs::bgfx;
 use glam::{EulerRot, Mat4, Vec3};
-use glfw::{Action, Key, Window};
-use raw_window_handle::{HasRawWindowHandle, RawWindowHandle};
-use std::{path::PathBuf, time::Instant};
+use glfw::{Action, Key};
+use std::time::Instant;
 
 const WIDTH: usize = 1280;
 const HEIGHT: usize = 720;
@@ -13,19 +12,19 @@ struct PosColorVertex {
     _x: f32,
     _y: f32,
     _z: f32,
-    _abgr: u32,
+    _color: Color,
 }
 
 #[rustfmt::skip]
 static CUBE_VERTICES: [PosColorVertex; 8] = [
-    PosColorVertex { _x: -1.0, _y:  1.0, _z:  1.0, _abgr: 0xff000000 },
-    PosColorVertex { _x:  1.0, _y:  1.0, _z:  1.0, _abgr: 0xff0000ff },
-    PosColorVertex { _x: -1.0, _y: -1.0, _z:  1.0, _abgr: 0xff00ff00 },
-    PosColorVertex { _x:  1.0, _y: -1.0, _z:  1.0, _abgr: 0xff00ffff },
-    PosColorVertex { _x: -1.0, _y:  1.0, _z: -1.0, _abgr: 0xffff0000 },
-    PosColorVertex { _x:  1.0, _y:  1.0, _z: -1.0, _abgr: 0xffff00ff },
-    PosColorVertex { _x: -1.0, _y: -1.0, _z: -1.0, _abgr: 0xffffff00 },
-    PosColorVertex { _x:  1.0, _y: -1.0, _z: -1.0, _abgr: 0xffffffff },
+    PosColorVertex { _x: -1.0, _y:  1.0, _z:  1.0, _color: Color::BLACK },
+    PosColorVertex { _x:  1.0, _y:  1.0, _z:  1.0, _color: Color::RED },
+    PosColorVertex { _x: -1.0, _y: -1.0, _z:  1.0, _color: Color::GREEN },
+    PosColorVertex { _x:  1.0, _y: -1.0, _z:  1.0, _color: Color::YELLOW },
+    PosColorVertex { _x: -1.0, _y:  1.0, _z: -1.0, _color: Color::BLUE },
+    PosColorVertex { _x:  1.0, _y:  1.0, _z: -1.0, _color: Color::MAGENTA },
+    PosColorVertex { _x: -1.0, _y: -1.0, _z: -1.0, _color: Color::CYAN },
+    PosColorVertex { _x:  1.0, _y: -1.0, _z: -1.0, _color: Color::WHITE },
 ];
 
 #[rustfmt::skip]
@@ -44,79 +43,6 @@ static CUBE_INDICES: [u16; 36] = [
     6, 3, 7,
 ];
 
-fn get_platform_data(window: &Window) -> PlatformData {
-    let mut pd = PlatformData::new();
-
-    match window.raw_window_handle() {
-        #[cfg(any(
-            target_os = "linux",
-            target_os = "dragonfly",
-            target_os = "freebsd",
-            target_os = "netbsd",
-            target_os = "openbsd"
-        ))]
-        RawWindowHandle::Xlib(data) => {
-            pd.nwh = data.window as *mut _;
-        }
-        #[cfg(any(
-            target_os = "linux",
-            target_os = "dragonfly",
-            target_os = "freebsd",
-            target_os = "netbsd",
-            target_os = "openbsd"
-        ))]
-        RawWindowHandle::Wayland(data) => {
-            pd.ndt = data.surface; // same as window, on wayland there ins't a concept of windows
-        }
-
-        #[cfg(target_os = "macos")]
-        RawWindowHandle::AppKit(data) => {
-            pd.nwh = data.ns_window;
-        }
-        #[cfg(target_os = "windows")]
-        RawWindowHandle::Win32(data) => {
-            pd.nwh = data.hwnd;
-        }
-        _ => panic!("Unsupported Window Manager"),
-    }
-
-    return pd;
-}
-
-fn load_shader_file(name: &str) -> std::io::Result<Vec<u8>> {
-    let mut path = PathBuf::with_capacity(512);
-    path.push("resources/examples/runtime/shaders");
-
-    match bgfx::get_renderer_type() {
-        RendererType::Direct3D11 => path.push("dx11"),
-        RendererType::OpenGL => path.push("glsl"),
-        RendererType::Metal => path.push("metal"),
-        RendererType::OpenGLES => path.push("essl"),
-        RendererType::Vulkan => path.push("spirv"),
-        e => panic!("Unsupported render type {:#?}", e),
-    }
-
-    path.push(format!("{}.bin", name));
-
-    let mut data = std::fs::read(path)?;
-    data.push(0); // this is to terminate the data
-    Ok(data)
-}
-
-// load shaders and create shader program
-fn load_shader_program(vs: &str, ps: &str) -> std::io::Result<Program> {
-    let vs_data = load_shader_file(vs)?;
-    let ps_data = load_shader_file(ps)?;
-
-    let vs_data = Memory::copy(&vs_data);
-    let ps_data = Memory::copy(&ps_data);
-
-    let vs_shader = bgfx::create_shader(&vs_data);
-    let ps_shader = bgfx::create_shader(&ps_data);
-
-    Ok(bgfx::create_program(&vs_shader, &ps_shader, false))
-}
-
 #[cfg(target_os = "linux")]
 fn get_render_type() -> RendererType {
     RendererType::OpenGL
@@ -127,7 +53,7 @@ fn get_render_type() -> RendererType {
     RendererType::Count
 }
 
-fn main() -> std::io::Result<()> {
+fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut glfw = glfw::init(glfw::FAIL_ON_ERRORS).unwrap();
     glfw.window_hint(glfw::WindowHint::ClientApi(glfw::ClientApiHint::NoApi));
 
@@ -147,19 +73,17 @@ fn main() -> std::io::Result<()> {
     init.type_r = get_render_type();
     init.resolution.width = WIDTH as u32;
     init.resolution.height = HEIGHT as u32;
-    init.resolution.reset = ResetFlags::VSYNC.bits();
-    init.platform_data = get_platform_data(&window);
+    init.resolution.reset = ResetFlags::VSYNC;
+    init.platform_data = PlatformData::from_window(&window).unwrap();
 
-    if !bgfx::init(&init) {
-        panic!("failed to init bgfx");
-    }
+    let mut ctx = Bgfx::init(&init).expect("failed to init bgfx");
 
-    bgfx::set_debug(DebugFlags::TEXT.bits());
-    bgfx::set_view_clear(
+    ctx.set_debug(DebugFlags::TEXT);
+    ctx.set_view_clear(
         0,
-        ClearFlags::COLOR.bits() | ClearFlags::DEPTH.bits(),
+        ClearFlags::COLOR | ClearFlags::DEPTH,
         SetViewClearArgs {
-            rgba: 0x103030ff,
+            rgba: Color::rgb(0x10, 0x30, 0x30),
             ..Default::default()
         },
     );
@@ -181,10 +105,11 @@ fn main() -> std::io::Result<()> {
         let verts_mem = unsafe { Memory::reference(&CUBE_VERTICES) };
         let index_mem = unsafe { Memory::reference(&CUBE_INDICES) };
 
-        let vbh = bgfx::create_vertex_buffer(&verts_mem, &layout, BufferFlags::NONE.bits());
-        let ibh = bgfx::create_index_buffer(&index_mem, BufferFlags::NONE.bits());
+        let vbh = ctx.create_vertex_buffer(&verts_mem, &layout, BufferFlags::NONE);
+        let ibh = ctx.create_index_buffer(&index_mem, BufferFlags::NONE);
 
-        let shader_program = load_shader_program("vs_cubes", "fs_cubes")?;
+        let shader_program = ShaderBundle::from_dir("resources/examples/runtime/shaders")
+            .create_program("vs_cubes", "fs_cubes")?;
 
         let state = (StateWriteFlags::R
             | StateWriteFlags::G
@@ -215,7 +140,7 @@ fn main() -> std::io::Result<()> {
             let size = window.get_framebuffer_size();
 
             if old_size != size {
-                bgfx::reset(size.0 as _, size.1 as _, ResetArgs::default());
+                ctx.reset(size.0 as _, size.1 as _, ResetArgs::default());
                 old_size = size;
             }
 
@@ -225,10 +150,10 @@ fn main() -> std::io::Result<()> {
                 Mat4::perspective_lh(60.0 * (std::f32::consts::PI / 180.0), aspect, 0.1, 100.0);
             let view = Mat4::look_at_lh(eye, at, up);
 
-            bgfx::set_view_rect(0, 0, 0, size.0 as _, size.1 as _);
-            bgfx::touch(0);
+            ctx.set_view_rect(0, 0, 0, size.0 as _, size.1 as _);
+            ctx.touch(0);
 
-            bgfx::set_view_transform(0, &view.to_cols_array(), &persp.to_cols_array());
+            ctx.set_view_transform(0, &view.to_cols_array(), &persp.to_cols_array());
 
             for yy in 0..11 {
                 for xx in 0..11 {
@@ -240,20 +165,18 @@ fn main() -> std::io::Result<()> {
                     let rot = Mat4::from_euler(EulerRot::XYZ, xr, yr, 0.0);
                     let transform = Mat4::from_translation(Vec3::new(x, y, 0.0)) * rot;
 
-                    bgfx::set_transform(&transform.to_cols_array(), 1);
-                    bgfx::set_vertex_buffer(0, &vbh, 0, std::u32::MAX);
-                    bgfx::set_index_buffer(&ibh, 0, std::u32::MAX);
+                    ctx.set_transform(&transform.to_cols_array(), 1);
+                    ctx.set_vertex_buffer(0, &vbh, 0, std::u32::MAX);
+                    ctx.set_index_buffer(&ibh, 0, std::u32::MAX);
 
-                    bgfx::set_state(state, 0);
-                    bgfx::submit(0, &shader_program, SubmitArgs::default());
+                    ctx.set_state(state, 0);
+                    ctx.submit(0, &shader_program, SubmitArgs::default());
                 }
             }
 
-            bgfx::frame(false);
+            ctx.frame(false);
         }
     }
 
-    bgfx::shutdown();
-
     Ok(())
 }
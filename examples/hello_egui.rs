@@ -0,0 +1,121 @@
+use bgfx::*;
+use bgfx_rs::bgfx;
+use winit::{
+    dpi::{LogicalSize, PhysicalSize},
+    event::{ElementState, Event, KeyboardInput, VirtualKeyCode, WindowEvent},
+    event_loop::EventLoop,
+    window::WindowBuilder,
+};
+
+const WIDTH: usize = 1280;
+const HEIGHT: usize = 720;
+
+#[cfg(target_os = "linux")]
+fn get_render_type() -> RendererType {
+    RendererType::OpenGL
+}
+
+#[cfg(not(target_os = "linux"))]
+fn get_render_type() -> RendererType {
+    RendererType::Count
+}
+
+fn main() {
+    let event_loop = EventLoop::new();
+
+    let window = WindowBuilder::new()
+        .with_title("Winit BGFX egui Example (Esc to exit)")
+        .with_inner_size(LogicalSize::new(WIDTH as f64, HEIGHT as f64))
+        .with_resizable(true)
+        .build(&event_loop)
+        .unwrap();
+
+    let mut init = Init::new();
+
+    init.type_r = get_render_type();
+    init.resolution.width = WIDTH as u32;
+    init.resolution.height = HEIGHT as u32;
+    init.resolution.reset = ResetFlags::VSYNC;
+    init.platform_data = PlatformData::from_window(&window).unwrap();
+
+    let mut ctx = Bgfx::init(&init).expect("failed to init bgfx");
+
+    ctx.set_view_clear(
+        0,
+        ClearFlags::COLOR | ClearFlags::DEPTH,
+        SetViewClearArgs {
+            rgba: Color::rgb(0x10, 0x30, 0x30),
+            ..Default::default()
+        },
+    );
+
+    let egui_ctx = egui::Context::default();
+    let mut egui_state = egui_winit::State::new(&event_loop);
+    egui_state.set_pixels_per_point(window.scale_factor() as f32);
+    let mut painter = EguiPainter::new(1).expect("failed to create egui painter");
+
+    let mut old_size = PhysicalSize::new(0, 0);
+    let mut clicks = 0;
+
+    event_loop.run(move |event, _, control_flow| {
+        control_flow.set_poll();
+
+        match event {
+            Event::WindowEvent { event, window_id } if window_id == window.id() => {
+                if egui_state.on_event(&egui_ctx, &event).consumed {
+                    return;
+                }
+                match event {
+                    WindowEvent::KeyboardInput {
+                        input:
+                            KeyboardInput {
+                                virtual_keycode: Some(VirtualKeyCode::Escape),
+                                state: ElementState::Pressed,
+                                ..
+                            },
+                        ..
+                    }
+                    | WindowEvent::CloseRequested => control_flow.set_exit(),
+                    _ => (),
+                }
+            }
+            Event::MainEventsCleared => {
+                let size = window.inner_size();
+
+                if old_size != size {
+                    ctx.reset(size.width as _, size.height as _, ResetArgs::default());
+                    old_size = size;
+                }
+
+                ctx.set_view_rect(0, 0, 0, size.width as _, size.height as _);
+                ctx.touch(0);
+
+                let raw_input = egui_state.take_egui_input(&window);
+                let output = egui_ctx.run(raw_input, |ctx| {
+                    egui::Window::new("Hello egui").show(ctx, |ui| {
+                        ui.label("egui drawn with bgfx-rs.");
+                        if ui.button("Click me").clicked() {
+                            clicks += 1;
+                        }
+                        ui.label(format!("Clicked {} times", clicks));
+                    });
+                });
+                egui_state.handle_platform_output(&window, &egui_ctx, output.platform_output);
+
+                let primitives = egui_ctx.tessellate(output.shapes);
+                let encoder = ctx.encoder().unwrap();
+                painter.paint(
+                    &encoder,
+                    &primitives,
+                    &output.textures_delta,
+                    [size.width, size.height],
+                    egui_ctx.pixels_per_point(),
+                );
+                drop(encoder);
+
+                ctx.frame(false);
+            }
+            _ => (),
+        }
+    });
+}
@@ -1,48 +1,6 @@
 use bgfx::*;
 use bgfx_rs::bgfx;
-use core::ffi::c_void;
-use glfw::{Action, Key, Window, WindowEvent};
-use raw_window_handle::{HasRawWindowHandle, RawWindowHandle};
-
-fn get_platform_data(window: &Window) -> PlatformData {
-    let mut pd = PlatformData::new();
-
-    match window.raw_window_handle() {
-        #[cfg(any(
-            target_os = "linux",
-            target_os = "dragonfly",
-            target_os = "freebsd",
-            target_os = "netbsd",
-            target_os = "openbsd"
-        ))]
-        RawWindowHandle::Xlib(data) => {
-            pd.nwh = data.window as *mut c_void;
-            pd.ndt = data.visual_id as *mut c_void;
-        }
-        #[cfg(any(
-            target_os = "linux",
-            target_os = "dragonfly",
-            target_os = "freebsd",
-            target_os = "netbsd",
-            target_os = "openbsd"
-        ))]
-        RawWindowHandle::Wayland(data) => {
-            pd.ndt = data.surface; // same as window, on wayland there ins't a concept of windows
-        }
-
-        #[cfg(target_os = "macos")]
-        RawWindowHandle::AppKit(data) => {
-            pd.nwh = data.ns_window;
-        }
-        #[cfg(target_os = "windows")]
-        RawWindowHandle::Win32(data) => {
-            pd.nwh = data.hwnd;
-        }
-        _ => panic!("Unsupported Window Manager"),
-    }
-
-    return pd;
-}
+use glfw::{Action, Key};
 
 fn get_render_type() -> RendererType {
     #[cfg(any(target_os = "linux", target_os = "windows"))]
@@ -78,12 +36,10 @@ fn main() {
     init.type_r = get_render_type();
     init.resolution.height = 0;
     init.resolution.width = 0;
-    init.resolution.reset = ResetFlags::VSYNC.bits(); // this makes the window recreation smoth
-    init.platform_data = get_platform_data(&window);
+    init.resolution.reset = ResetFlags::VSYNC; // this makes the window recreation smoth
+    init.platform_data = PlatformData::from_window(&window).unwrap();
 
-    if !bgfx::init(&init) {
-        panic!("failed to init bgfx");
-    }
+    let mut ctx = Bgfx::init(&init).expect("failed to init bgfx");
 
     {
         let windows = [window, window2];
@@ -104,26 +60,33 @@ fn main() {
                 let size = window.get_framebuffer_size();
 
                 if framebuffers[idx].is_none() || frame_sizes[idx] != size {
-                    framebuffers[idx] = Some(bgfx::create_frame_buffer_from_nwh(
-                        get_platform_data(&window).nwh as *mut c_void,
-                        size.0 as u16,
-                        size.1 as u16,
-                        CreateFrameBufferFromNwhArgs::default(),
-                    ));
+                    framebuffers[idx] = Some(
+                        FrameBuffer::from_window(
+                            window,
+                            size.0 as u16,
+                            size.1 as u16,
+                            CreateFrameBufferFromNwhArgs::default(),
+                        )
+                        .unwrap(),
+                    );
 
                     frame_sizes[idx] = size;
                 }
 
                 if let Some(frame_buffer) = &framebuffers[idx] {
-                    bgfx::set_view_frame_buffer(idx as _, &frame_buffer);
+                    ctx.set_view_frame_buffer(idx as _, &frame_buffer);
                 }
 
-                let color = if idx & 1 == 0 { 0x103030ff } else { 0x755413ff };
+                let color = if idx & 1 == 0 {
+                    Color::rgb(0x10, 0x30, 0x30)
+                } else {
+                    Color::rgb(0x75, 0x54, 0x13)
+                };
 
-                bgfx::set_view_rect(idx as _, 0, 0, size.0 as _, size.1 as _);
-                bgfx::set_view_clear(
+                ctx.set_view_rect(idx as _, 0, 0, size.0 as _, size.1 as _);
+                ctx.set_view_clear(
                     idx as _,
-                    ClearFlags::COLOR.bits() | ClearFlags::DEPTH.bits(),
+                    ClearFlags::COLOR | ClearFlags::DEPTH,
                     SetViewClearArgs {
                         rgba: color,
                         depth: 1.0,
@@ -131,12 +94,10 @@ fn main() {
                     },
                 );
 
-                bgfx::touch(idx as _);
+                ctx.touch(idx as _);
             }
 
-            bgfx::frame(false);
+            ctx.frame(false);
         }
     }
-
-    bgfx::shutdown();
 }
@@ -0,0 +1,177 @@
+use crate::interface::Interfaces;
+use std::alloc::{GlobalAlloc, Layout};
+use std::borrow::Cow;
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_void};
+
+/// Alignment bgfx expects when it passes an alignment of 0 (or anything smaller).
+const NATURAL_ALIGNMENT: usize = 8;
+
+/// Custom allocator used by bgfx for all internal allocations. Install it with
+/// [`Init::set_allocator`].
+///
+/// bgfx calls the allocator from both the API thread and the render thread, so implementations
+/// must be thread safe.
+///
+/// [`Init::set_allocator`]: crate::bgfx::Init::set_allocator
+pub trait Allocator: Send + Sync {
+    /// Allocates, resizes or frees a block of memory, following the semantics of `realloc`:
+    ///
+    /// * `ptr` is null and `size` is non-zero: allocate a new block.
+    /// * `ptr` is non-null and `size` is 0: free the block and return null.
+    /// * `ptr` is non-null and `size` is non-zero: resize the block, keeping its contents.
+    ///
+    /// `align` is the requested alignment, where 0 means natural alignment. `file_path` and
+    /// `line` identify the call site inside bgfx and can be used for memory accounting.
+    ///
+    /// # Safety
+    ///
+    /// `ptr` is either null or a block previously returned by this allocator that has not been
+    /// freed yet.
+    unsafe fn realloc(
+        &self,
+        ptr: *mut u8,
+        size: usize,
+        align: usize,
+        file_path: &str,
+        line: u32,
+    ) -> *mut u8;
+}
+
+/// [Allocator] that forwards to any [GlobalAlloc], such as [std::alloc::System] or a tracking
+/// allocator.
+///
+/// As bgfx doesn't pass the size of a block when freeing it, a small header holding the layout
+/// is stored in front of every allocation.
+#[derive(Clone, Debug, Default)]
+pub struct GlobalAllocAdapter<A: GlobalAlloc> {
+    alloc: A,
+}
+
+impl<A: GlobalAlloc> GlobalAllocAdapter<A> {
+    pub fn new(alloc: A) -> GlobalAllocAdapter<A> {
+        GlobalAllocAdapter { alloc }
+    }
+
+    /// Returns the wrapped allocator.
+    pub fn inner(&self) -> &A {
+        &self.alloc
+    }
+
+    /// Layout of a block of `size` bytes including the header, and the offset of the data.
+    fn layout(size: usize, align: usize) -> Option<(Layout, usize)> {
+        let align = align.max(NATURAL_ALIGNMENT).max(HEADER_SIZE);
+        let offset = align;
+        let layout = Layout::from_size_align(size.checked_add(offset)?, align).ok()?;
+        Some((layout, offset))
+    }
+
+    unsafe fn alloc(&self, size: usize, align: usize) -> *mut u8 {
+        let (layout, offset) = match Self::layout(size, align) {
+            Some(l) => l,
+            None => return std::ptr::null_mut(),
+        };
+        let block = self.alloc.alloc(layout);
+        if block.is_null() {
+            return block;
+        }
+        let ptr = block.add(offset);
+        (ptr as *mut Header).sub(1).write(Header {
+            size,
+            align: layout.align(),
+        });
+        ptr
+    }
+
+    unsafe fn free(&self, ptr: *mut u8) {
+        let header = (ptr as *mut Header).sub(1).read();
+        let block = ptr.sub(header.align);
+        self.alloc.dealloc(
+            block,
+            Layout::from_size_align_unchecked(header.size + header.align, header.align),
+        );
+    }
+}
+
+/// Stored right before every block handed out by [GlobalAllocAdapter].
+struct Header {
+    size: usize,
+    align: usize,
+}
+
+const HEADER_SIZE: usize = std::mem::size_of::<Header>();
+
+impl<A: GlobalAlloc + Send + Sync> Allocator for GlobalAllocAdapter<A> {
+    unsafe fn realloc(
+        &self,
+        ptr: *mut u8,
+        size: usize,
+        align: usize,
+        _file_path: &str,
+        _line: u32,
+    ) -> *mut u8 {
+        if ptr.is_null() {
+            if size == 0 {
+                return std::ptr::null_mut();
+            }
+            return self.alloc(size, align);
+        }
+
+        if size == 0 {
+            self.free(ptr);
+            return std::ptr::null_mut();
+        }
+
+        let new_ptr = self.alloc(size, align);
+        if !new_ptr.is_null() {
+            let old_size = (ptr as *mut Header).sub(1).read().size;
+            std::ptr::copy_nonoverlapping(ptr, new_ptr, old_size.min(size));
+            self.free(ptr);
+        }
+        new_ptr
+    }
+}
+
+/// Memory layout expected by bgfx for `bgfx_allocator_interface_t`, followed by the Rust
+/// allocator.
+#[repr(C)]
+pub(crate) struct AllocatorInterface {
+    vtbl: *const bgfx_sys::bgfx_allocator_vtbl_t,
+    allocator: Box<dyn Allocator>,
+}
+
+static ALLOCATOR_VTBL: bgfx_sys::bgfx_allocator_vtbl_t = bgfx_sys::bgfx_allocator_vtbl_t {
+    realloc: Some(realloc),
+};
+
+/// Allocator interfaces created by [into_raw], and the one bgfx was initialized with.
+pub(crate) static INTERFACES: Interfaces<AllocatorInterface> = Interfaces::new();
+
+/// Boxes `allocator` into an interface that can be stored in `Init::allocator`, holding one
+/// reference to it.
+pub(crate) fn into_raw(allocator: Box<dyn Allocator>) -> *const u8 {
+    INTERFACES.add(AllocatorInterface {
+        vtbl: &ALLOCATOR_VTBL,
+        allocator,
+    })
+}
+
+unsafe fn to_str<'a>(s: *const c_char) -> Cow<'a, str> {
+    if s.is_null() {
+        Cow::Borrowed("")
+    } else {
+        CStr::from_ptr(s).to_string_lossy()
+    }
+}
+
+unsafe extern "C" fn realloc(
+    this: *mut bgfx_sys::bgfx_allocator_interface_t,
+    ptr: *mut c_void,
+    size: usize,
+    align: usize,
+    file_path: *const c_char,
+    line: u32,
+) -> *mut c_void {
+    let allocator = &*(*(this as *const AllocatorInterface)).allocator;
+    allocator.realloc(ptr as *mut u8, size, align, &to_str(file_path), line) as *mut c_void
+}
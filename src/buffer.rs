@@ -0,0 +1,506 @@
+use crate::bgfx::{
+    alloc_instance_data_buffer, alloc_transient_buffers, alloc_transient_index_buffer,
+    alloc_transient_vertex_buffer, get_avail_instance_data_buffer,
+    get_avail_transient_index_buffer, get_avail_transient_vertex_buffer, get_caps,
+    get_renderer_type, BgfxError, BufferFlags, BuiltVertexLayout, CapsFlags, Encoder, Frame,
+    IndexBuffer, InstanceDataBuffer, Memory, TransientIndexBuffer, TransientVertexBuffer, Vertex,
+    VertexBuffer,
+};
+use std::marker::PhantomData;
+use std::ops::{Bound, Deref, RangeBounds};
+
+mod sealed {
+    pub trait Sealed {}
+    impl Sealed for u16 {}
+    impl Sealed for u32 {}
+}
+
+/// Plain data type that can be written directly into bgfx buffers, such as the slices returned
+/// by [Transient::as_mut_slice].
+///
+/// # Safety
+///
+/// Any bit pattern must be a valid value of the type, so it can't contain references, `bool`s
+/// or enums. `#[derive(Vertex)]` implements it for vertices made of such fields.
+pub unsafe trait Pod: Copy + 'static {}
+
+macro_rules! impl_pod {
+    ($($ty:ty),*) => {
+        $(unsafe impl Pod for $ty {})*
+    };
+}
+
+impl_pod!(u8, i8, u16, i16, u32, i32, u64, i64, f32, f64);
+
+unsafe impl<T: Pod, const N: usize> Pod for [T; N] {}
+
+/// Index type of a [TypedIndexBuffer], either `u16` or `u32`.
+pub trait Index: Pod + sealed::Sealed {
+    /// Buffer flags selecting the index width.
+    const FLAGS: BufferFlags;
+}
+
+impl Index for u16 {
+    const FLAGS: BufferFlags = BufferFlags::NONE;
+}
+
+impl Index for u32 {
+    const FLAGS: BufferFlags = BufferFlags::INDEX_32;
+}
+
+/// Returns the start and number of elements in `range` of a buffer with `len` elements.
+fn element_range<R: RangeBounds<u32>>(range: R, len: u32) -> (u32, u32) {
+    // Bounds are widened so that `..=u32::MAX` doesn't overflow.
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start as u64,
+        Bound::Excluded(&start) => start as u64 + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end as u64 + 1,
+        Bound::Excluded(&end) => end as u64,
+        Bound::Unbounded => len as u64,
+    };
+    assert!(
+        start <= end && end <= len as u64,
+        "range {}..{} out of range for buffer of length {}",
+        start,
+        end,
+        len
+    );
+    (start as u32, (end - start) as u32)
+}
+
+/// Vertex buffer holding vertices of type `V`, created with the layout from [Vertex::layout].
+/// The vertices are copied to bgfx byte by byte, so `V` must be [Pod].
+#[derive(Debug)]
+pub struct TypedVertexBuffer<V: Vertex + Pod> {
+    buffer: VertexBuffer,
+    len: u32,
+    _vertex: PhantomData<fn() -> V>,
+}
+
+impl<V: Vertex + Pod> TypedVertexBuffer<V> {
+    /// Creates a buffer holding a copy of `vertices`, with the layout of `V` for the current
+    /// renderer. See [VertexBuffer::create_vertex_buffer] for `flags`.
+    ///
+    /// Panics if the stride of the layout doesn't match the size of `V`.
+    pub fn create(vertices: &[V], flags: BufferFlags) -> TypedVertexBuffer<V> {
+        let layout = Self::layout();
+        TypedVertexBuffer {
+            buffer: VertexBuffer::create_vertex_buffer(&Memory::copy(vertices), &layout, flags),
+            len: vertices.len() as u32,
+            _vertex: PhantomData,
+        }
+    }
+
+    /// Fallible version of [TypedVertexBuffer::create].
+    pub fn try_create(
+        vertices: &[V],
+        flags: BufferFlags,
+    ) -> Result<TypedVertexBuffer<V>, BgfxError> {
+        let layout = Self::layout();
+        let buffer =
+            VertexBuffer::try_create_vertex_buffer(&Memory::copy(vertices), &layout, flags)?;
+        Ok(TypedVertexBuffer {
+            buffer,
+            len: vertices.len() as u32,
+            _vertex: PhantomData,
+        })
+    }
+
+    fn layout() -> BuiltVertexLayout {
+        let layout = V::layout(get_renderer_type());
+        assert_eq!(
+            layout.stride() as usize,
+            std::mem::size_of::<V>(),
+            "stride of vertex layout doesn't match the size of the vertex"
+        );
+        layout
+    }
+
+    /// Returns the number of vertices in the buffer.
+    pub fn len(&self) -> u32 {
+        self.len
+    }
+
+    /// Returns `true` if the buffer holds no vertices.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the untyped buffer.
+    pub fn buffer(&self) -> &VertexBuffer {
+        &self.buffer
+    }
+
+    /// Returns `false` if bgfx failed to create the buffer.
+    pub fn is_valid(&self) -> bool {
+        self.buffer.is_valid()
+    }
+}
+
+/// Index buffer holding indices of type `I`, either `u16` or `u32`.
+#[derive(Debug)]
+pub struct TypedIndexBuffer<I: Index> {
+    buffer: IndexBuffer,
+    len: u32,
+    _index: PhantomData<fn() -> I>,
+}
+
+impl<I: Index> TypedIndexBuffer<I> {
+    /// Creates a buffer holding a copy of `indices`. [BufferFlags::INDEX_32] is set from the
+    /// index type, and ignored in `flags`. See [IndexBuffer::create_index_buffer] for the other
+    /// flags.
+    pub fn create(indices: &[I], flags: BufferFlags) -> TypedIndexBuffer<I> {
+        TypedIndexBuffer {
+            buffer: IndexBuffer::create_index_buffer(&Memory::copy(indices), Self::flags(flags)),
+            len: indices.len() as u32,
+            _index: PhantomData,
+        }
+    }
+
+    /// Fallible version of [TypedIndexBuffer::create].
+    pub fn try_create(indices: &[I], flags: BufferFlags) -> Result<TypedIndexBuffer<I>, BgfxError> {
+        let buffer =
+            IndexBuffer::try_create_index_buffer(&Memory::copy(indices), Self::flags(flags))?;
+        Ok(TypedIndexBuffer {
+            buffer,
+            len: indices.len() as u32,
+            _index: PhantomData,
+        })
+    }
+
+    fn flags(flags: BufferFlags) -> BufferFlags {
+        (flags - BufferFlags::INDEX_32) | I::FLAGS
+    }
+
+    /// Returns the number of indices in the buffer.
+    pub fn len(&self) -> u32 {
+        self.len
+    }
+
+    /// Returns `true` if the buffer holds no indices.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the untyped buffer.
+    pub fn buffer(&self) -> &IndexBuffer {
+        &self.buffer
+    }
+
+    /// Returns `false` if bgfx failed to create the buffer.
+    pub fn is_valid(&self) -> bool {
+        self.buffer.is_valid()
+    }
+}
+
+impl Encoder {
+    /// Sets vertex buffer for draw primitive, drawing the vertices in `range`, such as `..` for
+    /// all of them.
+    ///
+    /// Panics if `range` is out of bounds of the buffer.
+    pub fn set_typed_vertex_buffer<V: Vertex + Pod, R: RangeBounds<u32>>(
+        &self,
+        stream: u8,
+        buffer: &TypedVertexBuffer<V>,
+        range: R,
+    ) {
+        let (start, num) = element_range(range, buffer.len);
+        self.set_vertex_buffer(stream, &buffer.buffer, start, num);
+    }
+
+    /// Sets index buffer for draw primitive, drawing the indices in `range`, such as `..` for
+    /// all of them.
+    ///
+    /// Panics if `range` is out of bounds of the buffer.
+    pub fn set_typed_index_buffer<I: Index, R: RangeBounds<u32>>(
+        &self,
+        buffer: &TypedIndexBuffer<I>,
+        range: R,
+    ) {
+        let (start, num) = element_range(range, buffer.len);
+        self.set_index_buffer(&buffer.buffer, start, num);
+    }
+}
+
+impl AsRef<VertexBuffer> for VertexBuffer {
+    fn as_ref(&self) -> &VertexBuffer {
+        self
+    }
+}
+
+impl<V: Vertex + Pod> AsRef<VertexBuffer> for TypedVertexBuffer<V> {
+    fn as_ref(&self) -> &VertexBuffer {
+        &self.buffer
+    }
+}
+
+impl AsRef<IndexBuffer> for IndexBuffer {
+    fn as_ref(&self) -> &IndexBuffer {
+        self
+    }
+}
+
+impl<I: Index> AsRef<IndexBuffer> for TypedIndexBuffer<I> {
+    fn as_ref(&self) -> &IndexBuffer {
+        &self.buffer
+    }
+}
+
+/// Transient buffer allocated for the current frame, such as by [TransientVertexBuffer::alloc].
+/// It borrows the [Frame], so the frame can't be advanced while the buffer is alive, and derefs
+/// to the buffer for setting it on draw calls.
+pub struct Transient<'frame, B> {
+    buffer: B,
+    _frame: PhantomData<&'frame Frame>,
+}
+
+impl<'frame, B> Transient<'frame, B> {
+    fn new(_frame: &'frame Frame, buffer: B) -> Transient<'frame, B> {
+        Transient {
+            buffer,
+            _frame: PhantomData,
+        }
+    }
+}
+
+impl<B> Deref for Transient<'_, B> {
+    type Target = B;
+
+    fn deref(&self) -> &B {
+        &self.buffer
+    }
+}
+
+/// Returns the buffer memory at `data` as a slice of `T`, checking that `T` fits `stride`.
+///
+/// # Safety
+///
+/// `data` must point to `size` writable bytes that are not otherwise referenced.
+unsafe fn buffer_slice<'a, T: Pod>(data: *const u8, size: u32, stride: usize) -> &'a mut [T] {
+    if size == 0 {
+        return &mut [];
+    }
+    assert_eq!(
+        std::mem::size_of::<T>(),
+        stride,
+        "size of element type doesn't match the stride of the buffer"
+    );
+    assert_eq!(
+        data as usize % std::mem::align_of::<T>(),
+        0,
+        "buffer data isn't aligned for the element type"
+    );
+    std::slice::from_raw_parts_mut(data as *mut T, size as usize / stride)
+}
+
+impl TransientVertexBuffer {
+    /// Allocates a transient vertex buffer for `num` vertices with `layout` in `frame`. Returns
+    /// `None` if there's not enough space left in the transient buffer this frame, or `num` is 0.
+    pub fn alloc<'frame>(
+        frame: &'frame Frame,
+        num: u32,
+        layout: &BuiltVertexLayout,
+    ) -> Option<Transient<'frame, TransientVertexBuffer>> {
+        if num == 0 || get_avail_transient_vertex_buffer(num, layout) < num {
+            return None;
+        }
+        let mut tvb = TransientVertexBuffer::new();
+        alloc_transient_vertex_buffer(&mut tvb, num, layout);
+        Some(Transient::new(frame, tvb))
+    }
+
+    /// Allocates a transient vertex buffer for `num` vertices with `layout` together with a
+    /// transient index buffer for `num_indices` indices in `frame`, or neither if they don't both
+    /// fit. See [TransientVertexBuffer::alloc] and [TransientIndexBuffer::alloc].
+    #[allow(clippy::type_complexity)]
+    pub fn alloc_with_indices<'frame>(
+        frame: &'frame Frame,
+        num: u32,
+        layout: &BuiltVertexLayout,
+        num_indices: u32,
+        index_32: bool,
+    ) -> Option<(
+        Transient<'frame, TransientVertexBuffer>,
+        Transient<'frame, TransientIndexBuffer>,
+    )> {
+        if num == 0
+            || num_indices == 0
+            || (index_32 && get_caps().supported & CapsFlags::INDEX_32.bits() == 0)
+        {
+            return None;
+        }
+        let mut tvb = TransientVertexBuffer::new();
+        let mut tib = TransientIndexBuffer::new();
+        if !alloc_transient_buffers(&mut tvb, layout, num, &mut tib, num_indices, index_32) {
+            return None;
+        }
+        Some((Transient::new(frame, tvb), Transient::new(frame, tib)))
+    }
+
+    /// Returns the number of vertices in the buffer.
+    pub fn len(&self) -> u32 {
+        self.size.checked_div(self.stride as u32).unwrap_or(0)
+    }
+
+    /// Returns `true` if the buffer holds no vertices.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Transient<'_, TransientVertexBuffer> {
+    /// Returns the vertices of the buffer to write to.
+    ///
+    /// Panics if the size of `V` doesn't match the stride of the buffer.
+    pub fn as_mut_slice<V: Pod>(&mut self) -> &mut [V] {
+        // The buffer was allocated this frame, and its fields can't be changed through the
+        // wrapper.
+        unsafe {
+            buffer_slice(
+                self.buffer.data,
+                self.buffer.size,
+                self.buffer.stride as usize,
+            )
+        }
+    }
+}
+
+impl TransientIndexBuffer {
+    /// Allocates a transient index buffer for `num` indices in `frame`. Returns `None` if
+    /// there's not enough space left in the transient buffer this frame, `num` is 0, or
+    /// `index_32` is set and 32-bit indices are not supported.
+    pub fn alloc(
+        frame: &Frame,
+        num: u32,
+        index_32: bool,
+    ) -> Option<Transient<'_, TransientIndexBuffer>> {
+        if num == 0
+            || get_avail_transient_index_buffer(num, index_32) < num
+            || (index_32 && get_caps().supported & CapsFlags::INDEX_32.bits() == 0)
+        {
+            return None;
+        }
+        let mut tib = TransientIndexBuffer::new();
+        alloc_transient_index_buffer(&mut tib, num, index_32);
+        Some(Transient::new(frame, tib))
+    }
+
+    fn index_size(&self) -> usize {
+        if self.is_index_16 {
+            2
+        } else {
+            4
+        }
+    }
+
+    /// Returns the number of indices in the buffer.
+    pub fn len(&self) -> u32 {
+        self.size / self.index_size() as u32
+    }
+
+    /// Returns `true` if the buffer holds no indices.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Transient<'_, TransientIndexBuffer> {
+    /// Returns the indices of the buffer to write to.
+    ///
+    /// Panics if `I` is not the index type the buffer was allocated with.
+    pub fn as_mut_slice<I: Index>(&mut self) -> &mut [I] {
+        // The buffer was allocated this frame, and its fields can't be changed through the
+        // wrapper.
+        unsafe { buffer_slice(self.buffer.data, self.buffer.size, self.buffer.index_size()) }
+    }
+}
+
+impl InstanceDataBuffer {
+    /// Allocates an instance data buffer for `num` instances of `stride` bytes in `frame`.
+    /// Returns `None` if there's not enough space left in the transient buffer this frame, `num`
+    /// is 0, or instancing is not supported.
+    ///
+    /// Panics if `stride` is not a multiple of 16.
+    pub fn alloc(
+        frame: &Frame,
+        num: u32,
+        stride: u16,
+    ) -> Option<Transient<'_, InstanceDataBuffer>> {
+        assert_eq!(stride % 16, 0, "instance stride must be a multiple of 16");
+        if num == 0
+            || get_caps().supported & CapsFlags::INSTANCING.bits() == 0
+            || get_avail_instance_data_buffer(num, stride) < num
+        {
+            return None;
+        }
+        let mut idb = InstanceDataBuffer::new();
+        alloc_instance_data_buffer(&mut idb, num, stride);
+        Some(Transient::new(frame, idb))
+    }
+
+    /// Returns the number of instances in the buffer.
+    pub fn len(&self) -> u32 {
+        self.num
+    }
+
+    /// Returns `true` if the buffer holds no instances.
+    pub fn is_empty(&self) -> bool {
+        self.num == 0
+    }
+}
+
+impl Transient<'_, InstanceDataBuffer> {
+    /// Returns the instances of the buffer to write to.
+    ///
+    /// Panics if the size of `T` doesn't match the stride of the buffer.
+    pub fn as_mut_slice<T: Pod>(&mut self) -> &mut [T] {
+        // The buffer was allocated this frame, and its fields can't be changed through the
+        // wrapper.
+        unsafe {
+            buffer_slice(
+                self.buffer.data,
+                self.buffer.size,
+                self.buffer.stride as usize,
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn element_ranges() {
+        assert_eq!(element_range(.., 10), (0, 10));
+        assert_eq!(element_range(2..5, 10), (2, 3));
+        assert_eq!(element_range(2..=5, 10), (2, 4));
+        assert_eq!(element_range(10.., 10), (10, 0));
+        assert_eq!(
+            element_range((Bound::Excluded(2), Bound::Unbounded), 10),
+            (3, 7)
+        );
+        assert_eq!(element_range(..=u32::MAX - 1, u32::MAX), (0, u32::MAX));
+    }
+
+    #[test]
+    #[should_panic(expected = "out of range")]
+    fn element_range_past_end() {
+        element_range(..=10, 10);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of range")]
+    fn element_range_inclusive_max() {
+        element_range(..=u32::MAX, u32::MAX);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of range")]
+    fn element_range_excluded_max() {
+        element_range((Bound::Excluded(u32::MAX), Bound::Unbounded), u32::MAX);
+    }
+}
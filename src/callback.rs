@@ -0,0 +1,256 @@
+use crate::bgfx::{Fatal, TextureFormat};
+use crate::interface::Interfaces;
+use core::ffi::c_void;
+use std::borrow::Cow;
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int};
+
+/// Application specific callback interface. Install it with [`Init::set_callback`].
+///
+/// bgfx can call these functions from both the API thread and the render thread, so
+/// implementations must be thread safe. Every function has a default implementation that
+/// matches the behaviour of bgfx's built-in callback stub, except that nothing is printed.
+///
+/// [`Init::set_callback`]: crate::bgfx::Init::set_callback
+pub trait Callback: Send + Sync {
+    /// Called when an unrecoverable error happens. `msg` describes the error.
+    ///
+    /// If this function returns for any `code` other than [Fatal::DebugCheck], bgfx behaviour
+    /// is undefined. The default implementation aborts the process in that case.
+    fn fatal(&self, code: Fatal, _msg: &str) {
+        if code != Fatal::DebugCheck {
+            std::process::abort();
+        }
+    }
+
+    /// Print debug message. `msg` has already been formatted by bgfx.
+    fn trace(&self, _file_path: &str, _line: u16, _msg: &str) {}
+
+    /// Profiler region begin. `abgr` is the color of the region.
+    fn profiler_begin(&self, _name: &str, _abgr: u32, _file_path: &str, _line: u16) {}
+
+    /// Profiler region end.
+    fn profiler_end(&self) {}
+
+    /// Returns the size of the cached item, or 0 if the item is not found.
+    fn cache_read_size(&self, _id: u64) -> u32 {
+        0
+    }
+
+    /// Reads a cached item into `data`, which is sized as reported by
+    /// [Callback::cache_read_size]. Returns `true` if the item was found.
+    fn cache_read(&self, _id: u64, _data: &mut [u8]) -> bool {
+        false
+    }
+
+    /// Writes an item to the cache.
+    fn cache_write(&self, _id: u64, _data: &[u8]) {}
+
+    /// Screenshot requested with `request_screen_shot`. `data` holds `height` rows of
+    /// `pitch` bytes each in BGRA8 format. When `yflip` is set the image is upside down.
+    fn screen_shot(
+        &self,
+        _file_path: &str,
+        _width: u32,
+        _height: u32,
+        _pitch: u32,
+        _data: &[u8],
+        _yflip: bool,
+    ) {
+    }
+
+    /// Called when a video capture begins.
+    fn capture_begin(
+        &self,
+        _width: u32,
+        _height: u32,
+        _pitch: u32,
+        _format: TextureFormat,
+        _yflip: bool,
+    ) {
+    }
+
+    /// Called when a video capture ends.
+    fn capture_end(&self) {}
+
+    /// Captured frame.
+    fn capture_frame(&self, _data: &[u8]) {}
+}
+
+/// Memory layout expected by bgfx for `bgfx_callback_interface_t`, followed by the Rust callback.
+#[repr(C)]
+pub(crate) struct CallbackInterface {
+    vtbl: *const bgfx_sys::bgfx_callback_vtbl_t,
+    callback: Box<dyn Callback>,
+}
+
+static CALLBACK_VTBL: bgfx_sys::bgfx_callback_vtbl_t = bgfx_sys::bgfx_callback_vtbl_t {
+    fatal: Some(fatal),
+    trace_vargs: Some(trace_vargs),
+    profiler_begin: Some(profiler_begin),
+    profiler_begin_literal: Some(profiler_begin),
+    profiler_end: Some(profiler_end),
+    cache_read_size: Some(cache_read_size),
+    cache_read: Some(cache_read),
+    cache_write: Some(cache_write),
+    screen_shot: Some(screen_shot),
+    capture_begin: Some(capture_begin),
+    capture_end: Some(capture_end),
+    capture_frame: Some(capture_frame),
+};
+
+/// Callback interfaces created by [into_raw], and the one bgfx was initialized with.
+pub(crate) static INTERFACES: Interfaces<CallbackInterface> = Interfaces::new();
+
+extern "C" {
+    fn vsnprintf(
+        s: *mut c_char,
+        n: usize,
+        format: *const c_char,
+        arg: *mut bgfx_sys::__va_list_tag,
+    ) -> c_int;
+}
+
+/// Boxes `callback` into an interface that can be stored in `Init::callback`, holding one
+/// reference to it.
+pub(crate) fn into_raw(callback: Box<dyn Callback>) -> *const u8 {
+    INTERFACES.add(CallbackInterface {
+        vtbl: &CALLBACK_VTBL,
+        callback,
+    })
+}
+
+unsafe fn get<'a>(this: *mut bgfx_sys::bgfx_callback_interface_t) -> &'a dyn Callback {
+    &*(*(this as *const CallbackInterface)).callback
+}
+
+unsafe fn to_str<'a>(s: *const c_char) -> Cow<'a, str> {
+    if s.is_null() {
+        Cow::Borrowed("")
+    } else {
+        CStr::from_ptr(s).to_string_lossy()
+    }
+}
+
+unsafe fn to_slice<'a>(data: *const c_void, size: u32) -> &'a [u8] {
+    if data.is_null() {
+        &[]
+    } else {
+        std::slice::from_raw_parts(data as *const u8, size as usize)
+    }
+}
+
+unsafe extern "C" fn fatal(
+    this: *mut bgfx_sys::bgfx_callback_interface_t,
+    _file_path: *const c_char,
+    _line: u16,
+    code: bgfx_sys::bgfx_fatal_t,
+    msg: *const c_char,
+) {
+    get(this).fatal(std::mem::transmute::<u32, Fatal>(code), &to_str(msg));
+}
+
+unsafe extern "C" fn trace_vargs(
+    this: *mut bgfx_sys::bgfx_callback_interface_t,
+    file_path: *const c_char,
+    line: u16,
+    format: *const c_char,
+    arg_list: *mut bgfx_sys::__va_list_tag,
+) {
+    let mut buffer = [0 as c_char; 2048];
+    vsnprintf(buffer.as_mut_ptr(), buffer.len(), format, arg_list);
+    get(this).trace(&to_str(file_path), line, &to_str(buffer.as_ptr()));
+}
+
+unsafe extern "C" fn profiler_begin(
+    this: *mut bgfx_sys::bgfx_callback_interface_t,
+    name: *const c_char,
+    abgr: u32,
+    file_path: *const c_char,
+    line: u16,
+) {
+    get(this).profiler_begin(&to_str(name), abgr, &to_str(file_path), line);
+}
+
+unsafe extern "C" fn profiler_end(this: *mut bgfx_sys::bgfx_callback_interface_t) {
+    get(this).profiler_end();
+}
+
+unsafe extern "C" fn cache_read_size(
+    this: *mut bgfx_sys::bgfx_callback_interface_t,
+    id: u64,
+) -> u32 {
+    get(this).cache_read_size(id)
+}
+
+unsafe extern "C" fn cache_read(
+    this: *mut bgfx_sys::bgfx_callback_interface_t,
+    id: u64,
+    data: *mut c_void,
+    size: u32,
+) -> bool {
+    if data.is_null() {
+        return false;
+    }
+    let data = std::slice::from_raw_parts_mut(data as *mut u8, size as usize);
+    get(this).cache_read(id, data)
+}
+
+unsafe extern "C" fn cache_write(
+    this: *mut bgfx_sys::bgfx_callback_interface_t,
+    id: u64,
+    data: *const c_void,
+    size: u32,
+) {
+    get(this).cache_write(id, to_slice(data, size));
+}
+
+#[allow(clippy::too_many_arguments)]
+unsafe extern "C" fn screen_shot(
+    this: *mut bgfx_sys::bgfx_callback_interface_t,
+    file_path: *const c_char,
+    width: u32,
+    height: u32,
+    pitch: u32,
+    data: *const c_void,
+    size: u32,
+    yflip: bool,
+) {
+    get(this).screen_shot(
+        &to_str(file_path),
+        width,
+        height,
+        pitch,
+        to_slice(data, size),
+        yflip,
+    );
+}
+
+unsafe extern "C" fn capture_begin(
+    this: *mut bgfx_sys::bgfx_callback_interface_t,
+    width: u32,
+    height: u32,
+    pitch: u32,
+    format: bgfx_sys::bgfx_texture_format_t,
+    yflip: bool,
+) {
+    get(this).capture_begin(
+        width,
+        height,
+        pitch,
+        std::mem::transmute::<u32, TextureFormat>(format),
+        yflip,
+    );
+}
+
+unsafe extern "C" fn capture_end(this: *mut bgfx_sys::bgfx_callback_interface_t) {
+    get(this).capture_end();
+}
+
+unsafe extern "C" fn capture_frame(
+    this: *mut bgfx_sys::bgfx_callback_interface_t,
+    data: *const c_void,
+    size: u32,
+) {
+    get(this).capture_frame(to_slice(data, size));
+}
@@ -0,0 +1,513 @@
+use crate::bgfx::{set_palette_color, AttribType, Pod, SetViewClearMrtArgs, VertexAttribute};
+use std::fmt;
+
+/// Converts an sRGB encoded channel from 0 to 1 to linear.
+pub fn srgb_to_linear(c: f32) -> f32 {
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Converts a linear channel from 0 to 1 to sRGB encoding.
+pub fn linear_to_srgb(c: f32) -> f32 {
+    if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    }
+}
+
+/// Converts a channel from 0 to 1 to 8 bits, clamping it to the range.
+fn to_u8(c: f32) -> u8 {
+    (c.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+/// Color with 8-bit RGBA channels.
+///
+/// bgfx takes colors packed as `0xRRGGBBAA` for clears and the palette, see
+/// [Color::from_rgba32], while vertex colors are usually packed as `0xAABBGGRR`, see
+/// [Color::from_abgr32]. A `Color` is laid out like the latter in memory, so it can be used
+/// as a normalized `Color0` field of a vertex:
+///
+/// ```ignore
+/// #[derive(Clone, Copy, Vertex)]
+/// #[repr(C, packed)]
+/// struct PosColorVertex {
+///     #[attrib(Position)]
+///     pos: [f32; 3],
+///     #[attrib(Color0, normalized)]
+///     color: Color,
+/// }
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug, Default)]
+#[repr(C)]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+impl Color {
+    pub const TRANSPARENT: Color = Color::new(0, 0, 0, 0);
+    pub const BLACK: Color = Color::rgb(0, 0, 0);
+    pub const WHITE: Color = Color::rgb(255, 255, 255);
+    pub const RED: Color = Color::rgb(255, 0, 0);
+    pub const GREEN: Color = Color::rgb(0, 255, 0);
+    pub const BLUE: Color = Color::rgb(0, 0, 255);
+    pub const YELLOW: Color = Color::rgb(255, 255, 0);
+    pub const CYAN: Color = Color::rgb(0, 255, 255);
+    pub const MAGENTA: Color = Color::rgb(255, 0, 255);
+
+    /// Creates a color from its channels.
+    pub const fn new(r: u8, g: u8, b: u8, a: u8) -> Color {
+        Color { r, g, b, a }
+    }
+
+    /// Creates an opaque color.
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Color {
+        Color::new(r, g, b, 255)
+    }
+
+    /// Returns the color with alpha `a`.
+    pub const fn with_alpha(self, a: u8) -> Color {
+        Color::new(self.r, self.g, self.b, a)
+    }
+
+    /// Creates a color from its channels `[r, g, b, a]`.
+    pub const fn from_rgba8(rgba: [u8; 4]) -> Color {
+        Color::new(rgba[0], rgba[1], rgba[2], rgba[3])
+    }
+
+    /// Returns the channels `[r, g, b, a]`.
+    pub const fn to_rgba8(self) -> [u8; 4] {
+        [self.r, self.g, self.b, self.a]
+    }
+
+    /// Creates a color packed as `0xRRGGBBAA`, as used for clears and the palette.
+    pub const fn from_rgba32(rgba: u32) -> Color {
+        Color::new(
+            (rgba >> 24) as u8,
+            (rgba >> 16) as u8,
+            (rgba >> 8) as u8,
+            rgba as u8,
+        )
+    }
+
+    /// Returns the color packed as `0xRRGGBBAA`, as used for clears and the palette.
+    pub const fn to_rgba32(self) -> u32 {
+        (self.r as u32) << 24 | (self.g as u32) << 16 | (self.b as u32) << 8 | self.a as u32
+    }
+
+    /// Creates a color packed as `0xAABBGGRR`, as used for vertex colors.
+    pub const fn from_abgr32(abgr: u32) -> Color {
+        Color::new(
+            abgr as u8,
+            (abgr >> 8) as u8,
+            (abgr >> 16) as u8,
+            (abgr >> 24) as u8,
+        )
+    }
+
+    /// Returns the color packed as `0xAABBGGRR`, as used for vertex colors.
+    pub const fn to_abgr32(self) -> u32 {
+        (self.a as u32) << 24 | (self.b as u32) << 16 | (self.g as u32) << 8 | self.r as u32
+    }
+
+    /// Creates a color from channels `[r, g, b, a]` from 0 to 1, clamping them to the range.
+    pub fn from_f32(rgba: [f32; 4]) -> Color {
+        Color::new(
+            to_u8(rgba[0]),
+            to_u8(rgba[1]),
+            to_u8(rgba[2]),
+            to_u8(rgba[3]),
+        )
+    }
+
+    /// Returns the channels `[r, g, b, a]` from 0 to 1.
+    pub fn to_f32(self) -> [f32; 4] {
+        self.to_rgba8().map(|c| c as f32 / 255.0)
+    }
+
+    /// Returns the channels `[r, g, b, a]` from 0 to 1 of an sRGB encoded color, with the
+    /// color converted to linear. Alpha is left as it is.
+    pub fn to_linear_f32(self) -> [f32; 4] {
+        let [r, g, b, a] = self.to_f32();
+        [srgb_to_linear(r), srgb_to_linear(g), srgb_to_linear(b), a]
+    }
+
+    /// Creates an sRGB encoded color from linear channels `[r, g, b, a]` from 0 to 1. Alpha is
+    /// left as it is.
+    pub fn from_linear_f32(rgba: [f32; 4]) -> Color {
+        let [r, g, b, a] = rgba;
+        Color::from_f32([linear_to_srgb(r), linear_to_srgb(g), linear_to_srgb(b), a])
+    }
+
+    /// Converts an sRGB encoded color to linear. Dark colors lose precision in 8 bits, use
+    /// [Color::to_linear_f32] to keep it.
+    pub fn to_linear(self) -> Color {
+        Color::from_f32(self.to_linear_f32())
+    }
+
+    /// Converts a linear color to sRGB encoding.
+    pub fn to_srgb(self) -> Color {
+        Color::from_linear_f32(self.to_f32())
+    }
+}
+
+/// A color packed as `0xRRGGBBAA`.
+impl From<u32> for Color {
+    fn from(rgba: u32) -> Color {
+        Color::from_rgba32(rgba)
+    }
+}
+
+/// The color packed as `0xRRGGBBAA`.
+impl From<Color> for u32 {
+    fn from(color: Color) -> u32 {
+        color.to_rgba32()
+    }
+}
+
+impl From<[u8; 4]> for Color {
+    fn from(rgba: [u8; 4]) -> Color {
+        Color::from_rgba8(rgba)
+    }
+}
+
+impl From<[f32; 4]> for Color {
+    fn from(rgba: [f32; 4]) -> Color {
+        Color::from_f32(rgba)
+    }
+}
+
+impl From<Color> for [f32; 4] {
+    fn from(color: Color) -> [f32; 4] {
+        color.to_f32()
+    }
+}
+
+impl fmt::Display for Color {
+    /// Formats the color as `#rrggbbaa`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{:08x}", self.to_rgba32())
+    }
+}
+
+unsafe impl Pod for Color {}
+
+impl VertexAttribute for Color {
+    const TYPE: AttribType = AttribType::Uint8;
+    const NUM: u8 = 4;
+}
+
+/// Colors set in the palette of bgfx, which frame buffer attachments are cleared with by
+/// [set_view_clear_mrt](crate::bgfx::set_view_clear_mrt).
+///
+/// Keeps track of the used indices so that colors can be added without picking them by hand:
+///
+/// ```ignore
+/// let mut palette = Palette::new();
+/// let args = palette.clear_mrt_args(&[Color::BLACK, Color::TRANSPARENT]).unwrap();
+/// bgfx::set_view_clear_mrt(0, ClearFlags::COLOR | ClearFlags::DEPTH, 1.0, 0, args);
+/// ```
+///
+/// bgfx has a single palette, so a program should only use one `Palette`, or set indices that
+/// don't overlap.
+#[derive(Clone, Debug, Default)]
+pub struct Palette {
+    colors: [Option<Color>; Palette::SIZE as usize],
+}
+
+impl Palette {
+    /// Number of colors in the palette.
+    pub const SIZE: u8 = 16;
+
+    /// Creates a palette without any colors set.
+    pub fn new() -> Palette {
+        Palette::default()
+    }
+
+    /// Sets the color at `index` with [set_palette_color].
+    ///
+    /// Panics if `index` is not less than [Palette::SIZE].
+    pub fn set(&mut self, index: u8, color: Color) {
+        self.set_with(index, color, &mut set_palette_color);
+    }
+
+    /// Records the color at `index` and passes it to `set_color`, which sets it in bgfx.
+    fn set_with(&mut self, index: u8, color: Color, set_color: &mut impl FnMut(u8, Color)) {
+        assert!(index < Palette::SIZE, "palette index must be less than 16");
+        self.colors[index as usize] = Some(color);
+        set_color(index, color);
+    }
+
+    /// Returns the color at `index`, or `None` if it isn't set.
+    pub fn get(&self, index: u8) -> Option<Color> {
+        self.colors.get(index as usize).copied().flatten()
+    }
+
+    /// Returns the first index with `color`.
+    pub fn index_of(&self, color: Color) -> Option<u8> {
+        self.colors
+            .iter()
+            .position(|c| *c == Some(color))
+            .map(|index| index as u8)
+    }
+
+    /// Returns the index of `color`, setting it at the first free index if it isn't in the
+    /// palette yet. Returns `None` if the palette is full.
+    pub fn add(&mut self, color: Color) -> Option<u8> {
+        self.add_with(color, &mut set_palette_color)
+    }
+
+    fn add_with(&mut self, color: Color, set_color: &mut impl FnMut(u8, Color)) -> Option<u8> {
+        if let Some(index) = self.index_of(color) {
+            return Some(index);
+        }
+        let index = self.colors.iter().position(Option::is_none)? as u8;
+        self.set_with(index, color, set_color);
+        Some(index)
+    }
+
+    /// Frees `index` for [Palette::add]. bgfx keeps the color until the index is set again.
+    pub fn remove(&mut self, index: u8) {
+        if let Some(color) = self.colors.get_mut(index as usize) {
+            *color = None;
+        }
+    }
+
+    /// Adds `colors` with [Palette::add] and returns the arguments to clear frame buffer
+    /// attachments 0 and up with them. Returns `None` without adding any of them if they don't
+    /// all fit in the palette.
+    ///
+    /// Panics if there are more than 8 colors.
+    pub fn clear_mrt_args(&mut self, colors: &[Color]) -> Option<SetViewClearMrtArgs> {
+        self.clear_mrt_args_with(colors, &mut set_palette_color)
+    }
+
+    fn clear_mrt_args_with(
+        &mut self,
+        colors: &[Color],
+        set_color: &mut impl FnMut(u8, Color),
+    ) -> Option<SetViewClearMrtArgs> {
+        assert!(
+            colors.len() <= 8,
+            "frame buffers have at most 8 attachments"
+        );
+        let missing = colors
+            .iter()
+            .enumerate()
+            .filter(|&(i, color)| self.index_of(*color).is_none() && !colors[..i].contains(color))
+            .count();
+        let free = self.colors.iter().filter(|c| c.is_none()).count();
+        if missing > free {
+            return None;
+        }
+
+        let mut indices = [u8::MAX; 8];
+        for (index, color) in indices.iter_mut().zip(colors) {
+            *index = self.add_with(*color, set_color)?;
+        }
+        Some(SetViewClearMrtArgs {
+            c_0: indices[0],
+            c_1: indices[1],
+            c_2: indices[2],
+            c_3: indices[3],
+            c_4: indices[4],
+            c_5: indices[5],
+            c_6: indices[6],
+            c_7: indices[7],
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[should_panic(expected = "palette index must be less than 16")]
+    fn set_palette_color_out_of_range() {
+        // Checked before bgfx is called, so bgfx doesn't need to be initialized.
+        set_palette_color(Palette::SIZE, Color::default());
+    }
+
+    fn assert_near(actual: [f32; 4], expected: [f32; 4]) {
+        for (a, e) in actual.iter().zip(&expected) {
+            assert!((a - e).abs() < 1.0e-6, "{:?} != {:?}", actual, expected);
+        }
+    }
+
+    #[test]
+    fn packed() {
+        let color = Color::new(0x12, 0x34, 0x56, 0x78);
+        assert_eq!(color.to_rgba32(), 0x12345678);
+        assert_eq!(color.to_abgr32(), 0x78563412);
+        assert_eq!(Color::from_rgba32(0x12345678), color);
+        assert_eq!(Color::from_abgr32(0x78563412), color);
+        assert_eq!(Color::from(0x12345678), color);
+        assert_eq!(u32::from(color), 0x12345678);
+        assert_eq!(Color::from_rgba8(color.to_rgba8()), color);
+        assert_eq!(Color::RED.to_rgba32(), 0xff0000ff);
+        assert_eq!(Color::RED.to_abgr32(), 0xff0000ff);
+        assert_eq!(Color::BLUE.to_abgr32(), 0xffff0000);
+    }
+
+    #[test]
+    fn layout_matches_abgr() {
+        let color = Color::new(0x12, 0x34, 0x56, 0x78);
+        let bytes: [u8; 4] = unsafe { std::mem::transmute(color) };
+        assert_eq!(bytes, color.to_abgr32().to_le_bytes());
+        assert_eq!(std::mem::size_of::<Color>(), 4);
+        assert_eq!(std::mem::align_of::<Color>(), 1);
+    }
+
+    #[test]
+    fn from_f32_clamps() {
+        assert_eq!(
+            Color::from_f32([-1.0, 0.5, 2.0, 1.0]),
+            Color::new(0, 128, 255, 255)
+        );
+        assert_eq!(
+            Color::from_f32([0.0, 1.0 / 255.0, 0.998, f32::INFINITY]).a,
+            255
+        );
+        assert_near(Color::new(0, 51, 255, 255).to_f32(), [0.0, 0.2, 1.0, 1.0]);
+        assert_eq!(
+            Color::from([0.0, 0.2, 1.0, 1.0]),
+            Color::new(0, 51, 255, 255)
+        );
+    }
+
+    #[test]
+    fn srgb_linear_round_trip() {
+        for &c in &[0.0, 0.5, 1.0] {
+            assert!((linear_to_srgb(srgb_to_linear(c)) - c).abs() < 1.0e-6);
+            assert!((srgb_to_linear(linear_to_srgb(c)) - c).abs() < 1.0e-6);
+        }
+        assert!((srgb_to_linear(0.5) - 0.214_041).abs() < 1.0e-5);
+        assert!((linear_to_srgb(0.5) - 0.735_357).abs() < 1.0e-5);
+
+        let color = Color::new(0, 128, 255, 77);
+        assert_eq!(color.to_linear().to_srgb(), color);
+        assert_eq!(color.to_linear().a, 77);
+        assert_near(
+            Color::from_linear_f32(color.to_linear_f32()).to_f32(),
+            color.to_f32(),
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(Color::new(0x12, 0x34, 0xab, 0xff).to_string(), "#1234abff");
+        assert_eq!(Color::TRANSPARENT.to_string(), "#00000000");
+    }
+
+    /// Calls of the palette to set a color in bgfx.
+    #[derive(Default)]
+    struct SetColors(Vec<(u8, Color)>);
+
+    impl SetColors {
+        fn record(&mut self) -> impl FnMut(u8, Color) + '_ {
+            move |index, color| self.0.push((index, color))
+        }
+    }
+
+    #[test]
+    fn palette_add_and_remove() {
+        let mut palette = Palette::new();
+        let mut set = SetColors::default();
+        assert_eq!(palette.add_with(Color::RED, &mut set.record()), Some(0));
+        assert_eq!(palette.add_with(Color::GREEN, &mut set.record()), Some(1));
+        // Colors already in the palette aren't set again.
+        assert_eq!(palette.add_with(Color::RED, &mut set.record()), Some(0));
+        assert_eq!(set.0, vec![(0, Color::RED), (1, Color::GREEN)]);
+
+        assert_eq!(palette.index_of(Color::GREEN), Some(1));
+        assert_eq!(palette.index_of(Color::BLUE), None);
+        assert_eq!(palette.get(1), Some(Color::GREEN));
+
+        palette.remove(0);
+        palette.remove(Palette::SIZE);
+        assert_eq!(palette.get(0), None);
+        assert_eq!(palette.index_of(Color::RED), None);
+        assert_eq!(palette.add_with(Color::BLUE, &mut set.record()), Some(0));
+        assert_eq!(set.0.last(), Some(&(0, Color::BLUE)));
+
+        palette.set_with(5, Color::RED, &mut set.record());
+        assert_eq!(palette.add_with(Color::RED, &mut set.record()), Some(5));
+    }
+
+    #[test]
+    fn palette_full() {
+        let mut palette = Palette::new();
+        let mut set = SetColors::default();
+        for i in 0..Palette::SIZE {
+            assert_eq!(
+                palette.add_with(Color::rgb(i, 0, 0), &mut set.record()),
+                Some(i)
+            );
+        }
+        assert_eq!(palette.add_with(Color::WHITE, &mut set.record()), None);
+        assert_eq!(
+            palette.add_with(Color::rgb(3, 0, 0), &mut set.record()),
+            Some(3)
+        );
+        assert_eq!(set.0.len(), Palette::SIZE as usize);
+    }
+
+    #[test]
+    fn palette_clear_mrt_args() {
+        let mut palette = Palette::new();
+        let mut set = SetColors::default();
+        let args = palette
+            .clear_mrt_args_with(
+                &[Color::BLACK, Color::WHITE, Color::BLACK],
+                &mut set.record(),
+            )
+            .unwrap();
+        assert_eq!((args.c_0, args.c_1, args.c_2), (0, 1, 0));
+        // Unused attachments are left at u8::MAX.
+        for index in [args.c_3, args.c_4, args.c_5, args.c_6, args.c_7] {
+            assert_eq!(index, u8::MAX);
+        }
+        assert_eq!(set.0, vec![(0, Color::BLACK), (1, Color::WHITE)]);
+
+        for i in 2..Palette::SIZE {
+            palette.set_with(i, Color::rgb(i, i, i), &mut set.record());
+        }
+        assert!(palette
+            .clear_mrt_args_with(&[Color::BLACK, Color::RED], &mut set.record())
+            .is_none());
+    }
+
+    #[test]
+    fn palette_clear_mrt_args_full() {
+        let mut palette = Palette::new();
+        let mut set = SetColors::default();
+        for i in 0..Palette::SIZE - 2 {
+            palette.set_with(i, Color::rgb(i, i, i), &mut set.record());
+        }
+        set.0.clear();
+
+        // Three new colors don't fit in the two free indices, so none of them are added.
+        let colors = [Color::RED, Color::rgb(0, 0, 0), Color::GREEN, Color::BLUE];
+        assert!(palette
+            .clear_mrt_args_with(&colors, &mut set.record())
+            .is_none());
+        assert!(set.0.is_empty());
+        assert_eq!(palette.index_of(Color::RED), None);
+        assert_eq!(palette.index_of(Color::GREEN), None);
+
+        // Repeated colors only take one index.
+        let colors = [Color::RED, Color::GREEN, Color::RED, Color::rgb(1, 1, 1)];
+        let args = palette
+            .clear_mrt_args_with(&colors, &mut set.record())
+            .unwrap();
+        assert_eq!((args.c_0, args.c_1, args.c_2, args.c_3), (14, 15, 14, 1));
+        assert_eq!(set.0, vec![(14, Color::RED), (15, Color::GREEN)]);
+    }
+}
@@ -0,0 +1,578 @@
+use crate::bgfx::{
+    self, Attachment, BackbufferRatio, BgfxError, BlitArgs, BufferFlags, BuiltVertexLayout, Caps,
+    ClearFlags, Color, CreateFrameBufferFromNwhArgs, CreateTexture3DArgs, CreateTextureCubeArgs,
+    DbgTextClearArgs, DebugFlags, DiscardFlags, DispatchArgs, DynamicIndexBuffer,
+    DynamicVertexBuffer, EncoderGuard, Frame, FrameBuffer, IndexBuffer, IndirectBuffer, Init,
+    InstanceDataBuffer, Memory, OcclusionQuery, Program, RendererType, ResetArgs, Sampler,
+    SetViewClearArgs, SetViewClearMrtArgs, SetViewScissorArgs, Shader, Stats, SubmitArgs, Texture,
+    TextureFlags, TextureFormat, TextureInfo, TransientIndexBuffer, TransientVertexBuffer, Uniform,
+    UniformType, VertexBuffer, VertexLayout, ViewId, ViewMode,
+};
+use core::ffi::c_void;
+use std::fmt;
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+
+/// Number of the current bgfx session, counting from 1 for every successful init, or 0 while
+/// bgfx isn't initialized. Handles remember the session they were created in, so handles dropped
+/// after shutdown, or after bgfx was initialized again, don't destroy a handle of the current
+/// session that reuses their index.
+static SESSION: AtomicU32 = AtomicU32::new(0);
+
+/// Number of the last session started.
+static LAST_SESSION: AtomicU32 = AtomicU32::new(0);
+
+/// Set while a [Bgfx] context exists, or is being initialized.
+static CONTEXT: AtomicBool = AtomicBool::new(false);
+
+pub(crate) fn set_initialized(initialized: bool) {
+    let session = if initialized {
+        LAST_SESSION.fetch_add(1, Ordering::AcqRel) + 1
+    } else {
+        0
+    };
+    SESSION.store(session, Ordering::Release);
+}
+
+pub(crate) fn is_initialized() -> bool {
+    session() != 0
+}
+
+/// Returns the current session, or 0 while bgfx isn't initialized.
+pub(crate) fn session() -> u32 {
+    SESSION.load(Ordering::Acquire)
+}
+
+/// Returns `true` if `session` is the current one, so handles created in it are still alive.
+pub(crate) fn is_current(session: u32) -> bool {
+    session != 0 && session == self::session()
+}
+
+/// Returns the session for a handle that is about to be created.
+///
+/// Panics if bgfx isn't initialized, as bgfx crashes when creating resources before init.
+pub(crate) fn creation_session() -> u32 {
+    let session = session();
+    assert!(session != 0, "bgfx must be initialized to create resources");
+    session
+}
+
+/// Claims bgfx and starts a new session if `init_bgfx` initializes it. Both [Bgfx::init] and
+/// the deprecated [bgfx::init] go through here, so bgfx is never initialized twice.
+pub(crate) fn begin_session(init_bgfx: impl FnOnce() -> bool) -> Result<(), InitError> {
+    if CONTEXT
+        .compare_exchange(false, true, Ordering::AcqRel, Ordering::Acquire)
+        .is_err()
+    {
+        return Err(InitError::AlreadyInitialized);
+    }
+    // bgfx may have been initialized by a host application, see `set_interface`.
+    if is_initialized() {
+        CONTEXT.store(false, Ordering::Release);
+        return Err(InitError::AlreadyInitialized);
+    }
+    if !init_bgfx() {
+        CONTEXT.store(false, Ordering::Release);
+        return Err(InitError::Failed);
+    }
+    set_initialized(true);
+    Ok(())
+}
+
+/// Ends the current session with `shutdown_bgfx` and releases bgfx for the next init. Does
+/// nothing if no session is running, so bgfx is only shut down once.
+pub(crate) fn end_session(shutdown_bgfx: impl FnOnce()) {
+    if SESSION.swap(0, Ordering::AcqRel) == 0 {
+        return;
+    }
+    shutdown_bgfx();
+    CONTEXT.store(false, Ordering::Release);
+}
+
+/// Error returned by [Bgfx::init].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum InitError {
+    /// bgfx is already initialized, either by another [Bgfx] or by [bgfx::init].
+    AlreadyInitialized,
+    /// bgfx failed to initialize the renderer.
+    Failed,
+}
+
+impl fmt::Display for InitError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InitError::AlreadyInitialized => write!(f, "bgfx is already initialized"),
+            InitError::Failed => write!(f, "bgfx failed to initialize"),
+        }
+    }
+}
+
+impl std::error::Error for InitError {}
+
+/// Initialized bgfx context. bgfx is shut down when the context is dropped.
+///
+/// The context lives on the API thread (the thread that called [Bgfx::init]) and owns the
+/// [Frame] token, so the frame can only be advanced through [Bgfx::frame] once all encoders
+/// have been ended.
+///
+/// Resources are created through the context, such as with [Bgfx::create_texture_2d] or its
+/// fallible version [Bgfx::try_create_texture_2d]. Handles don't borrow the context: each one
+/// remembers the bgfx session it was created in, and is only destroyed when dropped in that
+/// same session. Handles dropped after the context aren't destroyed, as bgfx has already
+/// released them on shutdown, and neither are handles dropped after bgfx was initialized again.
+///
+/// Views are set up and draw calls submitted through the context too, such as with
+/// [Bgfx::set_view_clear] and [Bgfx::submit]. Draw calls submitted on the context go through
+/// the encoder of the API thread; use [Bgfx::encoder] to submit from other threads.
+///
+/// The free functions and associated constructors such as [bgfx::submit] and
+/// [Texture::create_texture_2d] remain for code that is handed an already initialized bgfx.
+/// Resource constructors panic while bgfx isn't initialized, and the free [bgfx::init] fails
+/// while a context is alive.
+pub struct Bgfx {
+    frame: Frame,
+}
+
+impl Bgfx {
+    /// Initializes bgfx with the parameters in `init`.
+    pub fn init(init: &Init) -> Result<Bgfx, InitError> {
+        begin_session(|| bgfx::init_bgfx(init))?;
+        Ok(Bgfx {
+            frame: unsafe { Frame::new() },
+        })
+    }
+
+    /// Returns `true` if bgfx is currently initialized.
+    pub fn is_initialized() -> bool {
+        is_initialized()
+    }
+
+    /// Begins submitting draw calls through a new encoder. See: [Frame::encoder]
+    pub fn encoder(&self) -> Option<EncoderGuard<'_>> {
+        self.frame.encoder()
+    }
+
+    /// Returns the token of the frame being submitted, for allocating
+    /// [Transient](bgfx::Transient) buffers outside of an encoder.
+    pub fn frame_token(&self) -> &Frame {
+        &self.frame
+    }
+
+    /// Advances to next frame and returns the current frame number. See: [Frame::frame]
+    pub fn frame(&mut self, capture: bool) -> u32 {
+        self.frame.frame(capture)
+    }
+
+    /// Resets the graphic settings and back-buffer size. See: [bgfx::reset]
+    pub fn reset(&self, width: u32, height: u32, params: ResetArgs) {
+        bgfx::reset(width, height, params)
+    }
+
+    /// Returns the renderer backend bgfx was initialized with.
+    pub fn renderer_type(&self) -> RendererType {
+        bgfx::get_renderer_type()
+    }
+
+    /// Returns the renderer capabilities.
+    pub fn caps(&self) -> &Caps {
+        bgfx::get_caps()
+    }
+
+    /// Returns the performance counters of the last frame.
+    pub fn stats(&self) -> &Stats {
+        bgfx::get_stats()
+    }
+
+    /// Sets debug flags. See: [bgfx::set_debug]
+    pub fn set_debug(&self, debug: DebugFlags) {
+        bgfx::set_debug(debug)
+    }
+}
+
+/// Adds methods to [Bgfx] that forward to the given functions, such as the resource
+/// constructors.
+macro_rules! methods {
+    ($(
+        $(#[$meta:meta])*
+        fn $name:ident($($arg:ident: $ty:ty),*) $(-> $ret:ty)? = $function:path;
+    )*) => {
+        impl Bgfx {
+            $(
+                $(#[$meta])*
+                pub fn $name(&self, $($arg: $ty),*) $(-> $ret)? {
+                    $function($($arg),*)
+                }
+            )*
+        }
+    };
+}
+
+methods! {
+    /// Creates a shader from a compiled binary. See: [Shader::create_shader]
+    fn create_shader(mem: &Memory) -> Shader = Shader::create_shader;
+    /// Creates a program from a vertex and fragment shader. See: [Program::create_program]
+    fn create_program(vsh: &Shader, fsh: &Shader, destroy_shaders: bool) -> Program =
+        Program::create_program;
+    /// Creates a compute program. See: [Program::create_compute_program]
+    fn create_compute_program(csh: &Shader, destroy_shaders: bool) -> Program =
+        Program::create_compute_program;
+    /// Creates a shader uniform. See: [Uniform::create]
+    fn create_uniform(name: &str, type_r: UniformType, num: u16) -> Uniform = Uniform::create;
+    /// Creates a vertex layout handle. See: [VertexLayout::create_vertex_layout]
+    fn create_vertex_layout(layout: &BuiltVertexLayout) -> VertexLayout =
+        VertexLayout::create_vertex_layout;
+    /// Creates a static vertex buffer. See: [VertexBuffer::create_vertex_buffer]
+    fn create_vertex_buffer(mem: &Memory, layout: &BuiltVertexLayout, flags: BufferFlags)
+        -> VertexBuffer = VertexBuffer::create_vertex_buffer;
+    /// Creates a static index buffer. See: [IndexBuffer::create_index_buffer]
+    fn create_index_buffer(mem: &Memory, flags: BufferFlags) -> IndexBuffer =
+        IndexBuffer::create_index_buffer;
+    /// Creates an empty dynamic vertex buffer.
+    /// See: [DynamicVertexBuffer::create_dynamic_vertex_buffer]
+    fn create_dynamic_vertex_buffer(num: u32, layout: &BuiltVertexLayout, flags: BufferFlags)
+        -> DynamicVertexBuffer = DynamicVertexBuffer::create_dynamic_vertex_buffer;
+    /// Creates a dynamic vertex buffer initialized from memory.
+    /// See: [DynamicVertexBuffer::create_dynamic_vertex_buffer_mem]
+    fn create_dynamic_vertex_buffer_mem(
+        mem: &Memory,
+        layout: &BuiltVertexLayout,
+        flags: BufferFlags
+    ) -> DynamicVertexBuffer = DynamicVertexBuffer::create_dynamic_vertex_buffer_mem;
+    /// Creates an empty dynamic index buffer.
+    /// See: [DynamicIndexBuffer::create_dynamic_index_buffer]
+    fn create_dynamic_index_buffer(num: u32, flags: BufferFlags) -> DynamicIndexBuffer =
+        DynamicIndexBuffer::create_dynamic_index_buffer;
+    /// Creates a dynamic index buffer initialized from memory.
+    /// See: [DynamicIndexBuffer::create_dynamic_index_buffer_mem]
+    fn create_dynamic_index_buffer_mem(mem: &Memory, flags: BufferFlags) -> DynamicIndexBuffer =
+        DynamicIndexBuffer::create_dynamic_index_buffer_mem;
+    /// Creates an indirect buffer. See: [IndirectBuffer::create_indirect_buffer]
+    fn create_indirect_buffer(num: u32) -> IndirectBuffer = IndirectBuffer::create_indirect_buffer;
+    /// Creates a texture from a file format such as DDS, KTX or PVR. See: [Texture::create_texture]
+    fn create_texture(
+        mem: &Memory,
+        flags: impl Into<TextureFlags>,
+        skip: u8,
+        info: &mut TextureInfo
+    ) -> Texture = Texture::create_texture;
+    /// Creates a 2D texture. See: [Texture::create_texture_2d]
+    #[allow(clippy::too_many_arguments)]
+    fn create_texture_2d(
+        width: u16,
+        height: u16,
+        has_mips: bool,
+        num_layers: u16,
+        format: TextureFormat,
+        flags: impl Into<TextureFlags>,
+        mem: &Memory
+    ) -> Texture = Texture::create_texture_2d;
+    /// Creates a 2D texture scaled to the back buffer. See: [Texture::create_texture_2d_scaled]
+    fn create_texture_2d_scaled(
+        ratio: BackbufferRatio,
+        has_mips: bool,
+        num_layers: u16,
+        format: TextureFormat,
+        flags: impl Into<TextureFlags>
+    ) -> Texture = Texture::create_texture_2d_scaled;
+    /// Creates a 3D texture. See: [Texture::create_texture_3d]
+    fn create_texture_3d(
+        width: u16,
+        height: u16,
+        depth: u16,
+        has_mips: bool,
+        format: TextureFormat,
+        params: CreateTexture3DArgs
+    ) -> Texture = Texture::create_texture_3d;
+    /// Creates a cube map texture. See: [Texture::create_texture_cube]
+    fn create_texture_cube(
+        size: u16,
+        has_mips: bool,
+        num_layers: u16,
+        format: TextureFormat,
+        params: CreateTextureCubeArgs
+    ) -> Texture = Texture::create_texture_cube;
+    /// Creates a frame buffer with a new texture. See: [FrameBuffer::create_frame_buffer]
+    fn create_frame_buffer(
+        width: u16,
+        height: u16,
+        format: TextureFormat,
+        texture_flags: TextureFlags
+    ) -> FrameBuffer = FrameBuffer::create_frame_buffer;
+    /// Creates a frame buffer scaled to the back buffer.
+    /// See: [FrameBuffer::create_frame_buffer_scaled]
+    fn create_frame_buffer_scaled(
+        ratio: BackbufferRatio,
+        format: TextureFormat,
+        texture_flags: TextureFlags
+    ) -> FrameBuffer = FrameBuffer::create_frame_buffer_scaled;
+    /// Creates a frame buffer from textures. See: [FrameBuffer::create_frame_buffer_from_textures]
+    fn create_frame_buffer_from_textures(textures: &[&Texture]) -> FrameBuffer =
+        FrameBuffer::create_frame_buffer_from_textures;
+    /// Creates a frame buffer from texture handles.
+    /// See: [FrameBuffer::create_frame_buffer_from_handles]
+    fn create_frame_buffer_from_handles(num: u8, handles: &Texture, destroy_texture: bool)
+        -> FrameBuffer = FrameBuffer::create_frame_buffer_from_handles;
+    /// Creates a frame buffer from attachments.
+    /// See: [FrameBuffer::create_frame_buffer_from_attachment]
+    fn create_frame_buffer_from_attachment(
+        num: u8,
+        attachment: &Attachment,
+        destroy_texture: bool
+    ) -> FrameBuffer = FrameBuffer::create_frame_buffer_from_attachment;
+    /// Creates a frame buffer for an additional native window.
+    /// See: [FrameBuffer::create_frame_buffer_from_nwh]
+    fn create_frame_buffer_from_nwh(
+        nwh: *const c_void,
+        width: u16,
+        height: u16,
+        params: CreateFrameBufferFromNwhArgs
+    ) -> FrameBuffer = FrameBuffer::create_frame_buffer_from_nwh;
+    /// Creates an occlusion query. See: [OcclusionQuery::create_occlusion_query]
+    fn create_occlusion_query() -> OcclusionQuery = OcclusionQuery::create_occlusion_query;
+}
+
+methods! {
+    /// Fallible version of [Bgfx::create_shader]. See: [Shader::try_create_shader]
+    fn try_create_shader(mem: &Memory) -> Result<Shader, BgfxError> = Shader::try_create_shader;
+    /// Fallible version of [Bgfx::create_program]. See: [Program::try_create_program]
+    fn try_create_program(vsh: &Shader, fsh: &Shader, destroy_shaders: bool)
+        -> Result<Program, BgfxError> = Program::try_create_program;
+    /// Fallible version of [Bgfx::create_compute_program].
+    /// See: [Program::try_create_compute_program]
+    fn try_create_compute_program(csh: &Shader, destroy_shaders: bool)
+        -> Result<Program, BgfxError> = Program::try_create_compute_program;
+    /// Fallible version of [Bgfx::create_uniform]. See: [Uniform::try_create]
+    fn try_create_uniform(name: &str, type_r: UniformType, num: u16)
+        -> Result<Uniform, BgfxError> = Uniform::try_create;
+    /// Fallible version of [Bgfx::create_vertex_layout].
+    /// See: [VertexLayout::try_create_vertex_layout]
+    fn try_create_vertex_layout(layout: &BuiltVertexLayout) -> Result<VertexLayout, BgfxError> =
+        VertexLayout::try_create_vertex_layout;
+    /// Fallible version of [Bgfx::create_vertex_buffer].
+    /// See: [VertexBuffer::try_create_vertex_buffer]
+    fn try_create_vertex_buffer(mem: &Memory, layout: &BuiltVertexLayout, flags: BufferFlags)
+        -> Result<VertexBuffer, BgfxError> = VertexBuffer::try_create_vertex_buffer;
+    /// Fallible version of [Bgfx::create_index_buffer].
+    /// See: [IndexBuffer::try_create_index_buffer]
+    fn try_create_index_buffer(mem: &Memory, flags: BufferFlags)
+        -> Result<IndexBuffer, BgfxError> = IndexBuffer::try_create_index_buffer;
+    /// Fallible version of [Bgfx::create_dynamic_vertex_buffer].
+    /// See: [DynamicVertexBuffer::try_create_dynamic_vertex_buffer]
+    fn try_create_dynamic_vertex_buffer(num: u32, layout: &BuiltVertexLayout, flags: BufferFlags)
+        -> Result<DynamicVertexBuffer, BgfxError> =
+        DynamicVertexBuffer::try_create_dynamic_vertex_buffer;
+    /// Fallible version of [Bgfx::create_dynamic_vertex_buffer_mem].
+    /// See: [DynamicVertexBuffer::try_create_dynamic_vertex_buffer_mem]
+    fn try_create_dynamic_vertex_buffer_mem(
+        mem: &Memory,
+        layout: &BuiltVertexLayout,
+        flags: BufferFlags
+    ) -> Result<DynamicVertexBuffer, BgfxError> =
+        DynamicVertexBuffer::try_create_dynamic_vertex_buffer_mem;
+    /// Fallible version of [Bgfx::create_dynamic_index_buffer].
+    /// See: [DynamicIndexBuffer::try_create_dynamic_index_buffer]
+    fn try_create_dynamic_index_buffer(num: u32, flags: BufferFlags)
+        -> Result<DynamicIndexBuffer, BgfxError> =
+        DynamicIndexBuffer::try_create_dynamic_index_buffer;
+    /// Fallible version of [Bgfx::create_dynamic_index_buffer_mem].
+    /// See: [DynamicIndexBuffer::try_create_dynamic_index_buffer_mem]
+    fn try_create_dynamic_index_buffer_mem(mem: &Memory, flags: BufferFlags)
+        -> Result<DynamicIndexBuffer, BgfxError> =
+        DynamicIndexBuffer::try_create_dynamic_index_buffer_mem;
+    /// Fallible version of [Bgfx::create_indirect_buffer].
+    /// See: [IndirectBuffer::try_create_indirect_buffer]
+    fn try_create_indirect_buffer(num: u32) -> Result<IndirectBuffer, BgfxError> =
+        IndirectBuffer::try_create_indirect_buffer;
+    /// Fallible version of [Bgfx::create_texture]. See: [Texture::try_create_texture]
+    fn try_create_texture(
+        mem: &Memory,
+        flags: impl Into<TextureFlags>,
+        skip: u8,
+        info: &mut TextureInfo
+    ) -> Result<Texture, BgfxError> = Texture::try_create_texture;
+    /// Fallible version of [Bgfx::create_texture_2d]. See: [Texture::try_create_texture_2d]
+    #[allow(clippy::too_many_arguments)]
+    fn try_create_texture_2d(
+        width: u16,
+        height: u16,
+        has_mips: bool,
+        num_layers: u16,
+        format: TextureFormat,
+        flags: impl Into<TextureFlags>,
+        mem: &Memory
+    ) -> Result<Texture, BgfxError> = Texture::try_create_texture_2d;
+    /// Fallible version of [Bgfx::create_texture_2d_scaled].
+    /// See: [Texture::try_create_texture_2d_scaled]
+    fn try_create_texture_2d_scaled(
+        ratio: BackbufferRatio,
+        has_mips: bool,
+        num_layers: u16,
+        format: TextureFormat,
+        flags: impl Into<TextureFlags>
+    ) -> Result<Texture, BgfxError> = Texture::try_create_texture_2d_scaled;
+    /// Fallible version of [Bgfx::create_texture_3d]. See: [Texture::try_create_texture_3d]
+    fn try_create_texture_3d(
+        width: u16,
+        height: u16,
+        depth: u16,
+        has_mips: bool,
+        format: TextureFormat,
+        params: CreateTexture3DArgs
+    ) -> Result<Texture, BgfxError> = Texture::try_create_texture_3d;
+    /// Fallible version of [Bgfx::create_texture_cube]. See: [Texture::try_create_texture_cube]
+    fn try_create_texture_cube(
+        size: u16,
+        has_mips: bool,
+        num_layers: u16,
+        format: TextureFormat,
+        params: CreateTextureCubeArgs
+    ) -> Result<Texture, BgfxError> = Texture::try_create_texture_cube;
+    /// Fallible version of [Bgfx::create_frame_buffer].
+    /// See: [FrameBuffer::try_create_frame_buffer]
+    fn try_create_frame_buffer(
+        width: u16,
+        height: u16,
+        format: TextureFormat,
+        texture_flags: TextureFlags
+    ) -> Result<FrameBuffer, BgfxError> = FrameBuffer::try_create_frame_buffer;
+    /// Fallible version of [Bgfx::create_frame_buffer_scaled].
+    /// See: [FrameBuffer::try_create_frame_buffer_scaled]
+    fn try_create_frame_buffer_scaled(
+        ratio: BackbufferRatio,
+        format: TextureFormat,
+        texture_flags: TextureFlags
+    ) -> Result<FrameBuffer, BgfxError> = FrameBuffer::try_create_frame_buffer_scaled;
+    /// Fallible version of [Bgfx::create_frame_buffer_from_handles].
+    /// See: [FrameBuffer::try_create_frame_buffer_from_handles]
+    fn try_create_frame_buffer_from_handles(num: u8, handles: &Texture, destroy_texture: bool)
+        -> Result<FrameBuffer, BgfxError> = FrameBuffer::try_create_frame_buffer_from_handles;
+    /// Fallible version of [Bgfx::create_frame_buffer_from_attachment].
+    /// See: [FrameBuffer::try_create_frame_buffer_from_attachment]
+    fn try_create_frame_buffer_from_attachment(
+        num: u8,
+        attachment: &Attachment,
+        destroy_texture: bool
+    ) -> Result<FrameBuffer, BgfxError> = FrameBuffer::try_create_frame_buffer_from_attachment;
+    /// Fallible version of [Bgfx::create_frame_buffer_from_nwh].
+    /// See: [FrameBuffer::try_create_frame_buffer_from_nwh]
+    fn try_create_frame_buffer_from_nwh(
+        nwh: *const c_void,
+        width: u16,
+        height: u16,
+        params: CreateFrameBufferFromNwhArgs
+    ) -> Result<FrameBuffer, BgfxError> = FrameBuffer::try_create_frame_buffer_from_nwh;
+    /// Fallible version of [Bgfx::create_occlusion_query].
+    /// See: [OcclusionQuery::try_create_occlusion_query]
+    fn try_create_occlusion_query() -> Result<OcclusionQuery, BgfxError> =
+        OcclusionQuery::try_create_occlusion_query;
+}
+
+methods! {
+    /// Sets the view rectangle. See: [bgfx::set_view_rect]
+    fn set_view_rect(id: ViewId, x: u16, y: u16, width: u16, height: u16) = bgfx::set_view_rect;
+    /// Sets the view rectangle scaled to the back buffer. See: [bgfx::set_view_rect_ratio]
+    fn set_view_rect_ratio(id: ViewId, x: u16, y: u16, ratio: BackbufferRatio) =
+        bgfx::set_view_rect_ratio;
+    /// Sets the view scissor. See: [bgfx::set_view_scissor]
+    fn set_view_scissor(id: ViewId, params: SetViewScissorArgs) = bgfx::set_view_scissor;
+    /// Sets the view clear flags. See: [bgfx::set_view_clear]
+    fn set_view_clear(id: ViewId, flags: ClearFlags, params: SetViewClearArgs) =
+        bgfx::set_view_clear;
+    /// Sets the view clear flags with palette colors for each frame buffer texture.
+    /// See: [bgfx::set_view_clear_mrt]
+    fn set_view_clear_mrt(
+        id: ViewId,
+        flags: ClearFlags,
+        depth: f32,
+        stencil: u8,
+        params: SetViewClearMrtArgs
+    ) = bgfx::set_view_clear_mrt;
+    /// Sets the view sorting mode. See: [bgfx::set_view_mode]
+    fn set_view_mode(id: ViewId, mode: ViewMode) = bgfx::set_view_mode;
+    /// Sets the view frame buffer. See: [bgfx::set_view_frame_buffer]
+    fn set_view_frame_buffer(id: ViewId, handle: &FrameBuffer) = bgfx::set_view_frame_buffer;
+    /// Sets the view and projection matrices. See: [bgfx::set_view_transform]
+    fn set_view_transform(id: ViewId, view: &[f32; 16], proj: &[f32; 16]) =
+        bgfx::set_view_transform;
+    /// Resets all view settings to default. See: [bgfx::reset_view]
+    fn reset_view(id: ViewId) = bgfx::reset_view;
+    /// Sets a color of the palette used by [Bgfx::set_view_clear_mrt].
+    /// See: [bgfx::set_palette_color]
+    fn set_palette_color(index: u8, color: Color) = bgfx::set_palette_color;
+    /// Clears the debug text buffer. See: [bgfx::dbg_text_clear]
+    fn dbg_text_clear(params: DbgTextClearArgs) = bgfx::dbg_text_clear;
+    /// Prints into the debug text buffer. See: [bgfx::dbg_text]
+    fn dbg_text(x: u16, y: u16, attr: u8, text: &str) = bgfx::dbg_text;
+}
+
+methods! {
+    /// Sets the render state for the next draw call. See: [bgfx::set_state]
+    fn set_state(state: u64, rgba: u32) = bgfx::set_state;
+    /// Sets the stencil test state for the next draw call. See: [bgfx::set_stencil]
+    fn set_stencil(fstencil: u32, bstencil: u32) = bgfx::set_stencil;
+    /// Sets the scissor for the next draw call. See: [bgfx::set_scissor]
+    fn set_scissor(x: u16, y: u16, width: u16, height: u16) -> u16 = bgfx::set_scissor;
+    /// Sets the model matrices for the next draw call. See: [bgfx::set_transform]
+    fn set_transform(mtx: &[f32; 16], num: u16) -> u32 = bgfx::set_transform;
+    /// Sets a shader uniform for the next draw call. See: [bgfx::set_uniform]
+    fn set_uniform(handle: &Uniform, value: &[f32], num: u16) = bgfx::set_uniform;
+    /// Sets a texture stage for the next draw call. See: [bgfx::set_texture]
+    fn set_texture(stage: u8, sampler: &Uniform, handle: &Texture, flags: impl Into<Sampler>) =
+        bgfx::set_texture;
+    /// Sets the vertex buffer for the next draw call. See: [bgfx::set_vertex_buffer]
+    fn set_vertex_buffer(
+        stream: u8,
+        handle: &impl AsRef<VertexBuffer>,
+        start_vertex: u32,
+        num_vertices: u32
+    ) = bgfx::set_vertex_buffer;
+    /// Sets the index buffer for the next draw call. See: [bgfx::set_index_buffer]
+    fn set_index_buffer(handle: &impl AsRef<IndexBuffer>, first_index: u32, num_indices: u32) =
+        bgfx::set_index_buffer;
+    /// Sets the transient vertex buffer for the next draw call.
+    /// See: [bgfx::set_transient_vertex_buffer]
+    fn set_transient_vertex_buffer(
+        stream: u8,
+        tvb: &TransientVertexBuffer,
+        start_vertex: u32,
+        num_vertices: u32
+    ) = bgfx::set_transient_vertex_buffer;
+    /// Sets the transient index buffer for the next draw call.
+    /// See: [bgfx::set_transient_index_buffer]
+    fn set_transient_index_buffer(tib: &TransientIndexBuffer, first_index: u32, num_indices: u32) =
+        bgfx::set_transient_index_buffer;
+    /// Sets the instance data buffer for the next draw call.
+    /// See: [bgfx::set_instance_data_buffer]
+    fn set_instance_data_buffer(idb: &InstanceDataBuffer, start: u32, num: u32) =
+        bgfx::set_instance_data_buffer;
+    /// Submits an empty draw call, so the view is cleared even if nothing is drawn into it.
+    /// See: [bgfx::touch]
+    fn touch(id: ViewId) = bgfx::touch;
+    /// Submits the draw call with the state set so far. See: [bgfx::submit]
+    fn submit(id: ViewId, program: &Program, params: SubmitArgs) = bgfx::submit;
+    /// Discards the state set for the next draw call. See: [bgfx::discard]
+    fn discard(flags: DiscardFlags) = bgfx::discard;
+    /// Dispatches a compute program. See: [bgfx::dispatch]
+    fn dispatch(id: ViewId, program: &Program, params: DispatchArgs) = bgfx::dispatch;
+    /// Blits a texture region between textures. See: [bgfx::blit]
+    #[allow(clippy::too_many_arguments)]
+    fn blit(
+        id: ViewId,
+        dst: &Texture,
+        dst_mip: u8,
+        dst_x: u16,
+        dst_y: u16,
+        dst_z: u16,
+        src: &Texture,
+        params: BlitArgs
+    ) = bgfx::blit;
+}
+
+impl Drop for Bgfx {
+    fn drop(&mut self) {
+        // The context owns the frame, so nothing borrows it anymore.
+        #[allow(deprecated)]
+        unsafe {
+            bgfx::shutdown();
+        }
+    }
+}
@@ -0,0 +1,541 @@
+use crate::bgfx::{
+    get_caps, set_state, set_transient_index_buffer, set_transient_vertex_buffer, submit, AddArgs,
+    Attrib, AttribType, BuiltVertexLayout, Color, EmbeddedShader, Frame, Pod, Program,
+    ShaderBundle, ShaderBundleError, StateDepthTestFlags, StateFlags, StatePtFlags,
+    StateWriteFlags, SubmitArgs, TransientVertexBuffer, VertexLayoutBuilder, ViewId,
+};
+use crate::math::{invert, transform_point};
+use crate::shader::embedded_shaders;
+use std::f32::consts::PI;
+
+/// Shaders transforming positions by `u_modelViewProj` and passing through the vertex color.
+static SHADERS: &[EmbeddedShader] = embedded_shaders!("debug_draw");
+
+/// Number of line segments of circles and spheres.
+const CIRCLE_SEGMENTS: u32 = 32;
+
+#[repr(C)]
+#[derive(Clone, Copy)]
+struct DebugVertex {
+    pos: [f32; 3],
+    color: Color,
+}
+
+unsafe impl Pod for DebugVertex {}
+
+/// Primitives drawn with the same state, from `first_vertex` and `first_index` up to the next
+/// batch. Indices are relative to the first vertex of the batch.
+#[derive(Debug, PartialEq)]
+struct Batch {
+    state: u64,
+    first_vertex: u32,
+    first_index: u32,
+}
+
+/// Vertices and line indices of the primitives drawn since [DebugDraw::begin], split into
+/// batches by state and by the 16-bit index range.
+struct Lines {
+    batches: Vec<Batch>,
+    vertices: Vec<DebugVertex>,
+    indices: Vec<u16>,
+}
+
+impl Lines {
+    fn new(state: u64) -> Lines {
+        Lines {
+            batches: vec![Batch {
+                state,
+                first_vertex: 0,
+                first_index: 0,
+            }],
+            vertices: Vec::new(),
+            indices: Vec::new(),
+        }
+    }
+
+    fn clear(&mut self, state: u64) {
+        self.vertices.clear();
+        self.indices.clear();
+        self.batches.truncate(1);
+        self.batches[0].state = state;
+    }
+
+    fn batch(&self) -> &Batch {
+        self.batches.last().unwrap()
+    }
+
+    /// Index and vertex ranges of each non-empty batch, with its state.
+    fn ranges(&self) -> impl Iterator<Item = (u64, (u32, u32), (u32, u32))> + '_ {
+        self.batches
+            .iter()
+            .enumerate()
+            .map(move |(i, batch)| {
+                let (end_vertex, end_index) = match self.batches.get(i + 1) {
+                    Some(next) => (next.first_vertex, next.first_index),
+                    None => (self.vertices.len() as u32, self.indices.len() as u32),
+                };
+                (
+                    batch.state,
+                    (batch.first_vertex, end_vertex - batch.first_vertex),
+                    (batch.first_index, end_index - batch.first_index),
+                )
+            })
+            .filter(|&(_, _, (_, num_indices))| num_indices > 0)
+    }
+
+    /// Sets the state of the following primitives, starting a new batch unless the current one
+    /// is still empty.
+    fn set_state(&mut self, state: u64) {
+        if state != self.batch().state {
+            self.start_batch(state);
+        }
+    }
+
+    fn start_batch(&mut self, state: u64) {
+        let first_vertex = self.vertices.len() as u32;
+        let first_index = self.indices.len() as u32;
+        let batch = self.batches.last_mut().unwrap();
+        if batch.first_vertex == first_vertex {
+            batch.state = state;
+        } else {
+            self.batches.push(Batch {
+                state,
+                first_vertex,
+                first_index,
+            });
+        }
+    }
+
+    /// Adds `vertices` and lines between pairs of them given by `lines`.
+    fn add(&mut self, vertices: &[[f32; 3]], lines: &[(u16, u16)], color: Color) {
+        // Indices are 16-bit and relative to the batch.
+        let batch_vertices = self.vertices.len() - self.batch().first_vertex as usize;
+        if batch_vertices + vertices.len() > u16::MAX as usize + 1 {
+            self.start_batch(self.batch().state);
+        }
+        let base = (self.vertices.len() - self.batch().first_vertex as usize) as u16;
+        self.vertices
+            .extend(vertices.iter().map(|&pos| DebugVertex { pos, color }));
+        for &(a, b) in lines {
+            self.indices.push(base + a);
+            self.indices.push(base + b);
+        }
+    }
+
+    fn line(&mut self, a: [f32; 3], b: [f32; 3], color: Color) {
+        self.add(&[a, b], &[(0, 1)], color);
+    }
+
+    fn aabb(&mut self, min: [f32; 3], max: [f32; 3], color: Color) {
+        let corners: Vec<[f32; 3]> = (0..8)
+            .map(|i| {
+                [
+                    if i & 1 == 0 { min[0] } else { max[0] },
+                    if i & 2 == 0 { min[1] } else { max[1] },
+                    if i & 4 == 0 { min[2] } else { max[2] },
+                ]
+            })
+            .collect();
+        self.add(&corners, &BOX_EDGES, color);
+    }
+
+    fn circle(&mut self, center: [f32; 3], u: [f32; 3], v: [f32; 3], radius: f32, color: Color) {
+        let points: Vec<[f32; 3]> = (0..CIRCLE_SEGMENTS)
+            .map(|i| {
+                let angle = i as f32 / CIRCLE_SEGMENTS as f32 * 2.0 * PI;
+                let (s, c) = angle.sin_cos();
+                let mut p = center;
+                for k in 0..3 {
+                    p[k] += (u[k] * c + v[k] * s) * radius;
+                }
+                p
+            })
+            .collect();
+        let segments: Vec<(u16, u16)> = (0..CIRCLE_SEGMENTS as u16)
+            .map(|i| (i, (i + 1) % CIRCLE_SEGMENTS as u16))
+            .collect();
+        self.add(&points, &segments, color);
+    }
+
+    fn sphere(&mut self, center: [f32; 3], radius: f32, color: Color) {
+        const X: [f32; 3] = [1.0, 0.0, 0.0];
+        const Y: [f32; 3] = [0.0, 1.0, 0.0];
+        const Z: [f32; 3] = [0.0, 0.0, 1.0];
+        self.circle(center, X, Y, radius, color);
+        self.circle(center, X, Z, radius, color);
+        self.circle(center, Y, Z, radius, color);
+    }
+
+    fn frustum(&mut self, view_proj: &[f32; 16], homogeneous_depth: bool, color: Color) {
+        let inv = match invert(view_proj) {
+            Some(inv) => inv,
+            None => return,
+        };
+        let near = if homogeneous_depth { -1.0 } else { 0.0 };
+        let corners: Vec<[f32; 3]> = (0..8)
+            .map(|i| {
+                let ndc = [
+                    if i & 1 == 0 { -1.0 } else { 1.0 },
+                    if i & 2 == 0 { -1.0 } else { 1.0 },
+                    if i & 4 == 0 { near } else { 1.0 },
+                ];
+                transform_point(&inv, ndc)
+            })
+            .collect();
+        self.add(&corners, &BOX_EDGES, color);
+    }
+
+    fn axis(&mut self, origin: [f32; 3], length: f32) {
+        let [x, y, z] = origin;
+        self.line(origin, [x + length, y, z], Color::RED);
+        self.line(origin, [x, y + length, z], Color::GREEN);
+        self.line(origin, [x, y, z + length], Color::BLUE);
+    }
+
+    fn grid(&mut self, center: [f32; 3], size: f32, divisions: u32, color: Color) {
+        let half = size * 0.5;
+        let [cx, cy, cz] = center;
+        for i in 0..=divisions.max(1) {
+            let t = i as f32 / divisions.max(1) as f32 * size - half;
+            self.line([cx + t, cy, cz - half], [cx + t, cy, cz + half], color);
+            self.line([cx - half, cy, cz + t], [cx + half, cy, cz + t], color);
+        }
+    }
+}
+
+/// Immediate mode drawing of lines, boxes, spheres, frusta, axes and grids for debugging.
+///
+/// Primitives are batched between [DebugDraw::begin] and [DebugDraw::end], and submitted to the
+/// view as lines in transient buffers of the frame. Positions are in world space, transformed by
+/// the view and projection of the view. Colors are anything that converts into a [Color], such
+/// as [Color::RED] or `0xff0000ff` packed as `0xRRGGBBAA`.
+///
+/// ```ignore
+/// let mut dd = DebugDraw::new()?;
+/// // Each frame:
+/// dd.begin(0)
+///     .line([0.0, 0.0, 0.0], [1.0, 1.0, 1.0], Color::WHITE)
+///     .aabb([-1.0, -1.0, -1.0], [1.0, 1.0, 1.0], Color::GREEN)
+///     .end(ctx.frame_token());
+/// ```
+pub struct DebugDraw {
+    program: Program,
+    layout: BuiltVertexLayout,
+    view: ViewId,
+    lines: Lines,
+}
+
+impl DebugDraw {
+    /// Creates the debug draw program for the renderer bgfx is running with.
+    pub fn new() -> Result<DebugDraw, ShaderBundleError> {
+        let program =
+            ShaderBundle::embedded(SHADERS).create_program("vs_debug_draw", "fs_debug_draw")?;
+        let layout = VertexLayoutBuilder::begin(crate::bgfx::get_renderer_type())
+            .add(Attrib::Position, 3, AttribType::Float, AddArgs::default())
+            .add(
+                Attrib::Color0,
+                4,
+                AttribType::Uint8,
+                AddArgs {
+                    normalized: true,
+                    as_int: false,
+                },
+            )
+            .end();
+        Ok(DebugDraw {
+            program,
+            layout,
+            view: 0,
+            lines: Lines::new(Self::state(true)),
+        })
+    }
+
+    fn state(depth_test: bool) -> u64 {
+        let state = StateWriteFlags::RGB.bits()
+            | StateWriteFlags::A.bits()
+            | StatePtFlags::LINES.bits()
+            | StateFlags::LINEAA.bits()
+            | StateFlags::MSAA.bits();
+        if depth_test {
+            state | StateWriteFlags::Z.bits() | StateDepthTestFlags::LESS.bits()
+        } else {
+            state
+        }
+    }
+
+    /// Begins drawing into `view`, with depth testing enabled. Primitives left over from a
+    /// previous [DebugDraw::begin] without [DebugDraw::end] are discarded.
+    pub fn begin(&mut self, view: ViewId) -> &mut Self {
+        self.lines.clear(Self::state(true));
+        self.view = view;
+        self
+    }
+
+    /// Submits the primitives drawn since [DebugDraw::begin] in `frame`. Nothing is drawn if
+    /// they don't fit in the transient buffers of the frame.
+    pub fn end(&mut self, frame: &Frame) {
+        if !self.lines.indices.is_empty() {
+            self.submit(frame);
+        }
+        self.begin(self.view);
+    }
+
+    fn submit(&self, frame: &Frame) {
+        let lines = &self.lines;
+        let (mut tvb, mut tib) = match TransientVertexBuffer::alloc_with_indices(
+            frame,
+            lines.vertices.len() as u32,
+            &self.layout,
+            lines.indices.len() as u32,
+            false,
+        ) {
+            Some(buffers) => buffers,
+            None => return,
+        };
+        tvb.as_mut_slice().copy_from_slice(&lines.vertices);
+        tib.as_mut_slice().copy_from_slice(&lines.indices);
+
+        for (state, (first_vertex, num_vertices), (first_index, num_indices)) in lines.ranges() {
+            set_transient_vertex_buffer(0, &tvb, first_vertex, num_vertices);
+            set_transient_index_buffer(&tib, first_index, num_indices);
+            set_state(state, 0);
+            submit(self.view, &self.program, SubmitArgs::default());
+        }
+    }
+
+    /// Sets whether the following primitives are depth tested against the scene.
+    pub fn depth_test(&mut self, enabled: bool) -> &mut Self {
+        self.lines.set_state(Self::state(enabled));
+        self
+    }
+
+    /// Draws a line from `a` to `b`.
+    pub fn line(&mut self, a: [f32; 3], b: [f32; 3], color: impl Into<Color>) -> &mut Self {
+        self.lines.line(a, b, color.into());
+        self
+    }
+
+    /// Draws an axis aligned box from `min` to `max`.
+    pub fn aabb(&mut self, min: [f32; 3], max: [f32; 3], color: impl Into<Color>) -> &mut Self {
+        self.lines.aabb(min, max, color.into());
+        self
+    }
+
+    /// Draws a circle around `center` in the plane spanned by the unit vectors `u` and `v`.
+    pub fn circle(
+        &mut self,
+        center: [f32; 3],
+        u: [f32; 3],
+        v: [f32; 3],
+        radius: f32,
+        color: impl Into<Color>,
+    ) -> &mut Self {
+        self.lines.circle(center, u, v, radius, color.into());
+        self
+    }
+
+    /// Draws a sphere around `center` as a circle in each of the XY, XZ and YZ planes.
+    pub fn sphere(&mut self, center: [f32; 3], radius: f32, color: impl Into<Color>) -> &mut Self {
+        self.lines.sphere(center, radius, color.into());
+        self
+    }
+
+    /// Draws the frustum of the column major `view_proj` matrix, such as the view and
+    /// projection of a camera multiplied together.
+    pub fn frustum(&mut self, view_proj: &[f32; 16], color: impl Into<Color>) -> &mut Self {
+        self.lines
+            .frustum(view_proj, get_caps().homogeneous_depth, color.into());
+        self
+    }
+
+    /// Draws the X, Y and Z axes from `origin` with `length`, in red, green and blue.
+    pub fn axis(&mut self, origin: [f32; 3], length: f32) -> &mut Self {
+        self.lines.axis(origin, length);
+        self
+    }
+
+    /// Draws a grid of `size` around `center` in the XZ plane, with `divisions` cells along
+    /// each side.
+    pub fn grid(
+        &mut self,
+        center: [f32; 3],
+        size: f32,
+        divisions: u32,
+        color: impl Into<Color>,
+    ) -> &mut Self {
+        self.lines.grid(center, size, divisions, color.into());
+        self
+    }
+}
+
+/// Edges between the corners of a box, where bit 0, 1 and 2 of a corner index select the
+/// maximum on the X, Y and Z axis.
+const BOX_EDGES: [(u16, u16); 12] = [
+    (0, 1),
+    (2, 3),
+    (4, 5),
+    (6, 7),
+    (0, 2),
+    (1, 3),
+    (4, 6),
+    (5, 7),
+    (0, 4),
+    (1, 5),
+    (2, 6),
+    (3, 7),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::math::ortho;
+
+    fn distance(a: [f32; 3], b: [f32; 3]) -> f32 {
+        (0..3).map(|k| (a[k] - b[k]).powi(2)).sum::<f32>().sqrt()
+    }
+
+    fn assert_near(a: [f32; 3], b: [f32; 3]) {
+        assert!(distance(a, b) < 1.0e-4, "{:?} != {:?}", a, b);
+    }
+
+    fn positions(lines: &Lines) -> Vec<[f32; 3]> {
+        lines.vertices.iter().map(|v| v.pos).collect()
+    }
+
+    #[test]
+    fn line() {
+        let mut lines = Lines::new(0);
+        lines.line([1.0, 2.0, 3.0], [4.0, 5.0, 6.0], Color::RED);
+        assert_eq!(positions(&lines), vec![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0]]);
+        assert!(lines.vertices.iter().all(|v| v.color == Color::RED));
+        assert_eq!(lines.indices, vec![0, 1]);
+    }
+
+    #[test]
+    fn aabb_edges() {
+        let mut lines = Lines::new(0);
+        lines.aabb([0.0, 0.0, 0.0], [1.0, 2.0, 3.0], Color::WHITE);
+        let corners = positions(&lines);
+        assert_eq!(corners.len(), 8);
+        assert_eq!(corners[0], [0.0, 0.0, 0.0]);
+        assert_eq!(corners[7], [1.0, 2.0, 3.0]);
+        // Each edge runs along a single axis.
+        for pair in lines.indices.chunks(2) {
+            let (a, b) = (corners[pair[0] as usize], corners[pair[1] as usize]);
+            assert_eq!((0..3).filter(|&k| a[k] != b[k]).count(), 1);
+        }
+        assert_eq!(lines.indices.len(), 24);
+    }
+
+    #[test]
+    fn sphere_circles() {
+        let center = [1.0, -2.0, 3.0];
+        let mut lines = Lines::new(0);
+        lines.sphere(center, 2.0, Color::BLUE);
+        let points = positions(&lines);
+        let segments = CIRCLE_SEGMENTS as usize;
+        assert_eq!(points.len(), 3 * segments);
+        assert_eq!(lines.indices.len(), 3 * 2 * segments);
+        for (circle, plane) in points.chunks(segments).zip(&[2, 1, 0]) {
+            for &p in circle {
+                assert!((distance(p, center) - 2.0).abs() < 1.0e-5);
+                assert_eq!(p[*plane], center[*plane]);
+            }
+        }
+        // Each circle is closed.
+        let first = &lines.indices[..2 * segments];
+        assert_eq!(first[first.len() - 1], 0);
+    }
+
+    #[test]
+    fn axis_colors() {
+        let mut lines = Lines::new(0);
+        lines.axis([1.0, 1.0, 1.0], 2.0);
+        let expected = [
+            ([3.0, 1.0, 1.0], Color::RED),
+            ([1.0, 3.0, 1.0], Color::GREEN),
+            ([1.0, 1.0, 3.0], Color::BLUE),
+        ];
+        for (pair, &(end, color)) in lines.vertices.chunks(2).zip(&expected) {
+            assert_eq!(pair[0].pos, [1.0, 1.0, 1.0]);
+            assert_eq!(pair[1].pos, end);
+            assert_eq!(pair[0].color, color);
+            assert_eq!(pair[1].color, color);
+        }
+    }
+
+    #[test]
+    fn grid_lines() {
+        let mut lines = Lines::new(0);
+        lines.grid([0.0, 1.0, 0.0], 4.0, 4, Color::WHITE);
+        assert_eq!(lines.vertices.len(), 2 * 2 * 5);
+        assert_eq!(lines.vertices[0].pos, [-2.0, 1.0, -2.0]);
+        assert_eq!(lines.vertices[1].pos, [-2.0, 1.0, 2.0]);
+        assert!(lines.vertices.iter().all(|v| v.pos[1] == 1.0));
+    }
+
+    #[test]
+    fn frustum_corners() {
+        for &homogeneous_depth in &[false, true] {
+            let view_proj = ortho(-4.0, 4.0, -3.0, 3.0, homogeneous_depth);
+            let mut lines = Lines::new(0);
+            lines.frustum(&view_proj, homogeneous_depth, Color::WHITE);
+            let corners = positions(&lines);
+            assert_near(corners[0], [-4.0, -3.0, 0.0]);
+            assert_near(corners[3], [4.0, 3.0, 0.0]);
+            assert_near(corners[7], [4.0, 3.0, 1000.0]);
+        }
+
+        let mut lines = Lines::new(0);
+        lines.frustum(&[0.0; 16], false, Color::WHITE);
+        assert!(lines.vertices.is_empty());
+    }
+
+    #[test]
+    fn batches_by_state() {
+        let mut lines = Lines::new(1);
+        lines.set_state(2);
+        lines.line([0.0; 3], [1.0; 3], Color::WHITE);
+        lines.set_state(2);
+        lines.line([0.0; 3], [1.0; 3], Color::WHITE);
+        lines.set_state(3);
+        lines.set_state(1);
+        lines.line([0.0; 3], [1.0; 3], Color::WHITE);
+
+        // The empty first batch took the new state, and the empty batch of state 3 was reused.
+        let ranges: Vec<_> = lines.ranges().collect();
+        assert_eq!(ranges, vec![(2, (0, 4), (0, 4)), (1, (4, 2), (4, 2))]);
+        // Indices are relative to the batch.
+        assert_eq!(lines.indices, vec![0, 1, 2, 3, 0, 1]);
+
+        lines.clear(5);
+        assert_eq!(
+            lines.batches,
+            vec![Batch {
+                state: 5,
+                first_vertex: 0,
+                first_index: 0
+            }]
+        );
+        assert_eq!(lines.ranges().count(), 0);
+    }
+
+    #[test]
+    fn batches_by_index_range() {
+        let mut lines = Lines::new(0);
+        let points = vec![[0.0; 3]; 1000];
+        let segments: Vec<(u16, u16)> = (0..999).map(|i| (i, i + 1)).collect();
+        for _ in 0..66 {
+            lines.add(&points, &segments, Color::WHITE);
+        }
+
+        let ranges: Vec<_> = lines.ranges().collect();
+        assert_eq!(ranges.len(), 2);
+        assert_eq!(ranges[0].1, (0, 65000));
+        assert_eq!(ranges[1].1, (65000, 1000));
+        assert_eq!(ranges[1].2, (65 * 999 * 2, 999 * 2));
+        let second = &lines.indices[ranges[1].2 .0 as usize..];
+        assert_eq!(second[..2], [0, 1]);
+        assert_eq!(*second.iter().max().unwrap(), 999);
+    }
+}
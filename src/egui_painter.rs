@@ -0,0 +1,271 @@
+use crate::bgfx::{
+    state_blend_func, AddressMode, EncoderGuard, Filter, Memory, SamplerDesc, ShaderBundleError,
+    StateBlendFlags, StateFlags, StateWriteFlags, Texture, TextureFormat, TransientVertexBuffer,
+    ViewId,
+};
+use crate::ui::{scissor, UiPipeline, UiVertex};
+use egui::epaint::{ImageDelta, Primitive, Vertex};
+use egui::{ClippedPrimitive, ImageData, Rect, TextureFilter, TextureId, TexturesDelta};
+use std::collections::HashMap;
+
+/// Paints [egui](https://github.com/emilk/egui) output with bgfx.
+///
+/// egui textures, including the font atlas, are created and updated from the [TexturesDelta] of
+/// each frame. Other textures are registered with [EguiPainter::register_user_texture] and
+/// referenced by the returned [TextureId] in the UI, such as with `egui::Image`.
+///
+/// ```ignore
+/// let ctx = egui::Context::default();
+/// let mut painter = EguiPainter::new(255)?;
+/// // Each frame:
+/// let output = ctx.run(raw_input, |ctx| {
+///     egui::Window::new("Hello").show(ctx, |ui| ui.label("Hello"));
+/// });
+/// let primitives = ctx.tessellate(output.shapes);
+/// let encoder = bgfx_ctx.encoder().unwrap();
+/// let pixels_per_point = ctx.pixels_per_point();
+/// painter.paint(&encoder, &primitives, &output.textures_delta, [width, height], pixels_per_point);
+/// ```
+pub struct EguiPainter {
+    pipeline: UiPipeline,
+    textures: HashMap<TextureId, Texture>,
+    next_user_texture: u64,
+    view: ViewId,
+}
+
+impl EguiPainter {
+    /// Creates the painter drawing into `view`.
+    pub fn new(view: ViewId) -> Result<EguiPainter, ShaderBundleError> {
+        Ok(EguiPainter {
+            pipeline: UiPipeline::new()?,
+            textures: HashMap::new(),
+            next_user_texture: 0,
+            view,
+        })
+    }
+
+    /// Returns the view the UI is drawn into.
+    pub fn view(&self) -> ViewId {
+        self.view
+    }
+
+    /// Sets the view the UI is drawn into.
+    pub fn set_view(&mut self, view: ViewId) {
+        self.view = view;
+    }
+
+    /// Registers `texture` to be drawn by the returned id, such as with `egui::Image`. Its
+    /// colors are expected to have premultiplied alpha.
+    pub fn register_user_texture(&mut self, texture: Texture) -> TextureId {
+        let id = TextureId::User(self.next_user_texture);
+        self.next_user_texture += 1;
+        self.textures.insert(id, texture);
+        id
+    }
+
+    /// Returns the texture drawn for `id`.
+    pub fn texture(&self, id: TextureId) -> Option<&Texture> {
+        self.textures.get(&id)
+    }
+
+    /// Creates or updates the texture `id` from `delta`. Partial updates of textures that
+    /// don't exist are ignored.
+    pub fn set_texture(&mut self, id: TextureId, delta: &ImageDelta) {
+        let [width, height] = delta.image.size();
+        let pixels: Vec<u8> = match &delta.image {
+            ImageData::Color(image) => image.pixels.iter().flat_map(|c| c.to_array()).collect(),
+            ImageData::Font(image) => image
+                .srgba_pixels(None)
+                .flat_map(|c| c.to_array())
+                .collect(),
+        };
+        let [x, y] = match delta.pos {
+            Some(pos) => pos,
+            None => {
+                // Created without data so that it can be updated partially later on.
+                let sampler = sampler(delta.options.minification, delta.options.magnification);
+                let texture = Texture::create_texture_2d(
+                    width as u16,
+                    height as u16,
+                    false,
+                    1,
+                    TextureFormat::RGBA8,
+                    sampler,
+                    &Memory::new(),
+                );
+                self.textures.insert(id, texture);
+                [0, 0]
+            }
+        };
+        if let Some(texture) = self.textures.get(&id) {
+            texture.update_texture_2d(
+                0,
+                0,
+                x as u16,
+                y as u16,
+                width as u16,
+                height as u16,
+                &Memory::copy(&pixels),
+                u16::MAX,
+            );
+        }
+    }
+
+    /// Destroys the texture `id`.
+    pub fn free_texture(&mut self, id: TextureId) {
+        self.textures.remove(&id);
+    }
+
+    /// Applies `textures_delta` and draws `primitives` to the view, which is set up to cover a
+    /// screen of `screen_size_px` pixels. Textures are set before drawing, and freed after.
+    ///
+    /// Paint callbacks are skipped, as are meshes that don't fit in the transient buffers of
+    /// the frame.
+    pub fn paint(
+        &mut self,
+        encoder: &EncoderGuard<'_>,
+        primitives: &[ClippedPrimitive],
+        textures_delta: &TexturesDelta,
+        screen_size_px: [u32; 2],
+        pixels_per_point: f32,
+    ) {
+        for (id, delta) in &textures_delta.set {
+            self.set_texture(*id, delta);
+        }
+        self.paint_primitives(encoder, primitives, screen_size_px, pixels_per_point);
+        for id in &textures_delta.free {
+            self.free_texture(*id);
+        }
+    }
+
+    /// Draws `primitives` to the view without updating any textures.
+    pub fn paint_primitives(
+        &self,
+        encoder: &EncoderGuard<'_>,
+        primitives: &[ClippedPrimitive],
+        screen_size_px: [u32; 2],
+        pixels_per_point: f32,
+    ) {
+        let [width_px, height_px] = screen_size_px;
+        if width_px == 0 || height_px == 0 {
+            return;
+        }
+        let width = width_px as f32 / pixels_per_point;
+        let height = height_px as f32 / pixels_per_point;
+
+        self.pipeline.setup_view(
+            self.view,
+            [width_px as f32, height_px as f32],
+            [0.0, 0.0, width, height],
+        );
+
+        for ClippedPrimitive {
+            clip_rect,
+            primitive,
+        } in primitives
+        {
+            let mesh = match primitive {
+                Primitive::Mesh(mesh) => mesh,
+                Primitive::Callback(_) => continue,
+            };
+            if mesh.vertices.len() <= u16::MAX as usize {
+                let indices = mesh.indices.iter().map(|&index| index as u16);
+                self.draw_mesh(
+                    encoder,
+                    clip_rect,
+                    mesh.texture_id,
+                    &mesh.vertices,
+                    indices,
+                    screen_size_px,
+                    pixels_per_point,
+                );
+            } else {
+                for mesh in mesh.clone().split_to_u16() {
+                    let indices = mesh.indices.iter().copied();
+                    self.draw_mesh(
+                        encoder,
+                        clip_rect,
+                        mesh.texture_id,
+                        &mesh.vertices,
+                        indices,
+                        screen_size_px,
+                        pixels_per_point,
+                    );
+                }
+            }
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn draw_mesh(
+        &self,
+        encoder: &EncoderGuard<'_>,
+        clip_rect: &Rect,
+        texture_id: TextureId,
+        vertices: &[Vertex],
+        indices: impl ExactSizeIterator<Item = u16>,
+        screen_size_px: [u32; 2],
+        pixels_per_point: f32,
+    ) {
+        let texture = match self.textures.get(&texture_id) {
+            Some(texture) => texture,
+            None => return,
+        };
+        let clip_rect = [
+            (clip_rect.min.x * pixels_per_point).round(),
+            (clip_rect.min.y * pixels_per_point).round(),
+            (clip_rect.max.x * pixels_per_point).round(),
+            (clip_rect.max.y * pixels_per_point).round(),
+        ];
+        let size_px = [screen_size_px[0] as f32, screen_size_px[1] as f32];
+        let scissor = match scissor(clip_rect, size_px) {
+            Some(scissor) => scissor,
+            None => return,
+        };
+
+        let (mut tvb, mut tib) = match TransientVertexBuffer::alloc_with_indices(
+            encoder.frame(),
+            vertices.len() as u32,
+            self.pipeline.layout(),
+            indices.len() as u32,
+            false,
+        ) {
+            Some(buffers) => buffers,
+            None => return,
+        };
+        for (dst, src) in tvb.as_mut_slice::<UiVertex>().iter_mut().zip(vertices) {
+            *dst = UiVertex {
+                pos: [src.pos.x, src.pos.y],
+                uv: [src.uv.x, src.uv.y],
+                col: src.color.to_array(),
+            };
+        }
+        for (dst, src) in tib.as_mut_slice::<u16>().iter_mut().zip(indices) {
+            *dst = src;
+        }
+
+        // egui colors and textures have premultiplied alpha.
+        let state = StateWriteFlags::RGB.bits()
+            | StateWriteFlags::A.bits()
+            | StateFlags::MSAA.bits()
+            | state_blend_func(StateBlendFlags::ONE, StateBlendFlags::INV_SRC_ALPHA);
+
+        encoder.set_transient_vertex_buffer(0, &tvb, 0, tvb.len());
+        encoder.set_transient_index_buffer(&tib, 0, tib.len());
+        self.pipeline
+            .submit(encoder, self.view, scissor, state, texture);
+    }
+}
+
+/// Sampler of a texture, clamped to its edges.
+fn sampler(minification: TextureFilter, magnification: TextureFilter) -> SamplerDesc {
+    let filter = |filter| match filter {
+        TextureFilter::Nearest => Filter::Point,
+        TextureFilter::Linear => Filter::Linear,
+    };
+    SamplerDesc::default()
+        .min(filter(minification))
+        .mag(filter(magnification))
+        .u(AddressMode::Clamp)
+        .v(AddressMode::Clamp)
+}
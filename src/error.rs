@@ -0,0 +1,405 @@
+use crate::bgfx::{
+    get_caps, get_stats, Attachment, BackbufferRatio, BufferFlags, BuiltVertexLayout,
+    CreateFrameBufferFromNwhArgs, CreateTexture3DArgs, CreateTextureCubeArgs, DynamicIndexBuffer,
+    DynamicVertexBuffer, FrameBuffer, IndexBuffer, IndirectBuffer, Memory, OcclusionQuery, Program,
+    Shader, Texture, TextureFlags, TextureFormat, TextureInfo, Uniform, UniformType, VertexBuffer,
+    VertexLayout,
+};
+use core::ffi::c_void;
+use std::fmt;
+
+/// Resource limit from [CapsLimits](crate::bgfx::CapsLimits).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum CapsLimit {
+    /// [CapsLimits::max_frame_buffers](crate::bgfx::CapsLimits::max_frame_buffers)
+    MaxFrameBuffers,
+    /// [CapsLimits::max_programs](crate::bgfx::CapsLimits::max_programs)
+    MaxPrograms,
+    /// [CapsLimits::max_shaders](crate::bgfx::CapsLimits::max_shaders)
+    MaxShaders,
+    /// [CapsLimits::max_textures](crate::bgfx::CapsLimits::max_textures)
+    MaxTextures,
+    /// [CapsLimits::max_vertex_layouts](crate::bgfx::CapsLimits::max_vertex_layouts)
+    MaxVertexLayouts,
+    /// [CapsLimits::max_index_buffers](crate::bgfx::CapsLimits::max_index_buffers)
+    MaxIndexBuffers,
+    /// [CapsLimits::max_vertex_buffers](crate::bgfx::CapsLimits::max_vertex_buffers)
+    MaxVertexBuffers,
+    /// [CapsLimits::max_dynamic_index_buffers](crate::bgfx::CapsLimits::max_dynamic_index_buffers)
+    MaxDynamicIndexBuffers,
+    /// [CapsLimits::max_dynamic_vertex_buffers](crate::bgfx::CapsLimits::max_dynamic_vertex_buffers)
+    MaxDynamicVertexBuffers,
+    /// [CapsLimits::max_uniforms](crate::bgfx::CapsLimits::max_uniforms)
+    MaxUniforms,
+    /// [CapsLimits::max_occlusion_queries](crate::bgfx::CapsLimits::max_occlusion_queries)
+    MaxOcclusionQueries,
+}
+
+impl CapsLimit {
+    /// Returns `true` if all handles of this kind were in use in the last frame. bgfx only
+    /// updates [Stats](crate::bgfx::Stats) in `frame`, so handles created or destroyed since
+    /// aren't counted.
+    fn is_exhausted(self) -> bool {
+        let limits = &get_caps().limits;
+        let stats = get_stats();
+        let (used, max) = match self {
+            CapsLimit::MaxFrameBuffers => (stats.num_frame_buffers, limits.max_frame_buffers),
+            CapsLimit::MaxPrograms => (stats.num_programs, limits.max_programs),
+            CapsLimit::MaxShaders => (stats.num_shaders, limits.max_shaders),
+            CapsLimit::MaxTextures => (stats.num_textures, limits.max_textures),
+            CapsLimit::MaxVertexLayouts => (stats.num_vertex_layouts, limits.max_vertex_layouts),
+            CapsLimit::MaxIndexBuffers => (stats.num_index_buffers, limits.max_index_buffers),
+            CapsLimit::MaxVertexBuffers => (stats.num_vertex_buffers, limits.max_vertex_buffers),
+            CapsLimit::MaxDynamicIndexBuffers => (
+                stats.num_dynamic_index_buffers,
+                limits.max_dynamic_index_buffers,
+            ),
+            CapsLimit::MaxDynamicVertexBuffers => (
+                stats.num_dynamic_vertex_buffers,
+                limits.max_dynamic_vertex_buffers,
+            ),
+            CapsLimit::MaxUniforms => (stats.num_uniforms, limits.max_uniforms),
+            CapsLimit::MaxOcclusionQueries => {
+                (stats.num_occlusion_queries, limits.max_occlusion_queries)
+            }
+        };
+        used as u32 >= max
+    }
+}
+
+/// Error returned by the `try_*` resource constructors when bgfx hands back an invalid handle.
+///
+/// bgfx doesn't report why it failed, so the limit is checked against the
+/// [Stats](crate::bgfx::Stats) of the last frame, which lag behind the handles created and
+/// destroyed during the current one.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum BgfxError {
+    /// All handles of this kind were in use in the last frame.
+    LimitExceeded(CapsLimit),
+    /// Handles of this kind were still free in the last frame. Either the limit was reached
+    /// by handles created since, or bgfx rejected the parameters, for example an unsupported
+    /// texture format or a program created from invalid shaders.
+    Unknown(CapsLimit),
+    /// The memory doesn't hold a compiled shader.
+    InvalidShader,
+}
+
+impl fmt::Display for BgfxError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BgfxError::LimitExceeded(limit) => write!(f, "bgfx limit {:?} exceeded", limit),
+            BgfxError::Unknown(limit) => write!(
+                f,
+                "bgfx failed to create the resource, limit {:?} may have been exceeded",
+                limit
+            ),
+            BgfxError::InvalidShader => write!(f, "invalid shader binary"),
+        }
+    }
+}
+
+impl std::error::Error for BgfxError {}
+
+/// Turns an invalid `handle` into an error, reporting whether `limit` was exhausted in the
+/// last frame. The invalid handle is dropped without being destroyed.
+fn check<T>(valid: bool, handle: T, limit: CapsLimit) -> Result<T, BgfxError> {
+    if valid {
+        Ok(handle)
+    } else if limit.is_exhausted() {
+        Err(BgfxError::LimitExceeded(limit))
+    } else {
+        Err(BgfxError::Unknown(limit))
+    }
+}
+
+/// Returns `true` if `data` starts with the magic of a vertex, fragment or compute shader
+/// compiled by shaderc. bgfx accepts all versions of the format, so the version isn't checked.
+fn is_shader_binary(data: &[u8]) -> bool {
+    matches!(data, [b'V' | b'F' | b'C', b'S', b'H', _, ..])
+}
+
+impl DynamicIndexBuffer {
+    /// Fallible version of [DynamicIndexBuffer::create_dynamic_index_buffer].
+    pub fn try_create_dynamic_index_buffer(
+        num: u32,
+        flags: BufferFlags,
+    ) -> Result<DynamicIndexBuffer, BgfxError> {
+        let handle = DynamicIndexBuffer::create_dynamic_index_buffer(num, flags);
+        check(handle.is_valid(), handle, CapsLimit::MaxDynamicIndexBuffers)
+    }
+
+    /// Fallible version of [DynamicIndexBuffer::create_dynamic_index_buffer_mem].
+    pub fn try_create_dynamic_index_buffer_mem(
+        mem: &Memory,
+        flags: BufferFlags,
+    ) -> Result<DynamicIndexBuffer, BgfxError> {
+        let handle = DynamicIndexBuffer::create_dynamic_index_buffer_mem(mem, flags);
+        check(handle.is_valid(), handle, CapsLimit::MaxDynamicIndexBuffers)
+    }
+}
+
+impl DynamicVertexBuffer {
+    /// Fallible version of [DynamicVertexBuffer::create_dynamic_vertex_buffer].
+    pub fn try_create_dynamic_vertex_buffer(
+        num: u32,
+        layout: &BuiltVertexLayout,
+        flags: BufferFlags,
+    ) -> Result<DynamicVertexBuffer, BgfxError> {
+        let handle = DynamicVertexBuffer::create_dynamic_vertex_buffer(num, layout, flags);
+        check(
+            handle.is_valid(),
+            handle,
+            CapsLimit::MaxDynamicVertexBuffers,
+        )
+    }
+
+    /// Fallible version of [DynamicVertexBuffer::create_dynamic_vertex_buffer_mem].
+    pub fn try_create_dynamic_vertex_buffer_mem(
+        mem: &Memory,
+        layout: &BuiltVertexLayout,
+        flags: BufferFlags,
+    ) -> Result<DynamicVertexBuffer, BgfxError> {
+        let handle = DynamicVertexBuffer::create_dynamic_vertex_buffer_mem(mem, layout, flags);
+        check(
+            handle.is_valid(),
+            handle,
+            CapsLimit::MaxDynamicVertexBuffers,
+        )
+    }
+}
+
+impl FrameBuffer {
+    /// Fallible version of [FrameBuffer::create_frame_buffer].
+    pub fn try_create_frame_buffer(
+        width: u16,
+        height: u16,
+        format: TextureFormat,
+        texture_flags: TextureFlags,
+    ) -> Result<FrameBuffer, BgfxError> {
+        let handle = FrameBuffer::create_frame_buffer(width, height, format, texture_flags);
+        check(handle.is_valid(), handle, CapsLimit::MaxFrameBuffers)
+    }
+
+    /// Fallible version of [FrameBuffer::create_frame_buffer_scaled].
+    pub fn try_create_frame_buffer_scaled(
+        ratio: BackbufferRatio,
+        format: TextureFormat,
+        texture_flags: TextureFlags,
+    ) -> Result<FrameBuffer, BgfxError> {
+        let handle = FrameBuffer::create_frame_buffer_scaled(ratio, format, texture_flags);
+        check(handle.is_valid(), handle, CapsLimit::MaxFrameBuffers)
+    }
+
+    /// Fallible version of [FrameBuffer::create_frame_buffer_from_handles].
+    pub fn try_create_frame_buffer_from_handles(
+        num: u8,
+        handles: &Texture,
+        destroy_texture: bool,
+    ) -> Result<FrameBuffer, BgfxError> {
+        let handle = FrameBuffer::create_frame_buffer_from_handles(num, handles, destroy_texture);
+        check(handle.is_valid(), handle, CapsLimit::MaxFrameBuffers)
+    }
+
+    /// Fallible version of [FrameBuffer::create_frame_buffer_from_attachment].
+    pub fn try_create_frame_buffer_from_attachment(
+        num: u8,
+        attachment: &Attachment,
+        destroy_texture: bool,
+    ) -> Result<FrameBuffer, BgfxError> {
+        let handle =
+            FrameBuffer::create_frame_buffer_from_attachment(num, attachment, destroy_texture);
+        check(handle.is_valid(), handle, CapsLimit::MaxFrameBuffers)
+    }
+
+    /// Fallible version of [FrameBuffer::create_frame_buffer_from_nwh].
+    pub fn try_create_frame_buffer_from_nwh(
+        nwh: *const c_void,
+        width: u16,
+        height: u16,
+        params: CreateFrameBufferFromNwhArgs,
+    ) -> Result<FrameBuffer, BgfxError> {
+        let handle = FrameBuffer::create_frame_buffer_from_nwh(nwh, width, height, params);
+        check(handle.is_valid(), handle, CapsLimit::MaxFrameBuffers)
+    }
+}
+
+impl IndexBuffer {
+    /// Fallible version of [IndexBuffer::create_index_buffer].
+    pub fn try_create_index_buffer(
+        mem: &Memory,
+        flags: BufferFlags,
+    ) -> Result<IndexBuffer, BgfxError> {
+        let handle = IndexBuffer::create_index_buffer(mem, flags);
+        check(handle.is_valid(), handle, CapsLimit::MaxIndexBuffers)
+    }
+}
+
+impl IndirectBuffer {
+    /// Fallible version of [IndirectBuffer::create_indirect_buffer].
+    pub fn try_create_indirect_buffer(num: u32) -> Result<IndirectBuffer, BgfxError> {
+        let handle = IndirectBuffer::create_indirect_buffer(num);
+        check(handle.is_valid(), handle, CapsLimit::MaxVertexBuffers)
+    }
+}
+
+impl OcclusionQuery {
+    /// Fallible version of [OcclusionQuery::create_occlusion_query].
+    pub fn try_create_occlusion_query() -> Result<OcclusionQuery, BgfxError> {
+        let handle = OcclusionQuery::create_occlusion_query();
+        check(handle.is_valid(), handle, CapsLimit::MaxOcclusionQueries)
+    }
+}
+
+impl Program {
+    /// Fallible version of [Program::create_program].
+    pub fn try_create_program(
+        vsh: &Shader,
+        fsh: &Shader,
+        destroy_shaders: bool,
+    ) -> Result<Program, BgfxError> {
+        let handle = Program::create_program(vsh, fsh, destroy_shaders);
+        check(handle.is_valid(), handle, CapsLimit::MaxPrograms)
+    }
+
+    /// Fallible version of [Program::create_compute_program].
+    pub fn try_create_compute_program(
+        csh: &Shader,
+        destroy_shaders: bool,
+    ) -> Result<Program, BgfxError> {
+        let handle = Program::create_compute_program(csh, destroy_shaders);
+        check(handle.is_valid(), handle, CapsLimit::MaxPrograms)
+    }
+}
+
+impl Shader {
+    /// Fallible version of [Shader::create_shader].
+    pub fn try_create_shader(mem: &Memory) -> Result<Shader, BgfxError> {
+        let is_shader = is_shader_binary(mem.as_slice());
+        let handle = Shader::create_shader(mem);
+        if !handle.is_valid() && !is_shader {
+            return Err(BgfxError::InvalidShader);
+        }
+        check(handle.is_valid(), handle, CapsLimit::MaxShaders)
+    }
+}
+
+impl Texture {
+    /// Fallible version of [Texture::create_texture].
+    pub fn try_create_texture(
+        mem: &Memory,
+        flags: impl Into<TextureFlags>,
+        skip: u8,
+        info: &mut TextureInfo,
+    ) -> Result<Texture, BgfxError> {
+        let handle = Texture::create_texture(mem, flags, skip, info);
+        check(handle.is_valid(), handle, CapsLimit::MaxTextures)
+    }
+
+    /// Fallible version of [Texture::create_texture_2d].
+    pub fn try_create_texture_2d(
+        width: u16,
+        height: u16,
+        has_mips: bool,
+        num_layers: u16,
+        format: TextureFormat,
+        flags: impl Into<TextureFlags>,
+        mem: &Memory,
+    ) -> Result<Texture, BgfxError> {
+        let handle =
+            Texture::create_texture_2d(width, height, has_mips, num_layers, format, flags, mem);
+        check(handle.is_valid(), handle, CapsLimit::MaxTextures)
+    }
+
+    /// Fallible version of [Texture::create_texture_2d_scaled].
+    pub fn try_create_texture_2d_scaled(
+        ratio: BackbufferRatio,
+        has_mips: bool,
+        num_layers: u16,
+        format: TextureFormat,
+        flags: impl Into<TextureFlags>,
+    ) -> Result<Texture, BgfxError> {
+        let handle = Texture::create_texture_2d_scaled(ratio, has_mips, num_layers, format, flags);
+        check(handle.is_valid(), handle, CapsLimit::MaxTextures)
+    }
+
+    /// Fallible version of [Texture::create_texture_3d].
+    pub fn try_create_texture_3d(
+        width: u16,
+        height: u16,
+        depth: u16,
+        has_mips: bool,
+        format: TextureFormat,
+        params: CreateTexture3DArgs,
+    ) -> Result<Texture, BgfxError> {
+        let handle = Texture::create_texture_3d(width, height, depth, has_mips, format, params);
+        check(handle.is_valid(), handle, CapsLimit::MaxTextures)
+    }
+
+    /// Fallible version of [Texture::create_texture_cube].
+    pub fn try_create_texture_cube(
+        size: u16,
+        has_mips: bool,
+        num_layers: u16,
+        format: TextureFormat,
+        params: CreateTextureCubeArgs,
+    ) -> Result<Texture, BgfxError> {
+        let handle = Texture::create_texture_cube(size, has_mips, num_layers, format, params);
+        check(handle.is_valid(), handle, CapsLimit::MaxTextures)
+    }
+}
+
+impl Uniform {
+    /// Fallible version of [Uniform::create].
+    pub fn try_create(name: &str, type_r: UniformType, num: u16) -> Result<Uniform, BgfxError> {
+        let handle = Uniform::create(name, type_r, num);
+        check(handle.is_valid(), handle, CapsLimit::MaxUniforms)
+    }
+}
+
+impl VertexBuffer {
+    /// Fallible version of [VertexBuffer::create_vertex_buffer].
+    pub fn try_create_vertex_buffer(
+        mem: &Memory,
+        layout: &BuiltVertexLayout,
+        flags: BufferFlags,
+    ) -> Result<VertexBuffer, BgfxError> {
+        let handle = VertexBuffer::create_vertex_buffer(mem, layout, flags);
+        check(handle.is_valid(), handle, CapsLimit::MaxVertexBuffers)
+    }
+}
+
+impl VertexLayout {
+    /// Fallible version of [VertexLayout::create_vertex_layout].
+    pub fn try_create_vertex_layout(layout: &BuiltVertexLayout) -> Result<VertexLayout, BgfxError> {
+        let handle = VertexLayout::create_vertex_layout(layout);
+        check(handle.is_valid(), handle, CapsLimit::MaxVertexLayouts)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shader_magic() {
+        assert!(is_shader_binary(b"VSH\x0b\0\0\0\0"));
+        assert!(is_shader_binary(b"FSH\x05"));
+        assert!(is_shader_binary(b"CSH\x03"));
+        assert!(!is_shader_binary(b"VSH"));
+        assert!(!is_shader_binary(b"GSH\x05"));
+        assert!(!is_shader_binary(b"void main() {}"));
+        assert!(!is_shader_binary(&[]));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            BgfxError::LimitExceeded(CapsLimit::MaxTextures).to_string(),
+            "bgfx limit MaxTextures exceeded"
+        );
+        assert_eq!(
+            BgfxError::Unknown(CapsLimit::MaxShaders).to_string(),
+            "bgfx failed to create the resource, limit MaxShaders may have been exceeded"
+        );
+    }
+}
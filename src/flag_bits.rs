@@ -0,0 +1,42 @@
+use std::fmt;
+
+/// Settings encoded into bgfx flag words, such as [SamplerDesc](crate::bgfx::SamplerDesc),
+/// [RenderState](crate::bgfx::RenderState) and [StencilState](crate::bgfx::StencilState).
+pub(crate) trait FlagBits: Sized + PartialEq {
+    type Bits: Copy + PartialEq;
+
+    /// Encodes the settings into flag words.
+    fn encode(&self) -> Self::Bits;
+
+    /// Decodes the settings from the fields of `bits`. Returns `None` for values of a field
+    /// that no setting has, but ignores bits that no field reads.
+    fn decode(bits: Self::Bits) -> Option<Self>;
+
+    /// Decodes `bits` only if they encode exactly the decoded settings. Each setting is read
+    /// from its own field, so unknown values and bits outside of any field are lost when
+    /// decoding, as are settings that bgfx ignores in combination with others. Encoding the
+    /// decoded settings again catches all of them in one place.
+    fn decode_exact(bits: Self::Bits) -> Option<Self> {
+        Self::decode(bits).filter(|decoded| decoded.encode() == bits)
+    }
+}
+
+/// Writes the settings that differ from the default as `parts` joined by commas, or `default`
+/// if there are none.
+pub(crate) fn write_settings(
+    f: &mut fmt::Formatter<'_>,
+    parts: &[String],
+    default: &str,
+) -> fmt::Result {
+    if parts.is_empty() {
+        write!(f, "{}", default)
+    } else {
+        write!(f, "{}", parts.join(", "))
+    }
+}
+
+/// Asserts that `value` survives encoding and decoding exactly.
+#[cfg(test)]
+pub(crate) fn assert_round_trip<T: FlagBits + Copy + fmt::Display + fmt::Debug>(value: T) {
+    assert_eq!(T::decode_exact(value.encode()), Some(value), "{}", value);
+}
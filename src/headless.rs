@@ -0,0 +1,267 @@
+use crate::bgfx::{
+    self, AddressMode, Bgfx, BlitArgs, CapsFlags, FrameBuffer, Init, InitError, PciIdFlags,
+    PlatformData, RendererType, SamplerDesc, Texture, TextureFlags, TextureFormat, TextureRtFlags,
+    ViewId,
+};
+use std::ops::{Deref, DerefMut};
+
+/// Image with 8-bit RGBA pixels, stored row by row from the top left.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct RgbaImage {
+    /// Width in pixels.
+    pub width: u32,
+    /// Height in pixels.
+    pub height: u32,
+    /// `width * height * 4` bytes of pixel data.
+    pub data: Vec<u8>,
+}
+
+impl RgbaImage {
+    /// Creates an image from `data`.
+    ///
+    /// Panics if `data` is not `width * height * 4` bytes long.
+    pub fn new(width: u32, height: u32, data: Vec<u8>) -> RgbaImage {
+        assert_eq!(
+            data.len(),
+            width as usize * height as usize * 4,
+            "image data doesn't match its size"
+        );
+        RgbaImage {
+            width,
+            height,
+            data,
+        }
+    }
+
+    /// Returns the pixel at `x`, `y`.
+    ///
+    /// Panics if the pixel is out of bounds.
+    pub fn pixel(&self, x: u32, y: u32) -> [u8; 4] {
+        assert!(x < self.width && y < self.height, "pixel out of bounds");
+        let offset = (y as usize * self.width as usize + x as usize) * 4;
+        let mut pixel = [0; 4];
+        pixel.copy_from_slice(&self.data[offset..offset + 4]);
+        pixel
+    }
+}
+
+/// Renderer used by [Headless::init] on this platform, which supports creating a device
+/// without a window.
+fn default_renderer() -> RendererType {
+    if cfg!(target_os = "windows") {
+        RendererType::Direct3D11
+    } else if cfg!(any(target_os = "macos", target_os = "ios")) {
+        RendererType::Metal
+    } else {
+        RendererType::Vulkan
+    }
+}
+
+/// bgfx rendering into an offscreen frame buffer without a window, such as for tests on
+/// machines without a display. Derefs to the [Bgfx] context it owns.
+///
+/// bgfx runs on the calling thread, so each frame is rendered when [Bgfx::frame] is called.
+/// Bind the frame buffer to the views to render with [Headless::set_view], and read the result
+/// back with [Headless::read_rgba]:
+///
+/// ```ignore
+/// let mut headless = Headless::init(256, 256)?;
+/// headless.set_view(0);
+/// bgfx::set_view_clear(0, ClearFlags::COLOR, SetViewClearArgs::default());
+/// bgfx::touch(0);
+/// let image = headless.read_rgba().unwrap();
+/// ```
+///
+/// The last view is used to copy the frame buffer for reading, so it shouldn't be used for
+/// rendering.
+pub struct Headless {
+    frame_buffer: FrameBuffer,
+    color: Texture,
+    depth: Texture,
+    readback: Texture,
+    width: u16,
+    height: u16,
+    blit_view: ViewId,
+    // Dropped last, so the resources above are destroyed before bgfx shuts down.
+    ctx: Bgfx,
+}
+
+impl Headless {
+    /// Initializes bgfx without a window, rendering into a frame buffer of `width` by
+    /// `height`. See [Headless::init_with] for the renderers that are tried.
+    pub fn init(width: u16, height: u16) -> Result<Headless, InitError> {
+        let mut init = Init::new();
+        init.type_r = default_renderer();
+        Headless::init_with(init, width, height)
+    }
+
+    /// Initializes bgfx with `init` but without a window, rendering into a frame buffer of
+    /// `width` by `height`.
+    ///
+    /// If the renderer in `init` fails to initialize, such as on machines without a GPU, it is
+    /// tried again with the software rasterizer of the platform if there is one, and finally
+    /// with [RendererType::Noop]. Check [Bgfx::renderer_type] for the one in use, as nothing
+    /// is drawn with the `Noop` renderer.
+    pub fn init_with(mut init: Init, width: u16, height: u16) -> Result<Headless, InitError> {
+        init.platform_data = PlatformData::new();
+        init.resolution.width = width as u32;
+        init.resolution.height = height as u32;
+
+        let mut ctx = Headless::try_init(&init);
+        if matches!(ctx, Err(InitError::Failed)) && init.type_r != RendererType::Noop {
+            init.vendor_id = PciIdFlags::SOFTWARE_RASTERIZER;
+            ctx = Headless::try_init(&init);
+        }
+        if matches!(ctx, Err(InitError::Failed)) {
+            init.type_r = RendererType::Noop;
+            init.vendor_id = PciIdFlags::NONE;
+            ctx = Headless::try_init(&init);
+        }
+        let ctx = ctx?;
+
+        let sampler = SamplerDesc::default()
+            .u(AddressMode::Clamp)
+            .v(AddressMode::Clamp)
+            .texture_flags();
+        let color = Texture::create_texture_2d(
+            width,
+            height,
+            false,
+            1,
+            TextureFormat::RGBA8,
+            TextureFlags::RT | sampler,
+            &bgfx::Memory::new(),
+        );
+        let depth = Texture::create_texture_2d(
+            width,
+            height,
+            false,
+            1,
+            TextureFormat::D24S8,
+            TextureFlags::RT | TextureRtFlags::WRITE_ONLY.into(),
+            &bgfx::Memory::new(),
+        );
+        let readback = Texture::create_texture_2d(
+            width,
+            height,
+            false,
+            1,
+            TextureFormat::RGBA8,
+            TextureFlags::BLIT_DST | TextureFlags::READ_BACK | sampler,
+            &bgfx::Memory::new(),
+        );
+        let frame_buffer = FrameBuffer::create_frame_buffer_from_textures(&[&color, &depth]);
+        if !frame_buffer.is_valid() {
+            return Err(InitError::Failed);
+        }
+
+        Ok(Headless {
+            frame_buffer,
+            color,
+            depth,
+            readback,
+            width,
+            height,
+            blit_view: (ctx.caps().limits.max_views - 1) as ViewId,
+            ctx,
+        })
+    }
+
+    /// Initializes bgfx on the calling thread.
+    fn try_init(init: &Init) -> Result<Bgfx, InitError> {
+        bgfx::render_frame(-1);
+        Bgfx::init(init)
+    }
+
+    /// Returns the width of the frame buffer.
+    pub fn width(&self) -> u16 {
+        self.width
+    }
+
+    /// Returns the height of the frame buffer.
+    pub fn height(&self) -> u16 {
+        self.height
+    }
+
+    /// Returns the frame buffer that is rendered into.
+    pub fn frame_buffer(&self) -> &FrameBuffer {
+        &self.frame_buffer
+    }
+
+    /// Returns the color texture of the frame buffer.
+    pub fn color_texture(&self) -> &Texture {
+        &self.color
+    }
+
+    /// Returns the depth stencil texture of the frame buffer.
+    pub fn depth_texture(&self) -> &Texture {
+        &self.depth
+    }
+
+    /// Renders `view` into the frame buffer, covering all of it.
+    pub fn set_view(&self, view: ViewId) {
+        bgfx::set_view_frame_buffer(view, &self.frame_buffer);
+        bgfx::set_view_rect(view, 0, 0, self.width, self.height);
+    }
+
+    /// Renders the submitted frame and reads back the color texture of the frame buffer.
+    ///
+    /// Returns `None` if the renderer doesn't support [CapsFlags::TEXTURE_BLIT] and
+    /// [CapsFlags::TEXTURE_READ_BACK]. The image is blank with the `Noop` renderer.
+    pub fn read_rgba(&mut self) -> Option<RgbaImage> {
+        let required = CapsFlags::TEXTURE_BLIT.bits() | CapsFlags::TEXTURE_READ_BACK.bits();
+        if self.ctx.caps().supported & required != required {
+            return None;
+        }
+
+        bgfx::blit(
+            self.blit_view,
+            &self.readback,
+            0,
+            0,
+            0,
+            0,
+            &self.color,
+            BlitArgs {
+                width: self.width,
+                height: self.height,
+                ..Default::default()
+            },
+        );
+        let mut read = self.readback.read(0)?;
+        // The read completes within two frames when bgfx runs on this thread.
+        for _ in 0..4 {
+            self.ctx.frame(false);
+            read = match read.into_data() {
+                Ok(data) => return Some(self.to_image(data)),
+                Err(read) => read,
+            };
+        }
+        None
+    }
+
+    /// Turns pixels read from the frame buffer into an image, flipping the rows if the
+    /// renderer stores them from the bottom left.
+    fn to_image(&self, mut data: Vec<u8>) -> RgbaImage {
+        if self.ctx.caps().origin_bottom_left {
+            let row = self.width as usize * 4;
+            let rows: Vec<_> = data.chunks_exact(row).rev().flatten().copied().collect();
+            data = rows;
+        }
+        RgbaImage::new(self.width as u32, self.height as u32, data)
+    }
+}
+
+impl Deref for Headless {
+    type Target = Bgfx;
+
+    fn deref(&self) -> &Bgfx {
+        &self.ctx
+    }
+}
+
+impl DerefMut for Headless {
+    fn deref_mut(&mut self) -> &mut Bgfx {
+        &mut self.ctx
+    }
+}
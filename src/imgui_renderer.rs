@@ -0,0 +1,173 @@
+use crate::bgfx::{
+    state_blend_alpha, EncoderGuard, Memory, SamplerDesc, ShaderBundleError, StateFlags,
+    StateWriteFlags, Texture, TextureFormat, TransientVertexBuffer, ViewId,
+};
+use crate::ui::{scissor, UiPipeline, UiVertex};
+use imgui::internal::RawWrapper;
+use imgui::{DrawCmd, DrawCmdParams, DrawData, TextureId, Textures};
+
+/// Renders [Dear ImGui](https://github.com/ocornut/imgui) draw data with bgfx.
+///
+/// The font atlas of the context is uploaded as a [Texture] on creation. Other textures are
+/// registered with [ImguiRenderer::textures] and referenced by their [TextureId] in the UI, such
+/// as with `imgui::Image`.
+///
+/// ```ignore
+/// let mut imgui = imgui::Context::create();
+/// let mut renderer = ImguiRenderer::new(&mut imgui, 255)?;
+/// // Each frame:
+/// let ui = imgui.new_frame();
+/// ui.text("Hello");
+/// let encoder = ctx.encoder().unwrap();
+/// renderer.render(&encoder, imgui.render());
+/// ```
+pub struct ImguiRenderer {
+    pipeline: UiPipeline,
+    textures: Textures<Texture>,
+    font_texture: TextureId,
+    view: ViewId,
+}
+
+impl ImguiRenderer {
+    /// Creates the renderer drawing into `view`, and uploads the font atlas of `ctx`.
+    pub fn new(ctx: &mut imgui::Context, view: ViewId) -> Result<ImguiRenderer, ShaderBundleError> {
+        let pipeline = UiPipeline::new()?;
+        ctx.set_renderer_name(Some(format!("bgfx-rs {}", env!("CARGO_PKG_VERSION"))));
+        ctx.io_mut().backend_flags |= imgui::BackendFlags::RENDERER_HAS_VTX_OFFSET;
+
+        let mut renderer = ImguiRenderer {
+            pipeline,
+            textures: Textures::new(),
+            font_texture: TextureId::new(usize::MAX),
+            view,
+        };
+        renderer.reload_font_texture(ctx);
+        Ok(renderer)
+    }
+
+    /// Uploads the font atlas of `ctx` again, after fonts have been added or changed.
+    pub fn reload_font_texture(&mut self, ctx: &mut imgui::Context) {
+        let fonts = ctx.fonts();
+        let atlas = fonts.build_rgba32_texture();
+        let texture = Texture::create_texture_2d(
+            atlas.width as u16,
+            atlas.height as u16,
+            false,
+            1,
+            TextureFormat::RGBA8,
+            SamplerDesc::DEFAULT,
+            &Memory::copy(atlas.data),
+        );
+        self.textures.remove(self.font_texture);
+        self.font_texture = self.textures.insert(texture);
+        fonts.tex_id = self.font_texture;
+    }
+
+    /// Returns the textures that can be drawn by their [TextureId], such as with `imgui::Image`.
+    pub fn textures(&mut self) -> &mut Textures<Texture> {
+        &mut self.textures
+    }
+
+    /// Returns the view the UI is drawn into.
+    pub fn view(&self) -> ViewId {
+        self.view
+    }
+
+    /// Sets the view the UI is drawn into.
+    pub fn set_view(&mut self, view: ViewId) {
+        self.view = view;
+    }
+
+    /// Draws `draw_data` into the view, which is set up to cover the whole UI.
+    ///
+    /// Draw lists that don't fit in the transient buffers of the frame are skipped.
+    pub fn render(&self, encoder: &EncoderGuard<'_>, draw_data: &DrawData) {
+        let [x, y] = draw_data.display_pos;
+        let [width, height] = draw_data.display_size;
+        let [scale_x, scale_y] = draw_data.framebuffer_scale;
+        let fb_width = width * scale_x;
+        let fb_height = height * scale_y;
+        // `draw_lists` can't be called without any lists, as their array is null then.
+        if fb_width <= 0.0 || fb_height <= 0.0 || draw_data.draw_lists_count() == 0 {
+            return;
+        }
+
+        self.pipeline.setup_view(
+            self.view,
+            [fb_width, fb_height],
+            [x, y, x + width, y + height],
+        );
+
+        let state = StateWriteFlags::RGB.bits()
+            | StateWriteFlags::A.bits()
+            | StateFlags::MSAA.bits()
+            | state_blend_alpha();
+
+        for draw_list in draw_data.draw_lists() {
+            let vertices = draw_list.vtx_buffer();
+            let indices = draw_list.idx_buffer();
+            let (mut tvb, mut tib) = match TransientVertexBuffer::alloc_with_indices(
+                encoder.frame(),
+                vertices.len() as u32,
+                self.pipeline.layout(),
+                indices.len() as u32,
+                false,
+            ) {
+                Some(buffers) => buffers,
+                None => continue,
+            };
+            for (dst, src) in tvb.as_mut_slice::<UiVertex>().iter_mut().zip(vertices) {
+                *dst = UiVertex {
+                    pos: src.pos,
+                    uv: src.uv,
+                    col: src.col,
+                };
+            }
+            tib.as_mut_slice::<u16>().copy_from_slice(indices);
+
+            for cmd in draw_list.commands() {
+                match cmd {
+                    DrawCmd::Elements {
+                        count,
+                        cmd_params:
+                            DrawCmdParams {
+                                clip_rect,
+                                texture_id,
+                                vtx_offset,
+                                idx_offset,
+                            },
+                    } => {
+                        let texture = match self.textures.get(texture_id) {
+                            Some(texture) => texture,
+                            None => continue,
+                        };
+                        let clip_rect = [
+                            (clip_rect[0] - x) * scale_x,
+                            (clip_rect[1] - y) * scale_y,
+                            (clip_rect[2] - x) * scale_x,
+                            (clip_rect[3] - y) * scale_y,
+                        ];
+                        let scissor = match scissor(clip_rect, [fb_width, fb_height]) {
+                            Some(scissor) => scissor,
+                            None => continue,
+                        };
+
+                        encoder.set_transient_vertex_buffer(
+                            0,
+                            &tvb,
+                            vtx_offset as u32,
+                            (vertices.len() - vtx_offset) as u32,
+                        );
+                        encoder.set_transient_index_buffer(&tib, idx_offset as u32, count as u32);
+                        self.pipeline
+                            .submit(encoder, self.view, scissor, state, texture);
+                    }
+                    DrawCmd::ResetRenderState => {}
+                    DrawCmd::RawCallback { callback, raw_cmd } => unsafe {
+                        callback(draw_list.raw(), raw_cmd)
+                    },
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,126 @@
+use std::marker::PhantomData;
+use std::sync::atomic::{AtomicPtr, Ordering};
+use std::sync::Mutex;
+
+/// Interfaces of type `T` boxed for bgfx, such as the callback and allocator stored in `Init`,
+/// and the one bgfx was initialized with.
+///
+/// Each interface is counted: one reference from the `Init` holding it, and one from bgfx while
+/// it's initialized with it. Pointers that weren't boxed by [Interfaces::add], such as null
+/// or a user supplied C++ interface, are left alone.
+pub(crate) struct Interfaces<T> {
+    /// Interfaces that haven't been freed yet, with their number of references.
+    owned: Mutex<Vec<(usize, usize)>>,
+    /// Interface bgfx was initialized with, released on shutdown.
+    active: AtomicPtr<u8>,
+    _interface: PhantomData<fn(T)>,
+}
+
+impl<T> Interfaces<T> {
+    pub(crate) const fn new() -> Interfaces<T> {
+        Interfaces {
+            owned: Mutex::new(Vec::new()),
+            active: AtomicPtr::new(std::ptr::null_mut()),
+            _interface: PhantomData,
+        }
+    }
+
+    /// Boxes `interface` so it can be passed to bgfx, holding one reference to it.
+    pub(crate) fn add(&self, interface: T) -> *const u8 {
+        let ptr = Box::into_raw(Box::new(interface)) as *const u8;
+        self.owned.lock().unwrap().push((ptr as usize, 1));
+        ptr
+    }
+
+    /// Adds a reference to an interface created by [Interfaces::add].
+    fn retain(&self, ptr: *const u8) {
+        let mut owned = self.owned.lock().unwrap();
+        if let Some((_, refs)) = owned.iter_mut().find(|(p, _)| *p == ptr as usize) {
+            *refs += 1;
+        }
+    }
+
+    /// Removes a reference to an interface created by [Interfaces::add], freeing it once
+    /// there are none left.
+    pub(crate) fn release(&self, ptr: *const u8) {
+        let mut owned = self.owned.lock().unwrap();
+        if let Some(index) = owned.iter().position(|(p, _)| *p == ptr as usize) {
+            owned[index].1 -= 1;
+            if owned[index].1 == 0 {
+                owned.swap_remove(index);
+                drop(owned);
+                drop(unsafe { Box::from_raw(ptr as *mut T) });
+            }
+        }
+    }
+
+    /// Remembers the interface bgfx was successfully initialized with, holding a reference to
+    /// it until [Interfaces::release_active].
+    pub(crate) fn set_active(&self, ptr: *const u8) {
+        self.retain(ptr);
+        let old = self.active.swap(ptr as *mut u8, Ordering::AcqRel);
+        self.release(old);
+    }
+
+    /// Releases the interface bgfx was initialized with. Must be called after `bgfx_shutdown`.
+    pub(crate) fn release_active(&self) {
+        let old = self.active.swap(std::ptr::null_mut(), Ordering::AcqRel);
+        self.release(old);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::AtomicUsize;
+
+    /// Counts how many times it was dropped.
+    struct Counted<'a>(&'a AtomicUsize);
+
+    impl Drop for Counted<'_> {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn freed_after_last_release() {
+        let interfaces = Interfaces::new();
+        let drops = AtomicUsize::new(0);
+
+        let ptr = interfaces.add(Counted(&drops));
+        interfaces.set_active(ptr);
+        interfaces.release(ptr);
+        assert_eq!(drops.load(Ordering::SeqCst), 0);
+        interfaces.release_active();
+        assert_eq!(drops.load(Ordering::SeqCst), 1);
+        assert!(interfaces.owned.lock().unwrap().is_empty());
+    }
+
+    #[test]
+    fn replacing_active_releases_old() {
+        let interfaces = Interfaces::new();
+        let drops = AtomicUsize::new(0);
+
+        let first = interfaces.add(Counted(&drops));
+        interfaces.set_active(first);
+        interfaces.release(first);
+        let second = interfaces.add(Counted(&drops));
+        interfaces.set_active(second);
+        assert_eq!(drops.load(Ordering::SeqCst), 1);
+        interfaces.release(second);
+        interfaces.release_active();
+        assert_eq!(drops.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn ignores_foreign_pointers() {
+        let interfaces = Interfaces::<u32>::new();
+        let foreign = 0x1000 as *const u8;
+        interfaces.set_active(foreign);
+        interfaces.release(foreign);
+        interfaces.release_active();
+        interfaces.release(std::ptr::null());
+        assert!(interfaces.owned.lock().unwrap().is_empty());
+    }
+}
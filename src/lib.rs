@@ -1,7 +1,43 @@
 #[macro_use]
 extern crate bitflags;
 
+mod allocator;
+mod buffer;
+mod callback;
+mod color;
+mod context;
+mod debug_draw;
+#[cfg(feature = "egui")]
+mod egui_painter;
+mod error;
+mod flag_bits;
+mod headless;
+#[cfg(feature = "imgui")]
+mod imgui_renderer;
+mod interface;
+mod math;
+mod pending;
+mod render_state;
+mod sampler;
+mod shader;
+mod stencil_state;
+#[cfg(any(feature = "imgui", feature = "egui"))]
+mod ui;
+mod vertex;
+#[cfg(feature = "raw-window-handle")]
+mod window;
+
 #[cfg(not(feature = "shared-api"))]
 pub mod static_lib;
 #[cfg(not(feature = "shared-api"))]
 pub use static_lib as bgfx;
+
+#[cfg(feature = "shared-api")]
+mod shared_api;
+#[cfg(feature = "shared-api")]
+pub mod shared_lib;
+#[cfg(feature = "shared-api")]
+pub use shared_lib as bgfx;
+
+#[cfg(feature = "testing")]
+pub mod testing;
@@ -0,0 +1,201 @@
+#[cfg_attr(not(any(feature = "imgui", feature = "egui")), allow(dead_code))]
+pub(crate) const IDENTITY: [f32; 16] = [
+    1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 0.0, 1.0,
+];
+
+/// Column major orthographic projection with a depth range of 0 to 1000.
+#[cfg_attr(not(any(feature = "imgui", feature = "egui")), allow(dead_code))]
+pub(crate) fn ortho(
+    left: f32,
+    right: f32,
+    bottom: f32,
+    top: f32,
+    homogeneous_depth: bool,
+) -> [f32; 16] {
+    let (near, far) = (0.0, 1000.0);
+    let (depth_scale, depth_offset) = if homogeneous_depth {
+        (2.0 / (far - near), (near + far) / (near - far))
+    } else {
+        (1.0 / (far - near), near / (near - far))
+    };
+    [
+        2.0 / (right - left),
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        2.0 / (top - bottom),
+        0.0,
+        0.0,
+        0.0,
+        0.0,
+        depth_scale,
+        0.0,
+        (left + right) / (left - right),
+        (top + bottom) / (bottom - top),
+        depth_offset,
+        1.0,
+    ]
+}
+
+/// Transforms `p` by the column major matrix `m`, including the perspective divide.
+pub(crate) fn transform_point(m: &[f32; 16], p: [f32; 3]) -> [f32; 3] {
+    let mut out = [0.0; 4];
+    for (row, o) in out.iter_mut().enumerate() {
+        *o = m[row] * p[0] + m[4 + row] * p[1] + m[8 + row] * p[2] + m[12 + row];
+    }
+    [out[0] / out[3], out[1] / out[3], out[2] / out[3]]
+}
+
+/// Inverts the 4x4 matrix `m`, or returns `None` if it's not invertible.
+pub(crate) fn invert(m: &[f32; 16]) -> Option<[f32; 16]> {
+    let mut inv = [0.0; 16];
+    inv[0] = m[5] * m[10] * m[15] - m[5] * m[11] * m[14] - m[9] * m[6] * m[15]
+        + m[9] * m[7] * m[14]
+        + m[13] * m[6] * m[11]
+        - m[13] * m[7] * m[10];
+    inv[4] = -m[4] * m[10] * m[15] + m[4] * m[11] * m[14] + m[8] * m[6] * m[15]
+        - m[8] * m[7] * m[14]
+        - m[12] * m[6] * m[11]
+        + m[12] * m[7] * m[10];
+    inv[8] = m[4] * m[9] * m[15] - m[4] * m[11] * m[13] - m[8] * m[5] * m[15]
+        + m[8] * m[7] * m[13]
+        + m[12] * m[5] * m[11]
+        - m[12] * m[7] * m[9];
+    inv[12] = -m[4] * m[9] * m[14] + m[4] * m[10] * m[13] + m[8] * m[5] * m[14]
+        - m[8] * m[6] * m[13]
+        - m[12] * m[5] * m[10]
+        + m[12] * m[6] * m[9];
+    inv[1] = -m[1] * m[10] * m[15] + m[1] * m[11] * m[14] + m[9] * m[2] * m[15]
+        - m[9] * m[3] * m[14]
+        - m[13] * m[2] * m[11]
+        + m[13] * m[3] * m[10];
+    inv[5] = m[0] * m[10] * m[15] - m[0] * m[11] * m[14] - m[8] * m[2] * m[15]
+        + m[8] * m[3] * m[14]
+        + m[12] * m[2] * m[11]
+        - m[12] * m[3] * m[10];
+    inv[9] = -m[0] * m[9] * m[15] + m[0] * m[11] * m[13] + m[8] * m[1] * m[15]
+        - m[8] * m[3] * m[13]
+        - m[12] * m[1] * m[11]
+        + m[12] * m[3] * m[9];
+    inv[13] = m[0] * m[9] * m[14] - m[0] * m[10] * m[13] - m[8] * m[1] * m[14]
+        + m[8] * m[2] * m[13]
+        + m[12] * m[1] * m[10]
+        - m[12] * m[2] * m[9];
+    inv[2] = m[1] * m[6] * m[15] - m[1] * m[7] * m[14] - m[5] * m[2] * m[15]
+        + m[5] * m[3] * m[14]
+        + m[13] * m[2] * m[7]
+        - m[13] * m[3] * m[6];
+    inv[6] = -m[0] * m[6] * m[15] + m[0] * m[7] * m[14] + m[4] * m[2] * m[15]
+        - m[4] * m[3] * m[14]
+        - m[12] * m[2] * m[7]
+        + m[12] * m[3] * m[6];
+    inv[10] = m[0] * m[5] * m[15] - m[0] * m[7] * m[13] - m[4] * m[1] * m[15]
+        + m[4] * m[3] * m[13]
+        + m[12] * m[1] * m[7]
+        - m[12] * m[3] * m[5];
+    inv[14] = -m[0] * m[5] * m[14] + m[0] * m[6] * m[13] + m[4] * m[1] * m[14]
+        - m[4] * m[2] * m[13]
+        - m[12] * m[1] * m[6]
+        + m[12] * m[2] * m[5];
+    inv[3] = -m[1] * m[6] * m[11] + m[1] * m[7] * m[10] + m[5] * m[2] * m[11]
+        - m[5] * m[3] * m[10]
+        - m[9] * m[2] * m[7]
+        + m[9] * m[3] * m[6];
+    inv[7] = m[0] * m[6] * m[11] - m[0] * m[7] * m[10] - m[4] * m[2] * m[11]
+        + m[4] * m[3] * m[10]
+        + m[8] * m[2] * m[7]
+        - m[8] * m[3] * m[6];
+    inv[11] = -m[0] * m[5] * m[11] + m[0] * m[7] * m[9] + m[4] * m[1] * m[11]
+        - m[4] * m[3] * m[9]
+        - m[8] * m[1] * m[7]
+        + m[8] * m[3] * m[5];
+    inv[15] = m[0] * m[5] * m[10] - m[0] * m[6] * m[9] - m[4] * m[1] * m[10]
+        + m[4] * m[2] * m[9]
+        + m[8] * m[1] * m[6]
+        - m[8] * m[2] * m[5];
+
+    let det = m[0] * inv[0] + m[1] * inv[4] + m[2] * inv[8] + m[3] * inv[12];
+    if det == 0.0 {
+        return None;
+    }
+    for v in inv.iter_mut() {
+        *v /= det;
+    }
+    Some(inv)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mul(a: &[f32; 16], b: &[f32; 16]) -> [f32; 16] {
+        let mut out = [0.0; 16];
+        for col in 0..4 {
+            for row in 0..4 {
+                out[col * 4 + row] = (0..4).map(|k| a[k * 4 + row] * b[col * 4 + k]).sum();
+            }
+        }
+        out
+    }
+
+    fn assert_near(actual: &[f32], expected: &[f32]) {
+        for (a, e) in actual.iter().zip(expected) {
+            assert!(
+                (a - e).abs() <= 1.0e-5 * e.abs().max(1.0),
+                "{:?} != {:?}",
+                actual,
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn ortho_maps_corners() {
+        let m = ortho(10.0, 110.0, 50.0, 0.0, false);
+        assert_near(&transform_point(&m, [10.0, 0.0, 0.0]), &[-1.0, 1.0, 0.0]);
+        assert_near(
+            &transform_point(&m, [110.0, 50.0, 1000.0]),
+            &[1.0, -1.0, 1.0],
+        );
+
+        let m = ortho(0.0, 2.0, 0.0, 2.0, true);
+        assert_near(&transform_point(&m, [0.0, 0.0, 0.0]), &[-1.0, -1.0, -1.0]);
+        assert_near(&transform_point(&m, [2.0, 2.0, 1000.0]), &[1.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn transform_point_divides_by_w() {
+        let mut m = IDENTITY;
+        m[15] = 2.0;
+        m[12] = 4.0;
+        assert_eq!(transform_point(&m, [2.0, 4.0, 6.0]), [3.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn invert_round_trip() {
+        assert_eq!(invert(&IDENTITY), Some(IDENTITY));
+
+        let m = [
+            2.0, 0.0, 0.0, 0.0, 0.0, 0.0, 3.0, 0.0, 0.0, -1.0, 0.0, 0.0, 5.0, 6.0, 7.0, 1.0,
+        ];
+        let inv = invert(&m).unwrap();
+        assert_near(&mul(&m, &inv), &IDENTITY);
+        assert_near(&mul(&inv, &m), &IDENTITY);
+
+        let proj = ortho(-4.0, 4.0, -3.0, 3.0, true);
+        let inv = invert(&proj).unwrap();
+        assert_near(
+            &transform_point(&inv, [1.0, -1.0, 0.0]),
+            &[4.0, -3.0, 500.0],
+        );
+    }
+
+    #[test]
+    fn invert_singular() {
+        let mut m = IDENTITY;
+        m[10] = 0.0;
+        assert_eq!(invert(&m), None);
+        assert_eq!(invert(&[0.0; 16]), None);
+    }
+}
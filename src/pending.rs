@@ -0,0 +1,177 @@
+use std::sync::Mutex;
+
+/// Tracks frames submitted to bgfx, to know when results of asynchronous GPU work are ready.
+struct State {
+    /// Number returned by the last call to `frame`.
+    frame: Option<u32>,
+    /// Incremented on shutdown, as frame numbers start over after the next init.
+    session: u32,
+    /// Number returned by the last call to `frame` of each session that has been shut down.
+    ended: Vec<Option<u32>>,
+    /// Buffers of dropped reads that bgfx may still write to, with the frame they're done in.
+    deferred: Vec<(u32, Box<[u8]>)>,
+    /// Frame the last submission of each occlusion query is done in, by handle index.
+    queries: Vec<Option<u32>>,
+}
+
+static STATE: Mutex<State> = Mutex::new(State {
+    frame: None,
+    session: 0,
+    ended: Vec::new(),
+    deferred: Vec::new(),
+    queries: Vec::new(),
+});
+
+fn state() -> std::sync::MutexGuard<'static, State> {
+    STATE.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Called with the number returned by `bgfx_frame`.
+pub(crate) fn frame_done(frame: u32) {
+    let mut state = state();
+    state.frame = Some(frame);
+    state.deferred.retain(|(done, _)| *done > frame);
+}
+
+/// Called after `bgfx_shutdown`, when bgfx no longer touches any buffers.
+pub(crate) fn shutdown() {
+    let mut state = state();
+    let frame = state.frame.take();
+    state.ended.push(frame);
+    state.session += 1;
+    state.deferred.clear();
+    state.queries.clear();
+}
+
+/// Called when the occlusion query with handle index `index` is submitted.
+pub(crate) fn query_submitted(index: u16) {
+    let mut state = state();
+    // Results of work submitted now are available two frames later, as bgfx reports for
+    // `bgfx_read_texture`.
+    let done = state.frame.unwrap_or(0) + 2;
+    let index = index as usize;
+    if state.queries.len() <= index {
+        state.queries.resize(index + 1, None);
+    }
+    state.queries[index] = Some(done);
+}
+
+/// Returns `true` once the frame the last submission of the occlusion query with handle index
+/// `index` is done in has been returned by `frame`.
+pub(crate) fn query_done(index: u16) -> bool {
+    let state = state();
+    match (state.queries.get(index as usize), state.frame) {
+        (Some(Some(done)), Some(frame)) => frame >= *done,
+        _ => false,
+    }
+}
+
+/// Texture read started with [Texture::read](crate::bgfx::Texture::read). The data becomes
+/// available once `frame` has returned the frame number in [PendingRead::frame]. If bgfx is
+/// shut down before that, the read is cancelled and the data never becomes available.
+///
+/// The destination buffer is owned by the read. If it's dropped before the data is available,
+/// the buffer is kept alive until bgfx is done writing to it.
+#[derive(Debug)]
+pub struct PendingRead {
+    data: Box<[u8]>,
+    frame: u32,
+    session: u32,
+}
+
+impl PendingRead {
+    pub(crate) fn new(data: Box<[u8]>, frame: u32) -> PendingRead {
+        PendingRead {
+            data,
+            frame,
+            session: state().session,
+        }
+    }
+
+    /// Returns the frame number the data is available in.
+    pub fn frame(&self) -> u32 {
+        self.frame
+    }
+
+    /// Returns `true` once the data has been written.
+    pub fn is_ready(&self) -> bool {
+        let state = state();
+        let frame = if state.session == self.session {
+            state.frame
+        } else {
+            state.ended[self.session as usize]
+        };
+        matches!(frame, Some(frame) if frame >= self.frame)
+    }
+
+    /// Returns `true` if bgfx was shut down before the data was written.
+    pub fn is_cancelled(&self) -> bool {
+        state().session != self.session && !self.is_ready()
+    }
+
+    /// Returns the data if it's available.
+    pub fn data(&self) -> Option<&[u8]> {
+        if self.is_ready() {
+            Some(&self.data)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the data if it's available, or the read itself if it isn't yet.
+    pub fn into_data(mut self) -> Result<Vec<u8>, PendingRead> {
+        if self.is_ready() {
+            Ok(std::mem::take(&mut self.data).into_vec())
+        } else {
+            Err(self)
+        }
+    }
+}
+
+impl Drop for PendingRead {
+    fn drop(&mut self) {
+        // After shutdown bgfx doesn't write to the buffer anymore.
+        if !self.data.is_empty() && !self.is_ready() && !self.is_cancelled() {
+            let data = std::mem::take(&mut self.data);
+            state().deferred.push((self.frame, data));
+        }
+    }
+}
+
+/// Status of an [OcclusionQuery](crate::bgfx::OcclusionQuery), returned by
+/// [OcclusionQuery::poll](crate::bgfx::OcclusionQuery::poll).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum OcclusionStatus {
+    /// The query hasn't been submitted, or the frame it was submitted in isn't done yet.
+    Pending,
+    /// The result of the last submission.
+    Ready(OcclusionResult),
+}
+
+/// Result of an [OcclusionQuery](crate::bgfx::OcclusionQuery).
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct OcclusionResult {
+    /// `true` if any pixels passed the test.
+    pub visible: bool,
+    /// Number of pixels that passed the test.
+    pub num_pixels: i32,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn query_done_after_two_frames() {
+        frame_done(10);
+        query_submitted(7);
+        assert!(!query_done(7));
+        assert!(!query_done(8));
+        frame_done(11);
+        assert!(!query_done(7));
+        frame_done(12);
+        assert!(query_done(7));
+        shutdown();
+        assert!(!query_done(7));
+    }
+}
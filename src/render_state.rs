@@ -0,0 +1,777 @@
+use crate::bgfx::{
+    state_blend_equation_separate, state_blend_func_separate, Encoder, StateBlendEquationFlags,
+    StateBlendFlags, StateCullFlags, StateDepthTestFlags, StateFlags, StatePtFlags,
+    StateWriteFlags,
+};
+use crate::flag_bits::{write_settings, FlagBits};
+use std::fmt;
+
+/// Maps the variants of a state enum to their flags, and back.
+macro_rules! state_enum {
+    (
+        $(#[$meta:meta])*
+        pub enum $name:ident : $flags:ident {
+            $($(#[$variant_meta:meta])* $variant:ident = $flag:ident,)*
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+        pub enum $name {
+            $($(#[$variant_meta])* $variant,)*
+        }
+
+        impl $name {
+            const ALL: &'static [$name] = &[$($name::$variant,)*];
+
+            /// Returns the state flags of the value.
+            pub fn flags(self) -> $flags {
+                match self {
+                    $($name::$variant => $flags::$flag,)*
+                }
+            }
+
+            /// Returns the value with the given bits of its flags.
+            fn from_flag_bits(bits: u64) -> Option<$name> {
+                $name::ALL.iter().copied().find(|value| value.flags().bits() == bits)
+            }
+        }
+    };
+}
+
+state_enum! {
+    /// Depth test function, passing if the fragment depth compares to the stored depth.
+    pub enum DepthTest: StateDepthTestFlags {
+        Less = LESS,
+        LessEqual = LEQUAL,
+        Equal = EQUAL,
+        GreaterEqual = GEQUAL,
+        Greater = GREATER,
+        NotEqual = NOTEQUAL,
+        Never = NEVER,
+        Always = ALWAYS,
+    }
+}
+
+state_enum! {
+    /// Winding of the triangles that are culled.
+    pub enum Cull: StateCullFlags {
+        /// Cull clockwise triangles.
+        Cw = CW,
+        /// Cull counter-clockwise triangles.
+        Ccw = CCW,
+    }
+}
+
+state_enum! {
+    /// Blend factor the source or destination color is multiplied with.
+    pub enum BlendFactor: StateBlendFlags {
+        Zero = ZERO,
+        One = ONE,
+        SrcColor = SRC_COLOR,
+        InvSrcColor = INV_SRC_COLOR,
+        SrcAlpha = SRC_ALPHA,
+        InvSrcAlpha = INV_SRC_ALPHA,
+        DstAlpha = DST_ALPHA,
+        InvDstAlpha = INV_DST_ALPHA,
+        DstColor = DST_COLOR,
+        InvDstColor = INV_DST_COLOR,
+        SrcAlphaSat = SRC_ALPHA_SAT,
+        /// The blend factor color, see [RenderState::blend_factor].
+        Factor = FACTOR,
+        InvFactor = INV_FACTOR,
+    }
+}
+
+state_enum! {
+    /// Blend equation combining the source and destination color.
+    pub enum BlendEquation: StateBlendEquationFlags {
+        Add = ADD,
+        Sub = SUB,
+        RevSub = REVSUB,
+        Min = MIN,
+        Max = MAX,
+    }
+}
+
+/// Primitive type drawn from the vertices.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Primitive {
+    Triangles,
+    TriStrip,
+    Lines,
+    LineStrip,
+    Points,
+}
+
+impl Primitive {
+    const ALL: &'static [Primitive] = &[
+        Primitive::Triangles,
+        Primitive::TriStrip,
+        Primitive::Lines,
+        Primitive::LineStrip,
+        Primitive::Points,
+    ];
+
+    /// Returns the state flags of the primitive type, which are empty for triangles.
+    pub fn flags(self) -> StatePtFlags {
+        match self {
+            Primitive::Triangles => StatePtFlags::empty(),
+            Primitive::TriStrip => StatePtFlags::TRISTRIP,
+            Primitive::Lines => StatePtFlags::LINES,
+            Primitive::LineStrip => StatePtFlags::LINESTRIP,
+            Primitive::Points => StatePtFlags::POINTS,
+        }
+    }
+}
+
+/// Blending of the first render target, with separate factors and equations for the color and
+/// alpha channels.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct Blend {
+    pub src_rgb: BlendFactor,
+    pub dst_rgb: BlendFactor,
+    pub src_alpha: BlendFactor,
+    pub dst_alpha: BlendFactor,
+    pub equation_rgb: BlendEquation,
+    pub equation_alpha: BlendEquation,
+}
+
+impl Blend {
+    /// Alpha blending, see [state_blend_alpha](crate::bgfx::state_blend_alpha).
+    pub const ALPHA: Blend = Blend::func(BlendFactor::SrcAlpha, BlendFactor::InvSrcAlpha);
+    /// Blending of colors with premultiplied alpha.
+    pub const PREMULTIPLIED: Blend = Blend::func(BlendFactor::One, BlendFactor::InvSrcAlpha);
+    /// Additive blending, see [state_blend_add](crate::bgfx::state_blend_add).
+    pub const ADD: Blend = Blend::func(BlendFactor::One, BlendFactor::One);
+    /// Multiplies the colors, see [state_blend_multiply](crate::bgfx::state_blend_multiply).
+    pub const MULTIPLY: Blend = Blend::func(BlendFactor::DstColor, BlendFactor::Zero);
+
+    /// Adds `src` times the source to `dst` times the destination, for all channels.
+    pub const fn func(src: BlendFactor, dst: BlendFactor) -> Blend {
+        Blend {
+            src_rgb: src,
+            dst_rgb: dst,
+            src_alpha: src,
+            dst_alpha: dst,
+            equation_rgb: BlendEquation::Add,
+            equation_alpha: BlendEquation::Add,
+        }
+    }
+
+    /// Returns the blend with `equation` for all channels.
+    pub const fn equation(mut self, equation: BlendEquation) -> Blend {
+        self.equation_rgb = equation;
+        self.equation_alpha = equation;
+        self
+    }
+
+    fn bits(&self) -> u64 {
+        state_blend_func_separate(
+            self.src_rgb.flags(),
+            self.dst_rgb.flags(),
+            self.src_alpha.flags(),
+            self.dst_alpha.flags(),
+        ) | state_blend_equation_separate(self.equation_rgb.flags(), self.equation_alpha.flags())
+    }
+
+    fn from_bits(state: u64) -> Option<Blend> {
+        let factor = StateBlendFlags::all().bits();
+        let equation = StateBlendEquationFlags::all().bits();
+        Some(Blend {
+            src_rgb: BlendFactor::from_flag_bits(state & factor)?,
+            dst_rgb: BlendFactor::from_flag_bits((state >> 4) & factor)?,
+            src_alpha: BlendFactor::from_flag_bits((state >> 8) & factor)?,
+            dst_alpha: BlendFactor::from_flag_bits((state >> 12) & factor)?,
+            equation_rgb: BlendEquation::from_flag_bits(state & equation)?,
+            equation_alpha: BlendEquation::from_flag_bits((state >> 3) & equation)?,
+        })
+    }
+}
+
+/// Blending of one of the render targets 1 to 3, with independent blending.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct TargetBlend {
+    pub src: BlendFactor,
+    pub dst: BlendFactor,
+    pub equation: BlendEquation,
+}
+
+impl TargetBlend {
+    /// Bits of the blend in the `rgba` word, before shifting it for its render target.
+    fn bits(&self) -> u32 {
+        let factor_shift = StateBlendFlags::ZERO.bits().trailing_zeros();
+        let equation_shift = StateBlendEquationFlags::all().bits().trailing_zeros();
+        ((self.src.flags().bits() >> factor_shift)
+            | ((self.dst.flags().bits() >> factor_shift) << 4)
+            | ((self.equation.flags().bits() >> equation_shift) << 8)) as u32
+    }
+
+    fn from_bits(bits: u32) -> Option<TargetBlend> {
+        let factor_shift = StateBlendFlags::ZERO.bits().trailing_zeros();
+        let equation_shift = StateBlendEquationFlags::all().bits().trailing_zeros();
+        let bits = bits as u64;
+        Some(TargetBlend {
+            src: BlendFactor::from_flag_bits((bits & 0xf) << factor_shift)?,
+            dst: BlendFactor::from_flag_bits(((bits >> 4) & 0xf) << factor_shift)?,
+            equation: BlendEquation::from_flag_bits(((bits >> 8) & 0x7) << equation_shift)?,
+        })
+    }
+}
+
+/// Bits each render target blend takes in the `rgba` word.
+const TARGET_BLEND_BITS: u32 = 11;
+
+/// Render state of a draw call, which converts to the state word and blend factor passed to
+/// [Encoder::set_state], and back.
+///
+/// Each setting has one field, so conflicting flags such as two depth tests can't be set. The
+/// default is [StateFlags::DEFAULT], writing color and depth with a less depth test, clockwise
+/// culling and MSAA:
+///
+/// ```ignore
+/// let state = RenderState::default()
+///     .blend(Blend::ALPHA)
+///     .depth_write(false)
+///     .primitive(Primitive::Lines);
+/// encoder.set_render_state(&state);
+///
+/// let (bits, rgba) = state.to_bits();
+/// assert_eq!(RenderState::from_bits(bits, rgba), Some(state));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct RenderState {
+    /// Channels written to the render target, and depth write with [StateWriteFlags::Z].
+    pub write: StateWriteFlags,
+    /// Depth test, or `None` to pass all fragments.
+    pub depth_test: Option<DepthTest>,
+    /// Culled triangles, or `None` to draw both sides.
+    pub cull: Option<Cull>,
+    /// Counter-clockwise triangles are front facing instead of clockwise ones.
+    pub front_ccw: bool,
+    pub primitive: Primitive,
+    /// Blending of the first render target, or of all of them without `target_blends`.
+    pub blend: Option<Blend>,
+    /// Independent blending of render targets 1 to 3. These are passed in the `rgba` word, so
+    /// [RenderState::blend_factor] is ignored if any are set. Render target 3 can't use
+    /// [BlendEquation::Max], as its last bit doesn't fit in the word, and
+    /// [RenderState::to_bits] panics if it does.
+    pub target_blends: [Option<TargetBlend>; 3],
+    /// Color of [BlendFactor::Factor] as `0xRRGGBBAA`.
+    pub blend_factor: u32,
+    pub alpha_to_coverage: bool,
+    /// Reference value of the alpha test.
+    pub alpha_ref: u8,
+    /// Point size of 0 to 15 pixels.
+    pub point_size: u8,
+    pub msaa: bool,
+    pub line_aa: bool,
+    pub conservative_raster: bool,
+}
+
+/// Panics if render target `index` can't use `blend`.
+fn check_target_blend(index: usize, blend: Option<TargetBlend>) {
+    assert!(
+        index != 3 || !matches!(blend, Some(b) if b.equation == BlendEquation::Max),
+        "render target 3 can't blend with BlendEquation::Max"
+    );
+}
+
+impl Default for RenderState {
+    fn default() -> RenderState {
+        RenderState {
+            write: StateWriteFlags::RGB | StateWriteFlags::A | StateWriteFlags::Z,
+            depth_test: Some(DepthTest::Less),
+            cull: Some(Cull::Cw),
+            front_ccw: false,
+            primitive: Primitive::Triangles,
+            blend: None,
+            target_blends: [None; 3],
+            blend_factor: 0,
+            alpha_to_coverage: false,
+            alpha_ref: 0,
+            point_size: 0,
+            msaa: true,
+            line_aa: false,
+            conservative_raster: false,
+        }
+    }
+}
+
+impl RenderState {
+    /// State that writes nothing and tests nothing, to build a state from scratch.
+    pub const NONE: RenderState = RenderState {
+        write: StateWriteFlags::empty(),
+        depth_test: None,
+        cull: None,
+        front_ccw: false,
+        primitive: Primitive::Triangles,
+        blend: None,
+        target_blends: [None; 3],
+        blend_factor: 0,
+        alpha_to_coverage: false,
+        alpha_ref: 0,
+        point_size: 0,
+        msaa: false,
+        line_aa: false,
+        conservative_raster: false,
+    };
+
+    /// Sets the written channels, see [RenderState::write].
+    pub fn write(mut self, write: StateWriteFlags) -> RenderState {
+        self.write = write;
+        self
+    }
+
+    /// Enables or disables depth writes.
+    pub fn depth_write(mut self, enabled: bool) -> RenderState {
+        self.write.set(StateWriteFlags::Z, enabled);
+        self
+    }
+
+    /// Sets the depth test.
+    pub fn depth_test(mut self, depth_test: impl Into<Option<DepthTest>>) -> RenderState {
+        self.depth_test = depth_test.into();
+        self
+    }
+
+    /// Sets the culled triangles.
+    pub fn cull(mut self, cull: impl Into<Option<Cull>>) -> RenderState {
+        self.cull = cull.into();
+        self
+    }
+
+    /// Sets whether counter-clockwise triangles are front facing.
+    pub fn front_ccw(mut self, front_ccw: bool) -> RenderState {
+        self.front_ccw = front_ccw;
+        self
+    }
+
+    /// Sets the primitive type.
+    pub fn primitive(mut self, primitive: Primitive) -> RenderState {
+        self.primitive = primitive;
+        self
+    }
+
+    /// Sets the blending of the first render target.
+    pub fn blend(mut self, blend: impl Into<Option<Blend>>) -> RenderState {
+        self.blend = blend.into();
+        self
+    }
+
+    /// Sets the independent blending of render target `index` from 1 to 3.
+    ///
+    /// Panics if `index` is out of range, or render target 3 uses [BlendEquation::Max].
+    pub fn target_blend(
+        mut self,
+        index: usize,
+        blend: impl Into<Option<TargetBlend>>,
+    ) -> RenderState {
+        assert!(
+            (1..=3).contains(&index),
+            "render target blend index must be 1 to 3"
+        );
+        let blend = blend.into();
+        check_target_blend(index, blend);
+        self.target_blends[index - 1] = blend;
+        self
+    }
+
+    /// Sets the color of [BlendFactor::Factor] as `0xRRGGBBAA`.
+    pub fn blend_factor(mut self, rgba: u32) -> RenderState {
+        self.blend_factor = rgba;
+        self
+    }
+
+    /// Enables or disables alpha to coverage.
+    pub fn alpha_to_coverage(mut self, enabled: bool) -> RenderState {
+        self.alpha_to_coverage = enabled;
+        self
+    }
+
+    /// Sets the reference value of the alpha test.
+    pub fn alpha_ref(mut self, alpha_ref: u8) -> RenderState {
+        self.alpha_ref = alpha_ref;
+        self
+    }
+
+    /// Sets the point size.
+    ///
+    /// Panics if `size` is larger than 15.
+    pub fn point_size(mut self, size: u8) -> RenderState {
+        assert!(size <= 15, "point size must be 0 to 15");
+        self.point_size = size;
+        self
+    }
+
+    /// Enables or disables MSAA.
+    pub fn msaa(mut self, enabled: bool) -> RenderState {
+        self.msaa = enabled;
+        self
+    }
+
+    /// Enables or disables line antialiasing.
+    pub fn line_aa(mut self, enabled: bool) -> RenderState {
+        self.line_aa = enabled;
+        self
+    }
+
+    /// Enables or disables conservative rasterization.
+    pub fn conservative_raster(mut self, enabled: bool) -> RenderState {
+        self.conservative_raster = enabled;
+        self
+    }
+
+    /// Returns `true` if any render target has independent blending.
+    fn independent_blend(&self) -> bool {
+        self.target_blends.iter().any(Option::is_some)
+    }
+
+    /// Returns the state word and `rgba` word to pass to [Encoder::set_state].
+    ///
+    /// Panics if render target 3 uses [BlendEquation::Max] in `target_blends`.
+    pub fn to_bits(&self) -> (u64, u32) {
+        check_target_blend(3, self.target_blends[2]);
+        let mut state = self.write.bits() | self.primitive.flags().bits();
+        state |= self.depth_test.map_or(0, |test| test.flags().bits());
+        state |= self.cull.map_or(0, |cull| cull.flags().bits());
+        state |= self.blend.map_or(0, |blend| blend.bits());
+        state |= (self.alpha_ref as u64) << bgfx_sys::BGFX_STATE_ALPHA_REF_SHIFT;
+        state |= ((self.point_size as u64) << bgfx_sys::BGFX_STATE_POINT_SIZE_SHIFT)
+            & bgfx_sys::BGFX_STATE_POINT_SIZE_MASK;
+        for (enabled, flag) in [
+            (self.front_ccw, StateFlags::FRONT_CCW),
+            (self.independent_blend(), StateFlags::BLEND_INDEPENDENT),
+            (self.alpha_to_coverage, StateFlags::BLEND_ALPHA_TO_COVERAGE),
+            (self.msaa, StateFlags::MSAA),
+            (self.line_aa, StateFlags::LINEAA),
+            (self.conservative_raster, StateFlags::CONSERVATIVE_RASTER),
+        ] {
+            if enabled {
+                state |= flag.bits();
+            }
+        }
+
+        let rgba = if self.independent_blend() {
+            self.target_blends
+                .iter()
+                .enumerate()
+                .map(|(i, blend)| {
+                    blend.map_or(0, |blend| blend.bits() << (i as u32 * TARGET_BLEND_BITS))
+                })
+                .fold(0, |rgba, bits| rgba | bits)
+        } else {
+            self.blend_factor
+        };
+        (state, rgba)
+    }
+
+    /// Decodes a state word and `rgba` word as passed to [Encoder::set_state]. Returns `None`
+    /// if any bits don't belong to a valid setting, such as an unknown depth test.
+    pub fn from_bits(state: u64, rgba: u32) -> Option<RenderState> {
+        RenderState::decode_exact((state, rgba))
+    }
+}
+
+impl FlagBits for RenderState {
+    type Bits = (u64, u32);
+
+    fn encode(&self) -> (u64, u32) {
+        self.to_bits()
+    }
+
+    /// The `rgba` word holds the target blends with independent blending, and the blend
+    /// factor otherwise.
+    fn decode((state, rgba): (u64, u32)) -> Option<RenderState> {
+        let has = |flags: StateFlags| state & flags.bits() != 0;
+        let depth_test = match state & StateDepthTestFlags::all().bits() {
+            0 => None,
+            bits => Some(DepthTest::from_flag_bits(bits)?),
+        };
+        let cull = match state & StateCullFlags::all().bits() {
+            0 => None,
+            bits => Some(Cull::from_flag_bits(bits)?),
+        };
+        let pt = state & StatePtFlags::all().bits();
+        let primitive = *Primitive::ALL.iter().find(|p| p.flags().bits() == pt)?;
+        let blend_bits = state
+            & (state_blend_func_separate(
+                StateBlendFlags::all(),
+                StateBlendFlags::all(),
+                StateBlendFlags::all(),
+                StateBlendFlags::all(),
+            ) | state_blend_equation_separate(
+                StateBlendEquationFlags::all(),
+                StateBlendEquationFlags::all(),
+            ));
+        let blend = match blend_bits {
+            0 => None,
+            bits => Some(Blend::from_bits(bits)?),
+        };
+
+        let independent_blend = has(StateFlags::BLEND_INDEPENDENT);
+        let mut target_blends = [None; 3];
+        if independent_blend {
+            for (i, blend) in target_blends.iter_mut().enumerate() {
+                let bits =
+                    (rgba >> (i as u32 * TARGET_BLEND_BITS)) & ((1 << TARGET_BLEND_BITS) - 1);
+                if bits != 0 {
+                    *blend = Some(TargetBlend::from_bits(bits)?);
+                }
+            }
+        }
+
+        Some(RenderState {
+            write: StateWriteFlags::from_bits_truncate(state),
+            depth_test,
+            cull,
+            front_ccw: has(StateFlags::FRONT_CCW),
+            primitive,
+            blend,
+            target_blends,
+            blend_factor: if independent_blend { 0 } else { rgba },
+            alpha_to_coverage: has(StateFlags::BLEND_ALPHA_TO_COVERAGE),
+            alpha_ref: ((state & bgfx_sys::BGFX_STATE_ALPHA_REF_MASK)
+                >> bgfx_sys::BGFX_STATE_ALPHA_REF_SHIFT) as u8,
+            point_size: ((state & bgfx_sys::BGFX_STATE_POINT_SIZE_MASK)
+                >> bgfx_sys::BGFX_STATE_POINT_SIZE_SHIFT) as u8,
+            msaa: has(StateFlags::MSAA),
+            line_aa: has(StateFlags::LINEAA),
+            conservative_raster: has(StateFlags::CONSERVATIVE_RASTER),
+        })
+    }
+}
+
+/// The state word, without the `rgba` word of the blend factor or independent blending.
+impl From<RenderState> for u64 {
+    fn from(state: RenderState) -> u64 {
+        state.to_bits().0
+    }
+}
+
+impl fmt::Display for RenderState {
+    /// Formats the settings that differ from [RenderState::NONE], such as
+    /// `write: RGB | A | Z, depth_test: Less, cull: Cw, msaa`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = Vec::new();
+        if !self.write.is_empty() {
+            parts.push(format!("write: {:?}", self.write));
+        }
+        if let Some(depth_test) = self.depth_test {
+            parts.push(format!("depth_test: {:?}", depth_test));
+        }
+        if let Some(cull) = self.cull {
+            parts.push(format!("cull: {:?}", cull));
+        }
+        if self.front_ccw {
+            parts.push("front_ccw".to_string());
+        }
+        if self.primitive != Primitive::Triangles {
+            parts.push(format!("primitive: {:?}", self.primitive));
+        }
+        if let Some(blend) = self.blend {
+            parts.push(format!("blend: {:?}", blend));
+        }
+        for (i, blend) in self.target_blends.iter().enumerate() {
+            if let Some(blend) = blend {
+                parts.push(format!("target_blend[{}]: {:?}", i + 1, blend));
+            }
+        }
+        if self.blend_factor != 0 {
+            parts.push(format!("blend_factor: {:#010x}", self.blend_factor));
+        }
+        if self.alpha_to_coverage {
+            parts.push("alpha_to_coverage".to_string());
+        }
+        if self.alpha_ref != 0 {
+            parts.push(format!("alpha_ref: {}", self.alpha_ref));
+        }
+        if self.point_size != 0 {
+            parts.push(format!("point_size: {}", self.point_size));
+        }
+        if self.msaa {
+            parts.push("msaa".to_string());
+        }
+        if self.line_aa {
+            parts.push("line_aa".to_string());
+        }
+        if self.conservative_raster {
+            parts.push("conservative_raster".to_string());
+        }
+        write_settings(f, &parts, "none")
+    }
+}
+
+impl Encoder {
+    /// Sets the render state for draw primitive, see [Encoder::set_state].
+    pub fn set_render_state(&self, state: &RenderState) {
+        let (state, rgba) = state.to_bits();
+        self.set_state(state, rgba);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flag_bits::assert_round_trip as round_trip;
+
+    #[test]
+    fn default_is_state_default() {
+        let state = RenderState::default();
+        assert_eq!(state.to_bits(), (StateFlags::DEFAULT.bits(), 0));
+        round_trip(state);
+        round_trip(RenderState::NONE);
+    }
+
+    #[test]
+    fn depth_tests() {
+        for &depth_test in DepthTest::ALL {
+            round_trip(RenderState::default().depth_test(depth_test));
+        }
+    }
+
+    #[test]
+    fn culls() {
+        for &cull in Cull::ALL {
+            round_trip(RenderState::default().cull(cull).front_ccw(true));
+        }
+    }
+
+    #[test]
+    fn primitives() {
+        for &primitive in Primitive::ALL {
+            round_trip(RenderState::default().primitive(primitive));
+        }
+    }
+
+    #[test]
+    fn separate_blend() {
+        for &equation_rgb in BlendEquation::ALL {
+            for &equation_alpha in BlendEquation::ALL {
+                round_trip(RenderState::default().blend(Blend {
+                    src_rgb: BlendFactor::SrcAlpha,
+                    dst_rgb: BlendFactor::InvSrcAlpha,
+                    src_alpha: BlendFactor::One,
+                    dst_alpha: BlendFactor::Zero,
+                    equation_rgb,
+                    equation_alpha,
+                }));
+            }
+        }
+        for &src in BlendFactor::ALL {
+            for &dst in BlendFactor::ALL {
+                let state = RenderState::default()
+                    .blend(Blend::func(src, dst))
+                    .blend_factor(0x12345678);
+                round_trip(state);
+            }
+        }
+    }
+
+    #[test]
+    fn independent_blend() {
+        let state = RenderState::default()
+            .blend(Blend::ALPHA)
+            .target_blend(
+                1,
+                TargetBlend {
+                    src: BlendFactor::One,
+                    dst: BlendFactor::One,
+                    equation: BlendEquation::Add,
+                },
+            )
+            .target_blend(
+                3,
+                TargetBlend {
+                    src: BlendFactor::InvFactor,
+                    dst: BlendFactor::DstColor,
+                    equation: BlendEquation::Min,
+                },
+            );
+        let (bits, rgba) = state.to_bits();
+        assert_ne!(bits & StateFlags::BLEND_INDEPENDENT.bits(), 0);
+        assert_ne!(rgba >> (2 * TARGET_BLEND_BITS), 0);
+        assert_eq!(
+            (rgba >> TARGET_BLEND_BITS) & ((1 << TARGET_BLEND_BITS) - 1),
+            0
+        );
+        round_trip(state);
+    }
+
+    #[test]
+    fn independent_blend_equations() {
+        for &equation in BlendEquation::ALL {
+            for index in 1..=3 {
+                if index == 3 && equation == BlendEquation::Max {
+                    continue;
+                }
+                let blend = TargetBlend {
+                    src: BlendFactor::SrcColor,
+                    dst: BlendFactor::InvSrcColor,
+                    equation,
+                };
+                round_trip(RenderState::default().target_blend(index, blend));
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "BlendEquation::Max")]
+    fn independent_blend_max_on_target_3() {
+        let blend = TargetBlend {
+            src: BlendFactor::One,
+            dst: BlendFactor::One,
+            equation: BlendEquation::Max,
+        };
+        let _ = RenderState::default().target_blend(3, blend);
+    }
+
+    #[test]
+    #[should_panic(expected = "BlendEquation::Max")]
+    fn to_bits_max_on_target_3() {
+        let mut state = RenderState::default();
+        state.target_blends[2] = Some(TargetBlend {
+            src: BlendFactor::One,
+            dst: BlendFactor::One,
+            equation: BlendEquation::Max,
+        });
+        let _ = state.to_bits();
+    }
+
+    #[test]
+    fn alpha_ref_and_point_size() {
+        for alpha_ref in [0, 1, 128, 255] {
+            for point_size in [0, 1, 8, 15] {
+                let state = RenderState::default()
+                    .alpha_ref(alpha_ref)
+                    .point_size(point_size)
+                    .alpha_to_coverage(true)
+                    .line_aa(true)
+                    .conservative_raster(true);
+                round_trip(state);
+            }
+        }
+    }
+
+    #[test]
+    fn independent_without_target_blends() {
+        let (bits, rgba) = RenderState::default().blend(Blend::ALPHA).to_bits();
+        assert_eq!(
+            RenderState::from_bits(bits | StateFlags::BLEND_INDEPENDENT.bits(), rgba),
+            None
+        );
+    }
+
+    #[test]
+    fn invalid_bits() {
+        let (bits, rgba) = RenderState::default().to_bits();
+        // Two culls at once.
+        assert_eq!(
+            RenderState::from_bits(bits | StateCullFlags::all().bits(), rgba),
+            None
+        );
+        // Blend factor 0, which isn't a valid factor.
+        let blend = RenderState::default().blend(Blend::ADD).to_bits().0;
+        let src_rgb = StateBlendFlags::all().bits();
+        assert_eq!(RenderState::from_bits(blend & !src_rgb, rgba), None);
+    }
+}
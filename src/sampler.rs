@@ -0,0 +1,528 @@
+use crate::bgfx::{
+    SamplerCompareFlags, SamplerFlags, SamplerMagFlags, SamplerMinFlags, SamplerMipFlags,
+    TextureFlags,
+};
+use crate::flag_bits::{write_settings, FlagBits};
+use std::fmt;
+
+/// Texture filtering when minifying or magnifying.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Filter {
+    Linear,
+    Point,
+    Anisotropic,
+}
+
+impl Filter {
+    const ALL: &'static [Filter] = &[Filter::Linear, Filter::Point, Filter::Anisotropic];
+
+    /// Returns the sampler flags of the filter when minifying.
+    pub fn min_flags(self) -> SamplerMinFlags {
+        match self {
+            Filter::Linear => SamplerMinFlags::empty(),
+            Filter::Point => SamplerMinFlags::POINT,
+            Filter::Anisotropic => SamplerMinFlags::ANISOTROPIC,
+        }
+    }
+
+    /// Returns the sampler flags of the filter when magnifying.
+    pub fn mag_flags(self) -> SamplerMagFlags {
+        match self {
+            Filter::Linear => SamplerMagFlags::empty(),
+            Filter::Point => SamplerMagFlags::POINT,
+            Filter::Anisotropic => SamplerMagFlags::ANISOTROPIC,
+        }
+    }
+}
+
+/// Filtering between mip levels.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum MipFilter {
+    Linear,
+    Point,
+}
+
+impl MipFilter {
+    /// Returns the sampler flags of the filter.
+    pub fn flags(self) -> SamplerMipFlags {
+        match self {
+            MipFilter::Linear => SamplerMipFlags::empty(),
+            MipFilter::Point => SamplerMipFlags::POINT,
+        }
+    }
+}
+
+/// Addressing of texture coordinates outside of 0 to 1 along one axis.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum AddressMode {
+    /// Repeat the texture.
+    Repeat,
+    /// Repeat the texture, mirrored every other time.
+    Mirror,
+    /// Clamp to the edge of the texture.
+    Clamp,
+    /// Use the border color, see [SamplerDesc::border_color].
+    Border,
+}
+
+impl AddressMode {
+    const ALL: &'static [AddressMode] = &[
+        AddressMode::Repeat,
+        AddressMode::Mirror,
+        AddressMode::Clamp,
+        AddressMode::Border,
+    ];
+
+    /// Returns the bits of the mode for the axis at `shift`. The modes are numbered the same
+    /// for [SamplerUFlags](crate::bgfx::SamplerUFlags),
+    /// [SamplerVFlags](crate::bgfx::SamplerVFlags) and
+    /// [SamplerWFlags](crate::bgfx::SamplerWFlags).
+    fn bits(self, shift: u32) -> u32 {
+        let index = AddressMode::ALL
+            .iter()
+            .position(|&mode| mode == self)
+            .unwrap();
+        (index as u32) << shift
+    }
+
+    fn from_bits(bits: u32, shift: u32, mask: u32) -> AddressMode {
+        AddressMode::ALL[((bits & mask) >> shift) as usize]
+    }
+}
+
+/// Comparison of sampled depth textures, passing if the reference value compares to the
+/// stored depth.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum CompareFunc {
+    Less,
+    LessEqual,
+    Equal,
+    GreaterEqual,
+    Greater,
+    NotEqual,
+    Never,
+    Always,
+}
+
+impl CompareFunc {
+    const ALL: &'static [CompareFunc] = &[
+        CompareFunc::Less,
+        CompareFunc::LessEqual,
+        CompareFunc::Equal,
+        CompareFunc::GreaterEqual,
+        CompareFunc::Greater,
+        CompareFunc::NotEqual,
+        CompareFunc::Never,
+        CompareFunc::Always,
+    ];
+
+    /// Returns the sampler flags of the comparison.
+    pub fn flags(self) -> SamplerCompareFlags {
+        match self {
+            CompareFunc::Less => SamplerCompareFlags::LESS,
+            CompareFunc::LessEqual => SamplerCompareFlags::LEQUAL,
+            CompareFunc::Equal => SamplerCompareFlags::EQUAL,
+            CompareFunc::GreaterEqual => SamplerCompareFlags::GEQUAL,
+            CompareFunc::Greater => SamplerCompareFlags::GREATER,
+            CompareFunc::NotEqual => SamplerCompareFlags::NOTEQUAL,
+            CompareFunc::Never => SamplerCompareFlags::NEVER,
+            CompareFunc::Always => SamplerCompareFlags::ALWAYS,
+        }
+    }
+}
+
+/// Sampler state of a texture, which converts to the sampler flags passed to
+/// [Encoder::set_texture](crate::bgfx::Encoder::set_texture) and the `create_texture_*`
+/// functions, and back.
+///
+/// The default samples linearly and repeats the texture. [Sampler::Inherit] samples with the
+/// flags the texture was created with instead:
+///
+/// ```ignore
+/// let sampler = SamplerDesc::default()
+///     .filter(Filter::Point)
+///     .address(AddressMode::Clamp);
+/// encoder.set_texture(0, &uniform, &texture, sampler);
+/// encoder.set_texture(1, &uniform, &texture, Sampler::Inherit);
+///
+/// let texture = Texture::create_texture_2d(64, 64, false, 1, format, sampler, &mem);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct SamplerDesc {
+    /// Filtering when minifying.
+    pub min: Filter,
+    /// Filtering when magnifying.
+    pub mag: Filter,
+    /// Filtering between mip levels.
+    pub mip: MipFilter,
+    /// Addressing along U.
+    pub u: AddressMode,
+    /// Addressing along V.
+    pub v: AddressMode,
+    /// Addressing along W.
+    pub w: AddressMode,
+    /// Comparison for sampling depth textures, or `None` to sample the depth.
+    pub compare: Option<CompareFunc>,
+    /// Index of the border color in the palette, from 0 to 15.
+    pub border_color: u8,
+    /// Sample stencil instead of depth.
+    pub sample_stencil: bool,
+}
+
+impl Default for SamplerDesc {
+    fn default() -> SamplerDesc {
+        SamplerDesc::DEFAULT
+    }
+}
+
+impl SamplerDesc {
+    /// Samples linearly and repeats the texture.
+    pub const DEFAULT: SamplerDesc = SamplerDesc {
+        min: Filter::Linear,
+        mag: Filter::Linear,
+        mip: MipFilter::Linear,
+        u: AddressMode::Repeat,
+        v: AddressMode::Repeat,
+        w: AddressMode::Repeat,
+        compare: None,
+        border_color: 0,
+        sample_stencil: false,
+    };
+
+    /// Sets the filtering when minifying and magnifying.
+    pub fn filter(self, filter: Filter) -> SamplerDesc {
+        self.min(filter).mag(filter)
+    }
+
+    /// Sets the filtering when minifying.
+    pub fn min(self, filter: Filter) -> SamplerDesc {
+        SamplerDesc {
+            min: filter,
+            ..self
+        }
+    }
+
+    /// Sets the filtering when magnifying.
+    pub fn mag(self, filter: Filter) -> SamplerDesc {
+        SamplerDesc {
+            mag: filter,
+            ..self
+        }
+    }
+
+    /// Sets the filtering between mip levels.
+    pub fn mip(self, filter: MipFilter) -> SamplerDesc {
+        SamplerDesc {
+            mip: filter,
+            ..self
+        }
+    }
+
+    /// Sets the addressing along all axes.
+    pub fn address(self, mode: AddressMode) -> SamplerDesc {
+        self.u(mode).v(mode).w(mode)
+    }
+
+    /// Sets the addressing along U.
+    pub fn u(self, mode: AddressMode) -> SamplerDesc {
+        SamplerDesc { u: mode, ..self }
+    }
+
+    /// Sets the addressing along V.
+    pub fn v(self, mode: AddressMode) -> SamplerDesc {
+        SamplerDesc { v: mode, ..self }
+    }
+
+    /// Sets the addressing along W.
+    pub fn w(self, mode: AddressMode) -> SamplerDesc {
+        SamplerDesc { w: mode, ..self }
+    }
+
+    /// Sets the comparison for sampling depth textures.
+    pub fn compare(self, compare: impl Into<Option<CompareFunc>>) -> SamplerDesc {
+        SamplerDesc {
+            compare: compare.into(),
+            ..self
+        }
+    }
+
+    /// Sets the palette index of the border color.
+    ///
+    /// Panics if `index` is larger than 15.
+    pub fn border_color(self, index: u8) -> SamplerDesc {
+        assert!(index <= 15, "border color index must be 0 to 15");
+        SamplerDesc {
+            border_color: index,
+            ..self
+        }
+    }
+
+    /// Sets whether stencil is sampled instead of depth.
+    pub fn sample_stencil(self, enabled: bool) -> SamplerDesc {
+        SamplerDesc {
+            sample_stencil: enabled,
+            ..self
+        }
+    }
+
+    /// Returns the sampler flags to pass to
+    /// [Encoder::set_texture](crate::bgfx::Encoder::set_texture).
+    pub fn to_bits(&self) -> u32 {
+        let mut bits = self.min.min_flags().bits()
+            | self.mag.mag_flags().bits()
+            | self.mip.flags().bits()
+            | self.u.bits(bgfx_sys::BGFX_SAMPLER_U_SHIFT)
+            | self.v.bits(bgfx_sys::BGFX_SAMPLER_V_SHIFT)
+            | self.w.bits(bgfx_sys::BGFX_SAMPLER_W_SHIFT)
+            | self.compare.map_or(0, |compare| compare.flags().bits());
+        bits |= ((self.border_color as u32) << bgfx_sys::BGFX_SAMPLER_BORDER_COLOR_SHIFT)
+            & bgfx_sys::BGFX_SAMPLER_BORDER_COLOR_MASK;
+        if self.sample_stencil {
+            bits |= SamplerFlags::SAMPLE_STENCIL.bits();
+        }
+        bits
+    }
+
+    /// Returns the texture creation flags holding the sampler flags, to combine with other
+    /// [TextureFlags].
+    pub fn texture_flags(&self) -> TextureFlags {
+        // Sampler flags share the creation flags with the texture flags, in the lower 32 bits.
+        unsafe { TextureFlags::from_bits_unchecked(self.to_bits() as u64) }
+    }
+
+    /// Decodes sampler flags as passed to
+    /// [Encoder::set_texture](crate::bgfx::Encoder::set_texture). Returns `None` if any bits
+    /// don't belong to a valid setting, such as an unknown comparison, or for `u32::MAX`, which
+    /// is [Sampler::Inherit].
+    pub fn from_bits(bits: u32) -> Option<SamplerDesc> {
+        SamplerDesc::decode_exact(bits)
+    }
+}
+
+impl FlagBits for SamplerDesc {
+    type Bits = u32;
+
+    fn encode(&self) -> u32 {
+        self.to_bits()
+    }
+
+    fn decode(bits: u32) -> Option<SamplerDesc> {
+        let min = (bits & bgfx_sys::BGFX_SAMPLER_MIN_MASK) >> bgfx_sys::BGFX_SAMPLER_MIN_SHIFT;
+        let mag = (bits & bgfx_sys::BGFX_SAMPLER_MAG_MASK) >> bgfx_sys::BGFX_SAMPLER_MAG_SHIFT;
+        let compare = match bits & bgfx_sys::BGFX_SAMPLER_COMPARE_MASK {
+            0 => None,
+            compare => Some(
+                CompareFunc::ALL
+                    .iter()
+                    .copied()
+                    .find(|func| func.flags().bits() == compare)?,
+            ),
+        };
+
+        Some(SamplerDesc {
+            min: *Filter::ALL.get(min as usize)?,
+            mag: *Filter::ALL.get(mag as usize)?,
+            mip: if bits & SamplerMipFlags::POINT.bits() != 0 {
+                MipFilter::Point
+            } else {
+                MipFilter::Linear
+            },
+            u: AddressMode::from_bits(
+                bits,
+                bgfx_sys::BGFX_SAMPLER_U_SHIFT,
+                bgfx_sys::BGFX_SAMPLER_U_MASK,
+            ),
+            v: AddressMode::from_bits(
+                bits,
+                bgfx_sys::BGFX_SAMPLER_V_SHIFT,
+                bgfx_sys::BGFX_SAMPLER_V_MASK,
+            ),
+            w: AddressMode::from_bits(
+                bits,
+                bgfx_sys::BGFX_SAMPLER_W_SHIFT,
+                bgfx_sys::BGFX_SAMPLER_W_MASK,
+            ),
+            compare,
+            border_color: ((bits & bgfx_sys::BGFX_SAMPLER_BORDER_COLOR_MASK)
+                >> bgfx_sys::BGFX_SAMPLER_BORDER_COLOR_SHIFT) as u8,
+            sample_stencil: bits & SamplerFlags::SAMPLE_STENCIL.bits() != 0,
+        })
+    }
+}
+
+/// The sampler flags of the texture creation flags.
+impl From<SamplerDesc> for TextureFlags {
+    fn from(desc: SamplerDesc) -> TextureFlags {
+        desc.texture_flags()
+    }
+}
+
+impl fmt::Display for SamplerDesc {
+    /// Formats the settings that differ from [SamplerDesc::DEFAULT], such as
+    /// `min: Point, mag: Point, u: Clamp, v: Clamp`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = Vec::new();
+        for (name, filter) in [("min", self.min), ("mag", self.mag)] {
+            if filter != Filter::Linear {
+                parts.push(format!("{}: {:?}", name, filter));
+            }
+        }
+        if self.mip != MipFilter::Linear {
+            parts.push(format!("mip: {:?}", self.mip));
+        }
+        for (name, mode) in [("u", self.u), ("v", self.v), ("w", self.w)] {
+            if mode != AddressMode::Repeat {
+                parts.push(format!("{}: {:?}", name, mode));
+            }
+        }
+        if let Some(compare) = self.compare {
+            parts.push(format!("compare: {:?}", compare));
+        }
+        if self.border_color != 0 {
+            parts.push(format!("border_color: {}", self.border_color));
+        }
+        if self.sample_stencil {
+            parts.push("sample_stencil".to_string());
+        }
+        write_settings(f, &parts, "default")
+    }
+}
+
+/// Sampler passed to [Encoder::set_texture](crate::bgfx::Encoder::set_texture), either the
+/// flags the texture was created with, a [SamplerDesc], or raw sampler flags.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Sampler {
+    /// Samples with the flags the texture was created with.
+    Inherit,
+    /// Samples with the descriptor, overriding the flags of the texture.
+    Desc(SamplerDesc),
+    /// Samples with sampler flags that are passed to bgfx as they are, such as
+    /// [SamplerMinFlags] and [SamplerUFlags](crate::bgfx::SamplerUFlags) bits combined.
+    Raw(u32),
+}
+
+impl Sampler {
+    /// Returns the sampler flags to pass to
+    /// [Encoder::set_texture](crate::bgfx::Encoder::set_texture), which are `u32::MAX` for
+    /// [Sampler::Inherit].
+    pub fn to_bits(&self) -> u32 {
+        match self {
+            Sampler::Inherit => u32::MAX,
+            Sampler::Desc(desc) => desc.to_bits(),
+            Sampler::Raw(bits) => *bits,
+        }
+    }
+
+    /// Decodes sampler flags as passed to
+    /// [Encoder::set_texture](crate::bgfx::Encoder::set_texture), see
+    /// [SamplerDesc::from_bits].
+    pub fn from_bits(bits: u32) -> Option<Sampler> {
+        if bits == u32::MAX {
+            Some(Sampler::Inherit)
+        } else {
+            SamplerDesc::from_bits(bits).map(Sampler::Desc)
+        }
+    }
+}
+
+impl From<SamplerDesc> for Sampler {
+    fn from(desc: SamplerDesc) -> Sampler {
+        Sampler::Desc(desc)
+    }
+}
+
+impl fmt::Display for Sampler {
+    /// Formats the descriptor, `inherit`, or the raw flags such as `raw: 0x10`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Sampler::Inherit => write!(f, "inherit"),
+            Sampler::Desc(desc) => write!(f, "{}", desc),
+            Sampler::Raw(bits) => write!(f, "raw: {:#x}", bits),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flag_bits::assert_round_trip;
+
+    fn round_trip(desc: SamplerDesc) {
+        assert_round_trip(desc);
+        let sampler = Sampler::from(desc);
+        assert_eq!(Sampler::from_bits(sampler.to_bits()), Some(sampler));
+    }
+
+    #[test]
+    fn default() {
+        assert_eq!(SamplerDesc::DEFAULT.to_bits(), 0);
+        round_trip(SamplerDesc::DEFAULT);
+    }
+
+    #[test]
+    fn filters() {
+        for &min in Filter::ALL {
+            for &mag in Filter::ALL {
+                for mip in [MipFilter::Linear, MipFilter::Point] {
+                    round_trip(SamplerDesc::default().min(min).mag(mag).mip(mip));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn address_modes() {
+        for &u in AddressMode::ALL {
+            for &v in AddressMode::ALL {
+                for &w in AddressMode::ALL {
+                    round_trip(SamplerDesc::default().u(u).v(v).w(w));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn compare_and_border() {
+        round_trip(SamplerDesc::default().compare(None));
+        for &compare in CompareFunc::ALL {
+            for border_color in [0, 1, 15] {
+                round_trip(
+                    SamplerDesc::default()
+                        .compare(compare)
+                        .border_color(border_color)
+                        .address(AddressMode::Border)
+                        .sample_stencil(true),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn inherit() {
+        assert_eq!(Sampler::Inherit.to_bits(), u32::MAX);
+        assert_eq!(Sampler::from_bits(u32::MAX), Some(Sampler::Inherit));
+        assert_eq!(SamplerDesc::from_bits(u32::MAX), None);
+    }
+
+    #[test]
+    fn raw() {
+        let bits = SamplerMinFlags::POINT.bits() | bgfx_sys::BGFX_SAMPLER_U_CLAMP;
+        assert_eq!(Sampler::Raw(bits).to_bits(), bits);
+        assert_eq!(Sampler::Raw(bits).to_string(), format!("raw: {:#x}", bits));
+    }
+
+    #[test]
+    fn texture_flags() {
+        let desc = SamplerDesc::default().filter(Filter::Point);
+        assert_eq!(desc.texture_flags().bits(), desc.to_bits() as u64);
+    }
+
+    #[test]
+    fn unknown_bits_are_rejected() {
+        let bits = SamplerDesc::default().to_bits();
+        // Filter 3 is past the known filters.
+        let unknown_filter = bits | (3 << bgfx_sys::BGFX_SAMPLER_MIN_SHIFT);
+        assert_eq!(SamplerDesc::from_bits(unknown_filter), None);
+        assert_eq!(Sampler::from_bits(unknown_filter), None);
+    }
+}
@@ -0,0 +1,745 @@
+use crate::bgfx::{self, BgfxError, Memory, Program, RendererType, Shader, UniformType};
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Oldest shader binary versions bgfx accepts.
+const MIN_VERSION_COMPUTE: u8 = 3;
+const MIN_VERSION_GRAPHICS: u8 = 5;
+
+/// Bits bgfx stores together with the uniform type in shader binaries.
+const UNIFORM_FRAGMENT_BIT: u8 = 0x10;
+const UNIFORM_MASK: u8 = 0xf0;
+
+/// Stage of a compiled shader.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ShaderKind {
+    /// Vertex shader (`VSH`).
+    Vertex,
+    /// Fragment shader (`FSH`).
+    Fragment,
+    /// Compute shader (`CSH`).
+    Compute,
+}
+
+/// Format of the bytecode section, guessed from its contents.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ShaderCodeFormat {
+    /// Direct3D 9 bytecode.
+    Dx9,
+    /// DXBC container, used by Direct3D 11 and 12.
+    Dxbc,
+    /// SPIR-V, used by Vulkan and WebGPU.
+    SpirV,
+    /// Metal shading language source.
+    Metal,
+    /// GLSL or ESSL source.
+    Glsl,
+    /// Anything else, such as console specific formats.
+    Unknown,
+}
+
+impl ShaderCodeFormat {
+    fn detect(code: &[u8]) -> ShaderCodeFormat {
+        if code.starts_with(b"DXBC") {
+            ShaderCodeFormat::Dxbc
+        } else if code.starts_with(&[0x03, 0x02, 0x23, 0x07]) {
+            ShaderCodeFormat::SpirV
+        } else if code.len() >= 4 && (code[2..4] == [0xfe, 0xff] || code[2..4] == [0xff, 0xff]) {
+            ShaderCodeFormat::Dx9
+        } else if std::str::from_utf8(code.split(|&c| c == 0).next().unwrap_or(&[])).is_ok() {
+            let contains = |needle: &[u8]| code.windows(needle.len()).any(|w| w == needle);
+            if contains(b"metal_stdlib") || contains(b"using namespace metal") {
+                ShaderCodeFormat::Metal
+            } else {
+                ShaderCodeFormat::Glsl
+            }
+        } else {
+            ShaderCodeFormat::Unknown
+        }
+    }
+
+    /// Returns `true` if shaders in this format can be used with `renderer`.
+    pub fn is_supported_by(self, renderer: RendererType) -> bool {
+        match renderer {
+            RendererType::Noop | RendererType::Count => true,
+            RendererType::Direct3D9 => self == ShaderCodeFormat::Dx9,
+            RendererType::Direct3D11 | RendererType::Direct3D12 => self == ShaderCodeFormat::Dxbc,
+            RendererType::Vulkan | RendererType::WebGPU => self == ShaderCodeFormat::SpirV,
+            RendererType::Metal => self == ShaderCodeFormat::Metal,
+            RendererType::OpenGL | RendererType::OpenGLES => self == ShaderCodeFormat::Glsl,
+            RendererType::Agc | RendererType::Gnm | RendererType::Nvn => {
+                self == ShaderCodeFormat::Unknown
+            }
+        }
+    }
+}
+
+/// Error returned when parsing or creating a shader from a compiled shader binary.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ShaderError {
+    /// The binary ended before the section being read.
+    Truncated,
+    /// The binary doesn't start with `VSH`, `FSH` or `CSH`.
+    InvalidMagic,
+    /// The binary was compiled with a version of shaderc that bgfx no longer supports.
+    UnsupportedVersion(u8),
+    /// The uniform table contains an unknown uniform type.
+    InvalidUniformType(u8),
+    /// The bytecode was compiled for a different renderer.
+    WrongRenderer {
+        /// Renderer bgfx is running with.
+        renderer: RendererType,
+        /// Format of the bytecode in the binary.
+        format: ShaderCodeFormat,
+    },
+    /// bgfx failed to create the shader.
+    Create(BgfxError),
+}
+
+impl fmt::Display for ShaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShaderError::Truncated => write!(f, "shader binary is truncated"),
+            ShaderError::InvalidMagic => write!(f, "not a bgfx shader binary"),
+            ShaderError::UnsupportedVersion(v) => {
+                write!(f, "unsupported shader binary version {}", v)
+            }
+            ShaderError::InvalidUniformType(t) => write!(f, "invalid uniform type {}", t),
+            ShaderError::WrongRenderer { renderer, format } => write!(
+                f,
+                "shader bytecode is {:?}, which can't be used with {:?}",
+                format, renderer
+            ),
+            ShaderError::Create(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl std::error::Error for ShaderError {}
+
+impl From<BgfxError> for ShaderError {
+    fn from(e: BgfxError) -> ShaderError {
+        ShaderError::Create(e)
+    }
+}
+
+/// Uniform entry from the uniform table of a shader binary.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ShaderUniform {
+    /// Uniform name.
+    pub name: String,
+    /// Uniform type.
+    pub type_r: UniformType,
+    /// Number of elements in array.
+    pub num: u8,
+    /// `true` if the uniform is used by the fragment stage.
+    pub fragment: bool,
+    /// First register or location of the uniform.
+    pub reg_index: u16,
+    /// Number of registers used by the uniform.
+    pub reg_count: u16,
+    /// Texture dimension and component type of samplers (version 8 and later).
+    pub tex_info: u16,
+    /// Texture format of storage images (version 10 and later).
+    pub tex_format: u16,
+}
+
+/// Compiled shader, as produced by `shaderc`, split into its sections.
+#[derive(Clone, Debug)]
+pub struct ShaderBinary<'a> {
+    /// Shader stage.
+    pub kind: ShaderKind,
+    /// Version of the binary format.
+    pub version: u8,
+    /// Hash of the shader inputs, matched against the outputs of the vertex shader when
+    /// creating a program.
+    pub hash_in: u32,
+    /// Hash of the shader outputs.
+    pub hash_out: u32,
+    /// Uniforms used by the shader, including predefined ones.
+    pub uniforms: Vec<ShaderUniform>,
+    /// Thread group size of Metal compute shaders.
+    pub num_threads: Option<[u16; 3]>,
+    /// Shader bytecode or source.
+    pub code: &'a [u8],
+    /// Ids of the vertex attributes used by the shader, if the renderer needs them.
+    pub attributes: Vec<u16>,
+    /// Size of the constant buffer, if the renderer needs it.
+    pub constant_size: u16,
+    data: &'a [u8],
+}
+
+struct Reader<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8], ShaderError> {
+        let end = self.pos.checked_add(len).ok_or(ShaderError::Truncated)?;
+        let bytes = self.data.get(self.pos..end).ok_or(ShaderError::Truncated)?;
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    fn u8(&mut self) -> Result<u8, ShaderError> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, ShaderError> {
+        let b = self.bytes(2)?;
+        Ok(u16::from_le_bytes([b[0], b[1]]))
+    }
+
+    fn u32(&mut self) -> Result<u32, ShaderError> {
+        let b = self.bytes(4)?;
+        Ok(u32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+    }
+
+    fn remaining(&self) -> usize {
+        self.data.len() - self.pos
+    }
+}
+
+impl<'a> ShaderBinary<'a> {
+    /// Parses a shader binary, such as the `.bin` files compiled by `shaderc`.
+    pub fn parse(data: &'a [u8]) -> Result<ShaderBinary<'a>, ShaderError> {
+        let mut reader = Reader { data, pos: 0 };
+        let magic = reader.bytes(4)?;
+        let kind = match &magic[..3] {
+            b"VSH" => ShaderKind::Vertex,
+            b"FSH" => ShaderKind::Fragment,
+            b"CSH" => ShaderKind::Compute,
+            _ => return Err(ShaderError::InvalidMagic),
+        };
+        let version = magic[3];
+        let min_version = match kind {
+            ShaderKind::Compute => MIN_VERSION_COMPUTE,
+            _ => MIN_VERSION_GRAPHICS,
+        };
+        if version < min_version {
+            return Err(ShaderError::UnsupportedVersion(version));
+        }
+
+        let hash_in = reader.u32()?;
+        let hash_out = if version < 6 { hash_in } else { reader.u32()? };
+
+        let count = reader.u16()?;
+        let mut uniforms = Vec::with_capacity(count as usize);
+        for _ in 0..count {
+            let name_size = reader.u8()?;
+            let name = String::from_utf8_lossy(reader.bytes(name_size as usize)?).into_owned();
+            let type_bits = reader.u8()?;
+            let type_r = match type_bits & !UNIFORM_MASK {
+                0 => UniformType::Sampler,
+                1 => UniformType::End,
+                2 => UniformType::Vec4,
+                3 => UniformType::Mat3,
+                4 => UniformType::Mat4,
+                _ => return Err(ShaderError::InvalidUniformType(type_bits)),
+            };
+            let num = reader.u8()?;
+            let reg_index = reader.u16()?;
+            let reg_count = reader.u16()?;
+            let tex_info = if version >= 8 { reader.u16()? } else { 0 };
+            let tex_format = if version >= 10 { reader.u16()? } else { 0 };
+            uniforms.push(ShaderUniform {
+                name,
+                type_r,
+                num,
+                fragment: type_bits & UNIFORM_FRAGMENT_BIT != 0,
+                reg_index,
+                reg_count,
+                tex_info,
+                tex_format,
+            });
+        }
+
+        // Metal compute shaders store their thread group size in front of the code. Nothing in
+        // the header says the binary is for Metal, so fall back to it if the code doesn't fit.
+        let code_start = reader.pos;
+        let mut num_threads = None;
+        let mut code_size = reader.u32()? as usize;
+        if kind == ShaderKind::Compute && code_size > reader.remaining() {
+            reader.pos = code_start;
+            num_threads = Some([reader.u16()?, reader.u16()?, reader.u16()?]);
+            code_size = reader.u32()? as usize;
+        }
+        let code = reader.bytes(code_size)?;
+
+        // The code is followed by a null terminator, and for renderers that need them the
+        // attribute list and constant buffer size.
+        let mut attributes = Vec::new();
+        let mut constant_size = 0;
+        if reader.remaining() > 0 {
+            reader.u8()?;
+        }
+        if reader.remaining() > 0 {
+            let num_attrs = reader.u8()?;
+            for _ in 0..num_attrs {
+                attributes.push(reader.u16()?);
+            }
+            constant_size = reader.u16()?;
+        }
+
+        Ok(ShaderBinary {
+            kind,
+            version,
+            hash_in,
+            hash_out,
+            uniforms,
+            num_threads,
+            code,
+            attributes,
+            constant_size,
+            data,
+        })
+    }
+
+    /// Returns the format of the bytecode section.
+    pub fn code_format(&self) -> ShaderCodeFormat {
+        ShaderCodeFormat::detect(self.code)
+    }
+
+    /// Checks that the bytecode can be used with `renderer`.
+    pub fn check_renderer(&self, renderer: RendererType) -> Result<(), ShaderError> {
+        let format = self.code_format();
+        if format.is_supported_by(renderer) {
+            Ok(())
+        } else {
+            Err(ShaderError::WrongRenderer { renderer, format })
+        }
+    }
+
+    /// Returns the whole binary, as passed to [ShaderBinary::parse].
+    pub fn data(&self) -> &'a [u8] {
+        self.data
+    }
+
+    /// Checks the binary against the current renderer and creates a shader from it.
+    pub fn create_shader(&self) -> Result<Shader, ShaderError> {
+        self.check_renderer(bgfx::get_renderer_type())?;
+        let mut data = Vec::with_capacity(self.data.len() + 1);
+        data.extend_from_slice(self.data);
+        data.push(0);
+        Ok(Shader::try_create_shader(&Memory::copy(&data))?)
+    }
+}
+
+/// Returns the directory bgfx's tools and examples use for shaders compiled for `renderer`,
+/// such as `glsl` for [RendererType::OpenGL].
+pub fn shader_dir(renderer: RendererType) -> Option<&'static str> {
+    match renderer {
+        RendererType::Noop | RendererType::Direct3D11 | RendererType::Direct3D12 => Some("dx11"),
+        RendererType::Direct3D9 => Some("dx9"),
+        RendererType::Agc | RendererType::Gnm => Some("pssl"),
+        RendererType::Metal => Some("metal"),
+        RendererType::Nvn => Some("nvn"),
+        RendererType::OpenGL => Some("glsl"),
+        RendererType::OpenGLES => Some("essl"),
+        RendererType::Vulkan | RendererType::WebGPU => Some("spirv"),
+        RendererType::Count => None,
+    }
+}
+
+/// Shader binary embedded in the executable, for use with [ShaderBundle::embedded].
+///
+/// ```ignore
+/// static SHADERS: &[EmbeddedShader] = &[
+///     EmbeddedShader {
+///         renderer: RendererType::OpenGLES,
+///         name: "vs_cubes",
+///         data: include_bytes!("shaders/essl/vs_cubes.bin"),
+///     },
+///     // ...
+/// ];
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct EmbeddedShader<'a> {
+    /// Renderer the shader was compiled for.
+    pub renderer: RendererType,
+    /// Shader name, such as `vs_cubes`.
+    pub name: &'a str,
+    /// Compiled shader binary.
+    pub data: &'a [u8],
+}
+
+/// Table of [EmbeddedShader]s for the vertex and fragment shader `vs_<name>` and `fs_<name>`,
+/// compiled for every renderer and stored in `src/shaders/<dir>/`. The sources are next to them
+/// and are compiled with `src/shaders/compile.sh`.
+macro_rules! embedded_shaders {
+    ($name:literal) => {
+        embedded_shaders!(
+            $name;
+            Direct3D11 => "dx11",
+            Direct3D9 => "dx9",
+            OpenGLES => "essl",
+            OpenGL => "glsl",
+            Metal => "metal",
+            Vulkan => "spirv"
+        )
+    };
+    ($name:literal; $($renderer:ident => $dir:literal),*) => {
+        &[$(
+            $crate::bgfx::EmbeddedShader {
+                renderer: $crate::bgfx::RendererType::$renderer,
+                name: concat!("vs_", $name),
+                data: include_bytes!(concat!("shaders/", $dir, "/vs_", $name, ".bin")),
+            },
+            $crate::bgfx::EmbeddedShader {
+                renderer: $crate::bgfx::RendererType::$renderer,
+                name: concat!("fs_", $name),
+                data: include_bytes!(concat!("shaders/", $dir, "/fs_", $name, ".bin")),
+            },
+        )*]
+    };
+}
+
+pub(crate) use embedded_shaders;
+
+/// Error returned when loading shaders from a [ShaderBundle].
+#[derive(Debug)]
+pub enum ShaderBundleError {
+    /// The bundle has no shaders for the renderer bgfx is running with.
+    UnsupportedRenderer(RendererType),
+    /// No variant of the shader can be used with the renderer bgfx is running with.
+    NotFound {
+        /// Shader name.
+        name: String,
+        /// Renderer bgfx is running with.
+        renderer: RendererType,
+    },
+    /// The shader file couldn't be read.
+    Io {
+        /// Path of the shader file.
+        path: PathBuf,
+        /// Error reading the file.
+        error: io::Error,
+    },
+    /// The shader binary is invalid or bgfx failed to create the shader.
+    Shader {
+        /// Shader name.
+        name: String,
+        /// Error parsing or creating the shader.
+        error: ShaderError,
+    },
+    /// bgfx failed to create the program.
+    Program(BgfxError),
+}
+
+impl fmt::Display for ShaderBundleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ShaderBundleError::UnsupportedRenderer(renderer) => {
+                write!(f, "no shaders for renderer {:?}", renderer)
+            }
+            ShaderBundleError::NotFound { name, renderer } => {
+                write!(f, "shader {} not found for renderer {:?}", name, renderer)
+            }
+            ShaderBundleError::Io { path, error } => {
+                write!(f, "unable to read {}: {}", path.display(), error)
+            }
+            ShaderBundleError::Shader { name, error } => write!(f, "shader {}: {}", name, error),
+            ShaderBundleError::Program(e) => write!(f, "unable to create program: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for ShaderBundleError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ShaderBundleError::Io { error, .. } => Some(error),
+            ShaderBundleError::Shader { error, .. } => Some(error),
+            ShaderBundleError::Program(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Source<'a> {
+    Dir(PathBuf),
+    Embedded(&'a [EmbeddedShader<'a>]),
+}
+
+/// Set of shaders compiled for several renderers, from which the variant for the renderer bgfx
+/// is running with is picked when creating shaders and programs.
+#[derive(Clone, Debug)]
+pub struct ShaderBundle<'a> {
+    source: Source<'a>,
+}
+
+impl<'a> ShaderBundle<'a> {
+    /// Loads shaders from a directory with one sub-directory per renderer, named as returned by
+    /// [shader_dir], each holding `<name>.bin` files. This is the layout of
+    /// `bgfx/examples/runtime/shaders`.
+    pub fn from_dir<P: AsRef<Path>>(path: P) -> ShaderBundle<'static> {
+        ShaderBundle {
+            source: Source::Dir(path.as_ref().to_path_buf()),
+        }
+    }
+
+    /// Loads shaders from a table of shaders embedded in the executable. Shaders compiled for
+    /// the current renderer are preferred, otherwise any variant that the renderer can use is
+    /// picked, such as `dx11` shaders for [RendererType::Direct3D12].
+    pub fn embedded(shaders: &'a [EmbeddedShader<'a>]) -> ShaderBundle<'a> {
+        ShaderBundle {
+            source: Source::Embedded(shaders),
+        }
+    }
+
+    /// Returns the binary of shader `name` for `renderer`.
+    pub fn load(&self, name: &str, renderer: RendererType) -> Result<Vec<u8>, ShaderBundleError> {
+        match &self.source {
+            Source::Dir(root) => {
+                let dir =
+                    shader_dir(renderer).ok_or(ShaderBundleError::UnsupportedRenderer(renderer))?;
+                let path = root.join(dir).join(format!("{}.bin", name));
+                std::fs::read(&path).map_err(|error| ShaderBundleError::Io { path, error })
+            }
+            Source::Embedded(shaders) => {
+                let named = || shaders.iter().filter(|s| s.name == name);
+                named()
+                    .find(|s| s.renderer == renderer)
+                    .or_else(|| {
+                        named().find(|s| match ShaderBinary::parse(s.data) {
+                            Ok(binary) => binary.code_format().is_supported_by(renderer),
+                            Err(_) => false,
+                        })
+                    })
+                    .map(|s| s.data.to_vec())
+                    .ok_or_else(|| ShaderBundleError::NotFound {
+                        name: name.to_owned(),
+                        renderer,
+                    })
+            }
+        }
+    }
+
+    /// Creates shader `name` for the renderer bgfx is running with.
+    pub fn create_shader(&self, name: &str) -> Result<Shader, ShaderBundleError> {
+        let data = self.load(name, bgfx::get_renderer_type())?;
+        ShaderBinary::parse(&data)
+            .and_then(|binary| binary.create_shader())
+            .map_err(|error| ShaderBundleError::Shader {
+                name: name.to_owned(),
+                error,
+            })
+    }
+
+    /// Creates a program from vertex shader `vsh` and fragment shader `fsh`.
+    pub fn create_program(&self, vsh: &str, fsh: &str) -> Result<Program, ShaderBundleError> {
+        let vsh = self.create_shader(vsh)?;
+        let fsh = self.create_shader(fsh)?;
+        Program::try_create_program(&vsh, &fsh, false).map_err(ShaderBundleError::Program)
+    }
+
+    /// Creates a compute program from compute shader `csh`.
+    pub fn create_compute_program(&self, csh: &str) -> Result<Program, ShaderBundleError> {
+        let csh = self.create_shader(csh)?;
+        Program::try_create_compute_program(&csh, false).map_err(ShaderBundleError::Program)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BACKENDS: &[(&str, ShaderCodeFormat)] = &[
+        ("dx9", ShaderCodeFormat::Dx9),
+        ("dx11", ShaderCodeFormat::Dxbc),
+        ("essl", ShaderCodeFormat::Glsl),
+        ("glsl", ShaderCodeFormat::Glsl),
+        ("metal", ShaderCodeFormat::Metal),
+        ("spirv", ShaderCodeFormat::SpirV),
+    ];
+
+    fn shaders_dir() -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("resources/examples/runtime/shaders")
+    }
+
+    fn read(backend: &str, name: &str) -> Vec<u8> {
+        std::fs::read(shaders_dir().join(backend).join(format!("{}.bin", name))).unwrap()
+    }
+
+    fn uniform<'a>(binary: &'a ShaderBinary, name: &str) -> &'a ShaderUniform {
+        binary
+            .uniforms
+            .iter()
+            .find(|u| u.name == name)
+            .unwrap_or_else(|| panic!("no uniform {}", name))
+    }
+
+    /// Header of a binary of `version` with a single uniform `u_color` and 4 bytes of code.
+    fn synthetic(magic: &[u8; 3], version: u8) -> Vec<u8> {
+        let mut data = magic.to_vec();
+        data.push(version);
+        data.extend_from_slice(&0x1234_5678u32.to_le_bytes());
+        if version >= 6 {
+            data.extend_from_slice(&0x9abc_def0u32.to_le_bytes());
+        }
+        data.extend_from_slice(&1u16.to_le_bytes());
+        data.push(7);
+        data.extend_from_slice(b"u_color");
+        data.push(2 | UNIFORM_FRAGMENT_BIT);
+        data.push(1);
+        data.extend_from_slice(&3u16.to_le_bytes());
+        data.extend_from_slice(&1u16.to_le_bytes());
+        if version >= 8 {
+            data.extend_from_slice(&5u16.to_le_bytes());
+        }
+        if version >= 10 {
+            data.extend_from_slice(&6u16.to_le_bytes());
+        }
+        data.extend_from_slice(&4u32.to_le_bytes());
+        data.extend_from_slice(b"code");
+        data.push(0);
+        data
+    }
+
+    #[test]
+    fn parses_every_backend() {
+        for &(backend, format) in BACKENDS {
+            let mut count = 0;
+            for entry in std::fs::read_dir(shaders_dir().join(backend)).unwrap() {
+                let path = entry.unwrap().path();
+                let name = path.file_name().unwrap().to_str().unwrap();
+                let data = std::fs::read(&path).unwrap();
+                let binary = ShaderBinary::parse(&data)
+                    .unwrap_or_else(|e| panic!("{}/{}: {}", backend, name, e));
+
+                let kind = match &name[..3] {
+                    "vs_" => ShaderKind::Vertex,
+                    "fs_" => ShaderKind::Fragment,
+                    _ => ShaderKind::Compute,
+                };
+                assert_eq!(binary.kind, kind, "{}/{}", backend, name);
+                assert_eq!(binary.code_format(), format, "{}/{}", backend, name);
+                assert!(!binary.code.is_empty(), "{}/{}", backend, name);
+                assert_eq!(binary.data(), &data[..]);
+                count += 1;
+            }
+            assert!(count > 0, "no shaders for {}", backend);
+        }
+    }
+
+    #[test]
+    fn check_renderer() {
+        let data = read("spirv", "vs_cubes");
+        let binary = ShaderBinary::parse(&data).unwrap();
+        assert_eq!(binary.check_renderer(RendererType::Vulkan), Ok(()));
+        assert_eq!(binary.check_renderer(RendererType::Noop), Ok(()));
+        assert_eq!(
+            binary.check_renderer(RendererType::OpenGL),
+            Err(ShaderError::WrongRenderer {
+                renderer: RendererType::OpenGL,
+                format: ShaderCodeFormat::SpirV,
+            })
+        );
+    }
+
+    #[test]
+    fn uniforms() {
+        for &(backend, _) in BACKENDS {
+            let data = read(backend, "vs_cubes");
+            let binary = ShaderBinary::parse(&data).unwrap();
+            let mvp = uniform(&binary, "u_modelViewProj");
+            assert_eq!(mvp.type_r, UniformType::Mat4, "{}", backend);
+            assert!(!mvp.fragment, "{}", backend);
+
+            // GLSL binaries don't mark the uniforms of fragment shaders.
+            let glsl = backend == "glsl" || backend == "essl";
+            let data = read(backend, "fs_bump");
+            let binary = ShaderBinary::parse(&data).unwrap();
+            for name in ["s_texColor", "s_texNormal"] {
+                let sampler = uniform(&binary, name);
+                assert_eq!(sampler.type_r, UniformType::Sampler, "{}", backend);
+                assert_eq!(sampler.fragment, !glsl, "{}", backend);
+            }
+            let light = uniform(&binary, "u_lightPosRadius");
+            assert_eq!(light.type_r, UniformType::Vec4, "{}", backend);
+            assert_eq!(light.num, 4, "{}", backend);
+            assert_eq!(light.fragment, !glsl, "{}", backend);
+        }
+    }
+
+    #[test]
+    fn version_gated_fields() {
+        for version in [5, 6, 8, 9, 10, 11] {
+            let data = synthetic(b"FSH", version);
+            let binary = ShaderBinary::parse(&data).unwrap();
+            assert_eq!(binary.version, version);
+            assert_eq!(binary.hash_in, 0x1234_5678);
+            let hash_out = if version >= 6 {
+                0x9abc_def0
+            } else {
+                0x1234_5678
+            };
+            assert_eq!(binary.hash_out, hash_out);
+            assert_eq!(
+                binary.uniforms,
+                vec![ShaderUniform {
+                    name: "u_color".to_owned(),
+                    type_r: UniformType::Vec4,
+                    num: 1,
+                    fragment: true,
+                    reg_index: 3,
+                    reg_count: 1,
+                    tex_info: if version >= 8 { 5 } else { 0 },
+                    tex_format: if version >= 10 { 6 } else { 0 },
+                }]
+            );
+            assert_eq!(binary.code, b"code");
+        }
+
+        // The examples are compiled with versions 6 (compute), 9 and 11.
+        let data = read("glsl", "vs_instancing");
+        assert_eq!(ShaderBinary::parse(&data).unwrap().version, 11);
+        let data = read("spirv", "fs_bump");
+        let binary = ShaderBinary::parse(&data).unwrap();
+        assert_eq!(binary.version, 9);
+        assert_ne!(uniform(&binary, "s_texColor").tex_info, 0);
+    }
+
+    #[test]
+    fn metal_compute_num_threads() {
+        let data = read("metal", "cs_update");
+        let binary = ShaderBinary::parse(&data).unwrap();
+        assert_eq!(binary.kind, ShaderKind::Compute);
+        assert!(binary.num_threads.is_some());
+
+        let data = read("spirv", "cs_update");
+        assert_eq!(ShaderBinary::parse(&data).unwrap().num_threads, None);
+    }
+
+    #[test]
+    fn invalid_binaries() {
+        assert_eq!(
+            ShaderBinary::parse(b"PNG\x09").unwrap_err(),
+            ShaderError::InvalidMagic
+        );
+        assert_eq!(
+            ShaderBinary::parse(&synthetic(b"VSH", 4)).unwrap_err(),
+            ShaderError::UnsupportedVersion(4)
+        );
+        assert!(ShaderBinary::parse(&synthetic(b"CSH", 3)).is_ok());
+
+        let data = synthetic(b"VSH", 9);
+        for len in 0..data.len() - 5 {
+            assert_eq!(
+                ShaderBinary::parse(&data[..len]).unwrap_err(),
+                ShaderError::Truncated,
+                "{} bytes",
+                len
+            );
+        }
+
+        let mut data = synthetic(b"VSH", 9);
+        data[22] = 9;
+        assert_eq!(
+            ShaderBinary::parse(&data).unwrap_err(),
+            ShaderError::InvalidUniformType(9)
+        );
+    }
+}
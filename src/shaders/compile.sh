@@ -0,0 +1,39 @@
+#!/bin/sh
+# Regenerates the shader binaries embedded by bgfx-rs, with the same profiles as bgfx's
+# scripts/shader.mk. Run from anywhere with:
+#
+#   SHADERC=path/to/shaderc BGFX_DIR=path/to/bgfx src/shaders/compile.sh
+#
+# The dx9 and dx11 shaders can only be compiled on Windows.
+set -e
+
+cd "$(dirname "$0")"
+SHADERC=${SHADERC:-shaderc}
+BGFX_DIR=${BGFX_DIR:?set BGFX_DIR to the bgfx source directory}
+
+compile() {
+	dir=$1
+	shift
+	mkdir -p "$dir"
+	for name in debug_draw imgui; do
+		for stage in vs fs; do
+			if [ "$stage" = vs ]; then type=vertex; else type=fragment; fi
+			"$SHADERC" -f "${stage}_${name}.sc" -o "$dir/${stage}_${name}.bin" \
+				--type "$type" -i "$BGFX_DIR/src" --varyingdef varying.def.sc "$@"
+		done
+	done
+}
+
+compile essl --platform android -p 100_es
+compile glsl --platform linux -p 120
+compile spirv --platform linux -p spirv
+compile metal --platform osx -p metal
+case "$(uname -s)" in
+	MINGW* | MSYS* | CYGWIN*)
+		compile dx9 --platform windows -p s_3_0 -O 3
+		compile dx11 --platform windows -p s_5_0 -O 3
+		;;
+	*)
+		echo "skipping dx9 and dx11, which need shaderc on Windows" >&2
+		;;
+esac
@@ -0,0 +1,11 @@
+$input v_color0, v_texcoord0
+
+#include <bgfx_shader.sh>
+
+SAMPLER2D(s_texColor, 0);
+uniform vec4 u_params;
+
+void main()
+{
+	gl_FragColor = v_color0 * texture2D(s_texColor, v_texcoord0) * vec4(1.0, 1.0, 1.0, u_params.w);
+}
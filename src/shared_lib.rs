@@ -1,12 +1,61 @@
 // This code has been auto-generated by https://github.com/emoon/bgfx-rs-bindgen
 use core::ffi::c_void;
 use std::mem::MaybeUninit;
-STATIC g_vtbl: *const bgfx_sys::bgfx_interface_vtbl_t = std::ptr::null();
 
-pub fn set_interface(face: *const bgfx_sys::bgfx_interface_vtbl_t) {
-g_vtbl = face;
+use std::sync::atomic::{AtomicPtr, Ordering};
+
+/// Interface all functions in this module call bgfx through.
+static INTERFACE: AtomicPtr<bgfx_sys::bgfx_interface_vtbl_t> = AtomicPtr::new(std::ptr::null_mut());
+
+/// Sets the bgfx interface used by this module, for example one obtained from a host
+/// application with `get_interface`. Use [load_library] to load bgfx from a shared library
+/// instead.
+///
+/// The host has already initialized bgfx, so setting an interface starts a session as
+/// [Bgfx::init] would: handles created afterwards are destroyed when dropped. Setting a null
+/// interface ends the session, after which dropped handles are left to the host. Report the
+/// numbers returned by the host's `frame` with [set_host_frame], so that reads started here
+/// become ready.
+///
+/// # Safety
+///
+/// `face` must point to a bgfx interface of version [bgfx_sys::BGFX_API_VERSION] that stays
+/// valid until a null interface is set, and bgfx must be initialized until then.
+pub unsafe fn set_interface(face: *const bgfx_sys::bgfx_interface_vtbl_t) {
+    let previous = INTERFACE.swap(face as *mut _, Ordering::AcqRel);
+    if previous.is_null() != face.is_null() {
+        crate::context::set_initialized(!face.is_null());
+    }
 }
 
+/// Sets the interface without starting a session, as bgfx still has to be initialized.
+pub(crate) fn store_interface(face: *const bgfx_sys::bgfx_interface_vtbl_t) {
+    INTERFACE.store(face as *mut _, Ordering::Release);
+}
+
+/// Reports the number returned by `frame` in the host application, when the interface was set
+/// with [set_interface]. Reads such as [Texture::read] become ready once the frame they were
+/// started in is reported.
+pub fn set_host_frame(frame: u32) {
+    crate::pending::frame_done(frame);
+}
+
+/// Returns the bgfx interface used by this module, or null if none has been set.
+pub fn get_interface() -> *const bgfx_sys::bgfx_interface_vtbl_t {
+    INTERFACE.load(Ordering::Acquire)
+}
+
+fn vtbl() -> &'static bgfx_sys::bgfx_interface_vtbl_t {
+    let face = INTERFACE.load(Ordering::Acquire);
+    assert!(
+        !face.is_null(),
+        "bgfx interface not set, call `load_library` or `set_interface` first"
+    );
+    unsafe { &*face }
+}
+
+pub use crate::shared_api::{load_library, LoadError};
+
 use cfixed_string::CFixedString;
 /// Fatal error enum.
 #[repr(u32)]
@@ -189,16 +238,32 @@ pub enum TextureFormat {
     ATCI,
     /// ASTC 4x4 8.0 BPP
     ASTC4x4,
+    /// ASTC 5x4 6.40 BPP
+    ASTC5x4,
     /// ASTC 5x5 5.12 BPP
     ASTC5x5,
+    /// ASTC 6x5 4.27 BPP
+    ASTC6x5,
     /// ASTC 6x6 3.56 BPP
     ASTC6x6,
     /// ASTC 8x5 3.20 BPP
     ASTC8x5,
     /// ASTC 8x6 2.67 BPP
     ASTC8x6,
+    /// ASTC 8x8 2.00 BPP
+    ASTC8x8,
     /// ASTC 10x5 2.56 BPP
     ASTC10x5,
+    /// ASTC 10x6 2.13 BPP
+    ASTC10x6,
+    /// ASTC 10x8 1.60 BPP
+    ASTC10x8,
+    /// ASTC 10x10 1.28 BPP
+    ASTC10x10,
+    /// ASTC 12x10 1.07 BPP
+    ASTC12x10,
+    /// ASTC 12x12 0.89 BPP
+    ASTC12x12,
     /// Compressed formats above.
     Unknown,
     R1,
@@ -407,6 +472,18 @@ pub enum RenderFrame {
     Count,
 }
 
+/// Native window handle type.
+#[repr(u32)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum NativeWindowHandleType {
+    /// Platform default handle type (X11 on Linux).
+    Default,
+    /// Wayland.
+    Wayland,
+    /// Number of entries in the enum
+    Count,
+}
+
 bitflags! {
 /// Color RGB/alpha/depth write. When it's not specified write will be disabled.
     pub struct StateWriteFlags : u64 {
@@ -910,6 +987,7 @@ bitflags! {
 }
 
 bitflags! {
+    #[repr(transparent)]
     pub struct ResetFlags : u32 {
         /// No reset flags.
         const NONE = bgfx_sys::BGFX_RESET_NONE as _;
@@ -1047,6 +1125,7 @@ bitflags! {
 }
 
 bitflags! {
+    #[repr(transparent)]
     pub struct ResolveFlags : u8 {
         /// No resolve flags.
         const NONE = bgfx_sys::BGFX_RESOLVE_NONE as _;
@@ -1056,6 +1135,7 @@ bitflags! {
 }
 
 bitflags! {
+    #[repr(transparent)]
     pub struct PciIdFlags : u16 {
         /// Autoselect adapter.
         const NONE = bgfx_sys::BGFX_PCI_ID_NONE as _;
@@ -1096,61 +1176,74 @@ bitflags! {
 #[derive(Clone, Debug)]
 pub struct DynamicIndexBuffer {
     handle: bgfx_sys::bgfx_dynamic_index_buffer_handle_t,
+    session: u32,
 }
 
 #[derive(Clone, Debug)]
 pub struct DynamicVertexBuffer {
     handle: bgfx_sys::bgfx_dynamic_vertex_buffer_handle_t,
+    session: u32,
 }
 
 #[derive(Clone, Debug)]
 pub struct FrameBuffer {
     handle: bgfx_sys::bgfx_frame_buffer_handle_t,
+    session: u32,
 }
 
 #[derive(Clone, Debug)]
 pub struct IndexBuffer {
     handle: bgfx_sys::bgfx_index_buffer_handle_t,
+    session: u32,
 }
 
 #[derive(Clone, Debug)]
 pub struct IndirectBuffer {
     handle: bgfx_sys::bgfx_indirect_buffer_handle_t,
+    session: u32,
 }
 
 #[derive(Clone, Debug)]
 pub struct OcclusionQuery {
     handle: bgfx_sys::bgfx_occlusion_query_handle_t,
+    session: u32,
 }
 
 #[derive(Clone, Debug)]
 pub struct Program {
     handle: bgfx_sys::bgfx_program_handle_t,
+    session: u32,
 }
 
 #[derive(Clone, Debug)]
 pub struct Shader {
     handle: bgfx_sys::bgfx_shader_handle_t,
+    session: u32,
 }
 
 #[derive(Clone, Debug)]
 pub struct Texture {
     handle: bgfx_sys::bgfx_texture_handle_t,
+    session: u32,
+    size: Option<TextureSize>,
 }
 
 #[derive(Clone, Debug)]
 pub struct Uniform {
     handle: bgfx_sys::bgfx_uniform_handle_t,
+    session: u32,
 }
 
 #[derive(Clone, Debug)]
 pub struct VertexBuffer {
     handle: bgfx_sys::bgfx_vertex_buffer_handle_t,
+    session: u32,
 }
 
 #[derive(Clone, Debug)]
 pub struct VertexLayout {
     handle: bgfx_sys::bgfx_vertex_layout_handle_t,
+    session: u32,
 }
 
 pub struct InitArgs {
@@ -1163,7 +1256,7 @@ pub struct InitArgs {
     /// Mip level.
     pub mip: u16,
     /// Resolve flags. See: [ResolveFlags]
-    pub resolve: u8,
+    pub resolve: ResolveFlags,
 }
 
 impl Default for InitArgs {
@@ -1173,7 +1266,7 @@ impl Default for InitArgs {
             layer: 0,
             num_layers: 1,
             mip: 0,
-            resolve: ResolveFlags::AUTO_GEN_MIPS.bits(),
+            resolve: ResolveFlags::AUTO_GEN_MIPS,
         }
     }
 }
@@ -1212,7 +1305,7 @@ pub struct ResetArgs {
     ///     occurs. Default behaviour is that flip occurs before rendering new
     ///     frame. This flag only has effect when `BGFX_CONFIG_MULTITHREADED=0`.
     ///   - [ResetFlags::SRGB_BACKBUFFER] - Enable sRGB back-buffer.
-    pub flags: u32,
+    pub flags: ResetFlags,
     /// Texture format. See: [TextureFormat].
     pub format: TextureFormat,
 }
@@ -1220,7 +1313,7 @@ pub struct ResetArgs {
 impl Default for ResetArgs {
     fn default() -> ResetArgs {
         ResetArgs {
-            flags: ResetFlags::NONE.bits(),
+            flags: ResetFlags::NONE,
             format: TextureFormat::Count,
         }
     }
@@ -1249,7 +1342,8 @@ pub struct CreateTexture3DArgs {
     ///   mode.
     /// - [SamplerFlags::[MIN/MAG/MIP]_[POINT/ANISOTROPIC]] - Point or anisotropic
     ///   sampling.
-    pub flags: u64,
+    ///   A [SamplerDesc] converts to the sampler flags alone with `into()`.
+    pub flags: TextureFlags,
     /// Texture data. If `_mem` is non-NULL, created texture will be immutable. If
     /// `_mem` is NULL content of the texture is uninitialized. When `_numLayers` is more than
     /// 1, expected memory layout is texture and all mips together for each array element.
@@ -1259,7 +1353,7 @@ pub struct CreateTexture3DArgs {
 impl Default for CreateTexture3DArgs {
     fn default() -> CreateTexture3DArgs {
         CreateTexture3DArgs {
-            flags: TextureFlags::NONE.bits() as u64 | SamplerFlags::NONE.bits() as u64,
+            flags: TextureFlags::NONE,
             mem: None,
         }
     }
@@ -1272,7 +1366,8 @@ pub struct CreateTextureCubeArgs {
     ///   mode.
     /// - [SamplerFlags::[MIN/MAG/MIP]_[POINT/ANISOTROPIC]] - Point or anisotropic
     ///   sampling.
-    pub flags: u64,
+    ///   A [SamplerDesc] converts to the sampler flags alone with `into()`.
+    pub flags: TextureFlags,
     /// Texture data. If `_mem` is non-NULL, created texture will be immutable. If
     /// `_mem` is NULL content of the texture is uninitialized. When `_numLayers` is more than
     /// 1, expected memory layout is texture and all mips together for each array element.
@@ -1282,7 +1377,7 @@ pub struct CreateTextureCubeArgs {
 impl Default for CreateTextureCubeArgs {
     fn default() -> CreateTextureCubeArgs {
         CreateTextureCubeArgs {
-            flags: TextureFlags::NONE.bits() as u64 | SamplerFlags::NONE.bits() as u64,
+            flags: TextureFlags::NONE,
             mem: None,
         }
     }
@@ -1327,8 +1422,8 @@ impl Default for SetViewScissorArgs {
 }
 
 pub struct SetViewClearArgs {
-    /// Color clear value.
-    pub rgba: u32,
+    /// Color clear value. A `u32` packed as `0xRRGGBBAA` converts with `into()`.
+    pub rgba: Color,
     /// Depth clear value.
     pub depth: f32,
     /// Stencil clear value.
@@ -1338,7 +1433,7 @@ pub struct SetViewClearArgs {
 impl Default for SetViewClearArgs {
     fn default() -> SetViewClearArgs {
         SetViewClearArgs {
-            rgba: 0x000000ff,
+            rgba: Color::BLACK,
             depth: 1.0,
             stencil: 0,
         }
@@ -1383,14 +1478,14 @@ pub struct SubmitArgs {
     /// Depth for sorting.
     pub depth: u32,
     /// Discard or preserve states. See [DiscardFlags].
-    pub flags: u8,
+    pub flags: DiscardFlags,
 }
 
 impl Default for SubmitArgs {
     fn default() -> SubmitArgs {
         SubmitArgs {
             depth: 0,
-            flags: DiscardFlags::ALL.bits(),
+            flags: DiscardFlags::ALL,
         }
     }
 }
@@ -1399,14 +1494,14 @@ pub struct SubmitOcclusionQueryArgs {
     /// Depth for sorting.
     pub depth: u32,
     /// Discard or preserve states. See [DiscardFlags].
-    pub flags: u8,
+    pub flags: DiscardFlags,
 }
 
 impl Default for SubmitOcclusionQueryArgs {
     fn default() -> SubmitOcclusionQueryArgs {
         SubmitOcclusionQueryArgs {
             depth: 0,
-            flags: DiscardFlags::ALL.bits(),
+            flags: DiscardFlags::ALL,
         }
     }
 }
@@ -1419,7 +1514,7 @@ pub struct SubmitIndirectArgs {
     /// Depth for sorting.
     pub depth: u32,
     /// Discard or preserve states. See [DiscardFlags].
-    pub flags: u8,
+    pub flags: DiscardFlags,
 }
 
 impl Default for SubmitIndirectArgs {
@@ -1428,7 +1523,7 @@ impl Default for SubmitIndirectArgs {
             start: 0,
             num: 1,
             depth: 0,
-            flags: DiscardFlags::ALL.bits(),
+            flags: DiscardFlags::ALL,
         }
     }
 }
@@ -1441,7 +1536,7 @@ pub struct SubmitIndirectCountArgs {
     /// Depth for sorting.
     pub depth: u32,
     /// Discard or preserve states. See [DiscardFlags].
-    pub flags: u8,
+    pub flags: DiscardFlags,
 }
 
 impl Default for SubmitIndirectCountArgs {
@@ -1450,7 +1545,7 @@ impl Default for SubmitIndirectCountArgs {
             num_index: 0,
             num_max: std::u16::MAX,
             depth: 0,
-            flags: DiscardFlags::ALL.bits(),
+            flags: DiscardFlags::ALL,
         }
     }
 }
@@ -1463,7 +1558,7 @@ pub struct DispatchArgs {
     /// Number of groups Z.
     pub num_z: u32,
     /// Discard or preserve states. See [DiscardFlags].
-    pub flags: u8,
+    pub flags: DiscardFlags,
 }
 
 impl Default for DispatchArgs {
@@ -1472,7 +1567,7 @@ impl Default for DispatchArgs {
             num_x: 1,
             num_y: 1,
             num_z: 1,
-            flags: DiscardFlags::ALL.bits(),
+            flags: DiscardFlags::ALL,
         }
     }
 }
@@ -1483,7 +1578,7 @@ pub struct DispatchIndirectArgs {
     /// Number of dispatches.
     pub num: u16,
     /// Discard or preserve states. See [DiscardFlags].
-    pub flags: u8,
+    pub flags: DiscardFlags,
 }
 
 impl Default for DispatchIndirectArgs {
@@ -1491,7 +1586,7 @@ impl Default for DispatchIndirectArgs {
         DispatchIndirectArgs {
             start: 0,
             num: 1,
-            flags: DiscardFlags::ALL.bits(),
+            flags: DiscardFlags::ALL,
         }
     }
 }
@@ -1662,6 +1757,9 @@ pub struct PlatformData {
     /// Backbuffer depth/stencil. If `NULL`, bgfx will create a back-buffer
     /// depth/stencil surface.
     pub back_buffer_ds: *const c_void,
+    /// Handle type. Needed for platforms having more than one option.
+    /// See: `bgfx::NativeWindowHandleType`
+    pub type_r: NativeWindowHandleType,
 }
 /// Backbuffer resolution and reset parameters.
 #[repr(C)]
@@ -1672,8 +1770,8 @@ pub struct Resolution {
     pub width: u32,
     /// Backbuffer height.
     pub height: u32,
-    /// Reset parameters.
-    pub reset: u32,
+    /// Reset parameters. See: [ResetFlags]
+    pub reset: ResetFlags,
     /// Number of back buffers.
     pub num_back_buffers: u8,
     /// Maximum frame latency.
@@ -1707,7 +1805,7 @@ pub struct Init {
     ///   - [PciIdFlags::INTEL] - Intel adapter.
     ///   - [PciIdFlags::NVIDIA] - NVIDIA adapter.
     ///   - [PciIdFlags::MICROSOFT] - Microsoft adapter.
-    pub vendor_id: u16,
+    pub vendor_id: PciIdFlags,
     /// Device ID. If set to 0 it will select first device, or device with
     /// matching ID.
     pub device_id: u16,
@@ -1724,11 +1822,12 @@ pub struct Init {
     /// Configurable runtime limits parameters.
     pub limits: InitLimits,
     /// Provide application specific callback interface.
-    /// See: `bgfx::uint8_t`
+    /// See: [Init::set_callback]
     pub callback: *const u8,
     /// Custom allocator. When a custom allocator is not
     /// specified, bgfx uses the CRT allocator. Bgfx assumes
     /// custom allocator is thread safe.
+    /// See: [Init::set_allocator]
     pub allocator: *const u8,
 }
 /// Transient index buffer.
@@ -1800,10 +1899,10 @@ pub struct TextureInfo {
     pub cube_map: bool,
 }
 /// Uniform info.
-#[repr(C)]
+#[derive(Clone, PartialEq, Debug)]
 pub struct UniformInfo {
     /// Uniform name.
-    pub name: [i8; 256usize],
+    pub name: String,
     /// Uniform type.
     pub type_r: UniformType,
     /// Number of elements in array.
@@ -1823,7 +1922,7 @@ pub struct Attachment {
     /// Number of texture layer/slice(s) in array to use.
     pub num_layers: u16,
     /// Resolve flags. See: [ResolveFlags]
-    pub resolve: u8,
+    pub resolve: ResolveFlags,
 }
 /// Transform data.
 #[repr(C)]
@@ -1959,9 +2058,11 @@ pub struct VertexLayoutBuilder {
     pub attributes: [u16; 18usize],
 }
 /// Encoders are used for submitting draw calls from multiple threads. Only one encoder
-/// per thread should be used. Use `bgfx::begin()` to obtain an encoder for a thread.
+/// per thread should be used. Use [Frame::encoder] to obtain an encoder for a thread.
 #[repr(C)]
-pub struct Encoder {}
+pub struct Encoder {
+    _not_sync: std::marker::PhantomData<std::cell::Cell<()>>,
+}
 impl DynamicIndexBuffer {
     /// * `num`:
     /// Number of indices.
@@ -1978,10 +2079,14 @@ impl DynamicIndexBuffer {
     ///       buffers.
     ///   - [BufferFlags::INDEX32] - Buffer is using 32-bit indices. This flag has effect only on
     ///       index buffers.
-    pub fn create_dynamic_index_buffer(num: u32, flags: u16) -> DynamicIndexBuffer {
+    pub fn create_dynamic_index_buffer(num: u32, flags: BufferFlags) -> DynamicIndexBuffer {
+        let session = crate::context::creation_session();
         unsafe {
-            let _ret = (*g_vtbl.bgfx_create_dynamic_index_buffer).unwrap()(num, flags);
-            DynamicIndexBuffer { handle: _ret }
+            let _ret = vtbl().create_dynamic_index_buffer.unwrap()(num, flags.bits());
+            DynamicIndexBuffer {
+                handle: _ret,
+                session,
+            }
         }
     }
     /// * `mem`:
@@ -1999,10 +2104,14 @@ impl DynamicIndexBuffer {
     ///       buffers.
     ///   - [BufferFlags::INDEX32] - Buffer is using 32-bit indices. This flag has effect only on
     ///       index buffers.
-    pub fn create_dynamic_index_buffer_mem(mem: &Memory, flags: u16) -> DynamicIndexBuffer {
+    pub fn create_dynamic_index_buffer_mem(mem: &Memory, flags: BufferFlags) -> DynamicIndexBuffer {
+        let session = crate::context::creation_session();
         unsafe {
-            let _ret = (*g_vtbl.bgfx_create_dynamic_index_buffer_mem).unwrap()(mem.handle, flags);
-            DynamicIndexBuffer { handle: _ret }
+            let _ret = vtbl().create_dynamic_index_buffer_mem.unwrap()(mem.handle, flags.bits());
+            DynamicIndexBuffer {
+                handle: _ret,
+                session,
+            }
         }
     }
     /// * `handle`:
@@ -2013,7 +2122,7 @@ impl DynamicIndexBuffer {
     /// Index buffer data.
     pub fn update_dynamic_index_buffer(&self, start_index: u32, mem: &Memory) {
         unsafe {
-            (*g_vtbl.bgfx_update_dynamic_index_buffer).unwrap()(self.handle, start_index, mem.handle);
+            vtbl().update_dynamic_index_buffer.unwrap()(self.handle, start_index, mem.handle);
         }
     }
     /// * `handle`:
@@ -2024,15 +2133,17 @@ impl DynamicIndexBuffer {
     /// Number of indices to render.
     pub fn set_dynamic_index_buffer(&self, first_index: u32, num_indices: u32) {
         unsafe {
-            (*g_vtbl.bgfx_set_dynamic_index_buffer).unwrap()(self.handle, first_index, num_indices);
+            vtbl().set_dynamic_index_buffer.unwrap()(self.handle, first_index, num_indices);
         }
     }
 }
 
 impl Drop for DynamicIndexBuffer {
     fn drop(&mut self) {
-        unsafe {
-            (*g_vtbl.bgfx_destroy_dynamic_index_buffer).unwrap()(self.handle);
+        if self.is_valid() && crate::context::is_current(self.session) {
+            unsafe {
+                vtbl().destroy_dynamic_index_buffer.unwrap()(self.handle);
+            }
         }
     }
 }
@@ -2058,12 +2169,16 @@ impl DynamicVertexBuffer {
     pub fn create_dynamic_vertex_buffer(
         num: u32,
         layout: &BuiltVertexLayout,
-        flags: u16,
+        flags: BufferFlags,
     ) -> DynamicVertexBuffer {
+        let session = crate::context::creation_session();
         unsafe {
             let _layout = std::mem::transmute(layout);
-            let _ret = (*g_vtbl.bgfx_create_dynamic_vertex_buffer).unwrap()(num, _layout, flags);
-            DynamicVertexBuffer { handle: _ret }
+            let _ret = vtbl().create_dynamic_vertex_buffer.unwrap()(num, _layout, flags.bits());
+            DynamicVertexBuffer {
+                handle: _ret,
+                session,
+            }
         }
     }
     /// * `mem`:
@@ -2086,12 +2201,17 @@ impl DynamicVertexBuffer {
     pub fn create_dynamic_vertex_buffer_mem(
         mem: &Memory,
         layout: &BuiltVertexLayout,
-        flags: u16,
+        flags: BufferFlags,
     ) -> DynamicVertexBuffer {
+        let session = crate::context::creation_session();
         unsafe {
             let _layout = std::mem::transmute(layout);
-            let _ret = (*g_vtbl.bgfx_create_dynamic_vertex_buffer_mem).unwrap()(mem.handle, _layout, flags);
-            DynamicVertexBuffer { handle: _ret }
+            let _ret =
+                vtbl().create_dynamic_vertex_buffer_mem.unwrap()(mem.handle, _layout, flags.bits());
+            DynamicVertexBuffer {
+                handle: _ret,
+                session,
+            }
         }
     }
     /// * `handle`:
@@ -2102,7 +2222,7 @@ impl DynamicVertexBuffer {
     /// Vertex buffer data.
     pub fn update_dynamic_vertex_buffer(&self, start_vertex: u32, mem: &Memory) {
         unsafe {
-            (*g_vtbl.bgfx_update_dynamic_vertex_buffer).unwrap()(self.handle, start_vertex, mem.handle);
+            vtbl().update_dynamic_vertex_buffer.unwrap()(self.handle, start_vertex, mem.handle);
         }
     }
     /// * `handle`:
@@ -2113,7 +2233,7 @@ impl DynamicVertexBuffer {
     /// Number of data instances.
     pub fn set_instance_data_from_dynamic_vertex_buffer(&self, start_vertex: u32, num: u32) {
         unsafe {
-            bgfx_sys::bgfx_set_instance_data_from_dynamic_vertex_buffer(
+            vtbl().set_instance_data_from_dynamic_vertex_buffer.unwrap()(
                 self.handle,
                 start_vertex,
                 num,
@@ -2124,8 +2244,10 @@ impl DynamicVertexBuffer {
 
 impl Drop for DynamicVertexBuffer {
     fn drop(&mut self) {
-        unsafe {
-            (*g_vtbl.bgfx_destroy_dynamic_vertex_buffer).unwrap()(self.handle);
+        if self.is_valid() && crate::context::is_current(self.session) {
+            unsafe {
+                vtbl().destroy_dynamic_vertex_buffer.unwrap()(self.handle);
+            }
         }
     }
 }
@@ -2148,12 +2270,20 @@ impl FrameBuffer {
         width: u16,
         height: u16,
         format: TextureFormat,
-        texture_flags: u64,
+        texture_flags: TextureFlags,
     ) -> FrameBuffer {
+        let session = crate::context::creation_session();
         unsafe {
-            let _ret =
-                (*g_vtbl.bgfx_create_frame_buffer).unwrap()(width, height, format as _, texture_flags);
-            FrameBuffer { handle: _ret }
+            let _ret = vtbl().create_frame_buffer.unwrap()(
+                width,
+                height,
+                format as _,
+                texture_flags.bits(),
+            );
+            FrameBuffer {
+                handle: _ret,
+                session,
+            }
         }
     }
     /// * `ratio`:
@@ -2171,12 +2301,19 @@ impl FrameBuffer {
     pub fn create_frame_buffer_scaled(
         ratio: BackbufferRatio,
         format: TextureFormat,
-        texture_flags: u64,
+        texture_flags: TextureFlags,
     ) -> FrameBuffer {
+        let session = crate::context::creation_session();
         unsafe {
-            let _ret =
-                (*g_vtbl.bgfx_create_frame_buffer_scaled).unwrap()(ratio as _, format as _, texture_flags);
-            FrameBuffer { handle: _ret }
+            let _ret = vtbl().create_frame_buffer_scaled.unwrap()(
+                ratio as _,
+                format as _,
+                texture_flags.bits(),
+            );
+            FrameBuffer {
+                handle: _ret,
+                session,
+            }
         }
     }
     /// * `num`:
@@ -2191,13 +2328,17 @@ impl FrameBuffer {
         handles: &Texture,
         destroy_texture: bool,
     ) -> FrameBuffer {
+        let session = crate::context::creation_session();
         unsafe {
-            let _ret = bgfx_sys::bgfx_create_frame_buffer_from_handles(
+            let _ret = vtbl().create_frame_buffer_from_handles.unwrap()(
                 num,
                 &handles.handle,
                 destroy_texture,
             );
-            FrameBuffer { handle: _ret }
+            FrameBuffer {
+                handle: _ret,
+                session,
+            }
         }
     }
     /// * `num`:
@@ -2212,14 +2353,18 @@ impl FrameBuffer {
         attachment: &Attachment,
         destroy_texture: bool,
     ) -> FrameBuffer {
+        let session = crate::context::creation_session();
         unsafe {
             let _attachment = std::mem::transmute(attachment);
-            let _ret = bgfx_sys::bgfx_create_frame_buffer_from_attachment(
+            let _ret = vtbl().create_frame_buffer_from_attachment.unwrap()(
                 num,
                 _attachment,
                 destroy_texture,
             );
-            FrameBuffer { handle: _ret }
+            FrameBuffer {
+                handle: _ret,
+                session,
+            }
         }
     }
     /// * `nwh`:
@@ -2238,15 +2383,19 @@ impl FrameBuffer {
         height: u16,
         params: CreateFrameBufferFromNwhArgs,
     ) -> FrameBuffer {
+        let session = crate::context::creation_session();
         unsafe {
-            let _ret = bgfx_sys::bgfx_create_frame_buffer_from_nwh(
-                nwh,
+            let _ret = vtbl().create_frame_buffer_from_nwh.unwrap()(
+                nwh as _,
                 width,
                 height,
                 params.format as _,
                 params.depth_format as _,
             );
-            FrameBuffer { handle: _ret }
+            FrameBuffer {
+                handle: _ret,
+                session,
+            }
         }
     }
     /// * `handle`:
@@ -2258,34 +2407,46 @@ impl FrameBuffer {
     /// that _name is zero terminated string.
     pub fn set_name(&self, name: &str) {
         unsafe {
-            (*g_vtbl.bgfx_set_frame_buffer_name).unwrap()(self.handle, name.as_ptr() as _, name.len() as i32)
+            vtbl().set_frame_buffer_name.unwrap()(
+                self.handle,
+                name.as_ptr() as _,
+                name.len() as i32,
+            )
         }
     }
     /// * `handle`:
     /// Frame buffer handle.
     /// * `attachment`:
     pub fn get_texture(&self, attachment: u8) -> Texture {
+        let session = crate::context::creation_session();
         unsafe {
-            let _ret = (*g_vtbl.bgfx_get_texture).unwrap()(self.handle, attachment);
-            Texture { handle: _ret }
+            let _ret = vtbl().get_texture.unwrap()(self.handle, attachment);
+            Texture {
+                handle: _ret,
+                session,
+                size: None,
+            }
         }
     }
     /// * `handle`:
     /// Frame buffer handle. If handle is ]BGFX_INVALID_HANDLE] request will be
     /// made for main window back buffer.
     /// * `file_path`:
-    /// Will be passed to `bgfx::uint8_t::screenShot` callback.
-    pub fn request_screen_shot(&self, file_path: *const i8) {
+    /// Will be passed to [Callback::screen_shot].
+    pub fn request_screen_shot(&self, file_path: &str) {
         unsafe {
-            (*g_vtbl.bgfx_request_screen_shot).unwrap()(self.handle, file_path);
+            let file_path_ = CFixedString::from_str(file_path);
+            vtbl().request_screen_shot.unwrap()(self.handle, file_path_.as_ptr());
         }
     }
 }
 
 impl Drop for FrameBuffer {
     fn drop(&mut self) {
-        unsafe {
-            (*g_vtbl.bgfx_destroy_frame_buffer).unwrap()(self.handle);
+        if self.is_valid() && crate::context::is_current(self.session) {
+            unsafe {
+                vtbl().destroy_frame_buffer.unwrap()(self.handle);
+            }
         }
     }
 }
@@ -2306,10 +2467,14 @@ impl IndexBuffer {
     ///       buffers.
     ///   - [BufferFlags::INDEX32] - Buffer is using 32-bit indices. This flag has effect only on
     ///       index buffers.
-    pub fn create_index_buffer(mem: &Memory, flags: u16) -> IndexBuffer {
+    pub fn create_index_buffer(mem: &Memory, flags: BufferFlags) -> IndexBuffer {
+        let session = crate::context::creation_session();
         unsafe {
-            let _ret = (*g_vtbl.bgfx_create_index_buffer).unwrap()(mem.handle, flags);
-            IndexBuffer { handle: _ret }
+            let _ret = vtbl().create_index_buffer.unwrap()(mem.handle, flags.bits());
+            IndexBuffer {
+                handle: _ret,
+                session,
+            }
         }
     }
     /// * `handle`:
@@ -2321,7 +2486,11 @@ impl IndexBuffer {
     /// that _name is zero terminated string.
     pub fn set_name(&self, name: &str) {
         unsafe {
-            (*g_vtbl.bgfx_set_index_buffer_name).unwrap()(self.handle, name.as_ptr() as _, name.len() as i32)
+            vtbl().set_index_buffer_name.unwrap()(
+                self.handle,
+                name.as_ptr() as _,
+                name.len() as i32,
+            )
         }
     }
     /// * `handle`:
@@ -2332,15 +2501,17 @@ impl IndexBuffer {
     /// Number of indices to render.
     pub fn set_index_buffer(&self, first_index: u32, num_indices: u32) {
         unsafe {
-            (*g_vtbl.bgfx_set_index_buffer).unwrap()(self.handle, first_index, num_indices);
+            vtbl().set_index_buffer.unwrap()(self.handle, first_index, num_indices);
         }
     }
 }
 
 impl Drop for IndexBuffer {
     fn drop(&mut self) {
-        unsafe {
-            (*g_vtbl.bgfx_destroy_index_buffer).unwrap()(self.handle);
+        if self.is_valid() && crate::context::is_current(self.session) {
+            unsafe {
+                vtbl().destroy_index_buffer.unwrap()(self.handle);
+            }
         }
     }
 }
@@ -2349,26 +2520,36 @@ impl IndirectBuffer {
     /// * `num`:
     /// Number of indirect calls.
     pub fn create_indirect_buffer(num: u32) -> IndirectBuffer {
+        let session = crate::context::creation_session();
         unsafe {
-            let _ret = (*g_vtbl.bgfx_create_indirect_buffer).unwrap()(num);
-            IndirectBuffer { handle: _ret }
+            let _ret = vtbl().create_indirect_buffer.unwrap()(num);
+            IndirectBuffer {
+                handle: _ret,
+                session,
+            }
         }
     }
 }
 
 impl Drop for IndirectBuffer {
     fn drop(&mut self) {
-        unsafe {
-            (*g_vtbl.bgfx_destroy_indirect_buffer).unwrap()(self.handle);
+        if self.is_valid() && crate::context::is_current(self.session) {
+            unsafe {
+                vtbl().destroy_indirect_buffer.unwrap()(self.handle);
+            }
         }
     }
 }
 
 impl OcclusionQuery {
     pub fn create_occlusion_query() -> OcclusionQuery {
+        let session = crate::context::creation_session();
         unsafe {
-            let _ret = (*g_vtbl.bgfx_create_occlusion_query).unwrap()();
-            OcclusionQuery { handle: _ret }
+            let _ret = vtbl().create_occlusion_query.unwrap()();
+            OcclusionQuery {
+                handle: _ret,
+                session,
+            }
         }
     }
     /// * `handle`:
@@ -2378,7 +2559,7 @@ impl OcclusionQuery {
     /// can be `NULL` if result of occlusion query is not needed.
     pub fn get_result(&self, result: *mut i32) -> OcclusionQueryResult {
         unsafe {
-            let _ret = (*g_vtbl.bgfx_get_result).unwrap()(self.handle, result);
+            let _ret = vtbl().get_result.unwrap()(self.handle, result);
             std::mem::transmute(_ret)
         }
     }
@@ -2388,15 +2569,17 @@ impl OcclusionQuery {
     /// Render if occlusion query is visible.
     pub fn set_condition(&self, visible: bool) {
         unsafe {
-            (*g_vtbl.bgfx_set_condition).unwrap()(self.handle, visible);
+            vtbl().set_condition.unwrap()(self.handle, visible);
         }
     }
 }
 
 impl Drop for OcclusionQuery {
     fn drop(&mut self) {
-        unsafe {
-            (*g_vtbl.bgfx_destroy_occlusion_query).unwrap()(self.handle);
+        if self.is_valid() && crate::context::is_current(self.session) {
+            unsafe {
+                vtbl().destroy_occlusion_query.unwrap()(self.handle);
+            }
         }
     }
 }
@@ -2409,9 +2592,13 @@ impl Program {
     /// * `destroy_shaders`:
     /// If true, shaders will be destroyed when program is destroyed.
     pub fn create_program(vsh: &Shader, fsh: &Shader, destroy_shaders: bool) -> Program {
+        let session = crate::context::creation_session();
         unsafe {
-            let _ret = (*g_vtbl.bgfx_create_program).unwrap()(vsh.handle, fsh.handle, destroy_shaders);
-            Program { handle: _ret }
+            let _ret = vtbl().create_program.unwrap()(vsh.handle, fsh.handle, destroy_shaders);
+            Program {
+                handle: _ret,
+                session,
+            }
         }
     }
     /// * `csh`:
@@ -2419,17 +2606,23 @@ impl Program {
     /// * `destroy_shaders`:
     /// If true, shaders will be destroyed when program is destroyed.
     pub fn create_compute_program(csh: &Shader, destroy_shaders: bool) -> Program {
+        let session = crate::context::creation_session();
         unsafe {
-            let _ret = (*g_vtbl.bgfx_create_compute_program).unwrap()(csh.handle, destroy_shaders);
-            Program { handle: _ret }
+            let _ret = vtbl().create_compute_program.unwrap()(csh.handle, destroy_shaders);
+            Program {
+                handle: _ret,
+                session,
+            }
         }
     }
 }
 
 impl Drop for Program {
     fn drop(&mut self) {
-        unsafe {
-            (*g_vtbl.bgfx_destroy_program).unwrap()(self.handle);
+        if self.is_valid() && crate::context::is_current(self.session) {
+            unsafe {
+                vtbl().destroy_program.unwrap()(self.handle);
+            }
         }
     }
 }
@@ -2438,9 +2631,13 @@ impl Shader {
     /// * `mem`:
     /// Shader binary.
     pub fn create_shader(mem: &Memory) -> Shader {
+        let session = crate::context::creation_session();
         unsafe {
-            let _ret = (*g_vtbl.bgfx_create_shader).unwrap()(mem.handle);
-            Shader { handle: _ret }
+            let _ret = vtbl().create_shader.unwrap()(mem.handle);
+            Shader {
+                handle: _ret,
+                session,
+            }
         }
     }
     /// * `handle`:
@@ -2452,7 +2649,7 @@ impl Shader {
     /// that _name is zero terminated string).
     pub fn set_name(&self, name: &str) {
         unsafe {
-            (*g_vtbl.bgfx_set_shader_name).unwrap()(self.handle, name.as_ptr() as _, name.len() as i32)
+            vtbl().set_shader_name.unwrap()(self.handle, name.as_ptr() as _, name.len() as i32)
         }
     }
     /// * `vsh`:
@@ -2462,9 +2659,13 @@ impl Shader {
     /// * `destroy_shaders`:
     /// If true, shaders will be destroyed when program is destroyed.
     pub fn create_program(&self, fsh: &Shader, destroy_shaders: bool) -> Program {
+        let session = crate::context::creation_session();
         unsafe {
-            let _ret = (*g_vtbl.bgfx_create_program).unwrap()(self.handle, fsh.handle, destroy_shaders);
-            Program { handle: _ret }
+            let _ret = vtbl().create_program.unwrap()(self.handle, fsh.handle, destroy_shaders);
+            Program {
+                handle: _ret,
+                session,
+            }
         }
     }
     /// * `csh`:
@@ -2472,17 +2673,23 @@ impl Shader {
     /// * `destroy_shaders`:
     /// If true, shaders will be destroyed when program is destroyed.
     pub fn create_compute_program(&self, destroy_shaders: bool) -> Program {
+        let session = crate::context::creation_session();
         unsafe {
-            let _ret = (*g_vtbl.bgfx_create_compute_program).unwrap()(self.handle, destroy_shaders);
-            Program { handle: _ret }
+            let _ret = vtbl().create_compute_program.unwrap()(self.handle, destroy_shaders);
+            Program {
+                handle: _ret,
+                session,
+            }
         }
     }
 }
 
 impl Drop for Shader {
     fn drop(&mut self) {
-        unsafe {
-            (*g_vtbl.bgfx_destroy_shader).unwrap()(self.handle);
+        if self.is_valid() && crate::context::is_current(self.session) {
+            unsafe {
+                vtbl().destroy_shader.unwrap()(self.handle);
+            }
         }
     }
 }
@@ -2497,15 +2704,26 @@ impl Texture {
     ///   mode.
     /// - [SamplerFlags::[MIN/MAG/MIP]_[POINT/ANISOTROPIC]] - Point or anisotropic
     ///   sampling.
+    ///   Takes a [SamplerDesc] for the sampler flags alone, or [TextureFlags].
     /// * `skip`:
     /// Skip top level mips when parsing texture.
     /// * `info`:
     /// When non-`NULL` is specified it returns parsed texture information.
-    pub fn create_texture(mem: &Memory, flags: u64, skip: u8, info: &mut TextureInfo) -> Texture {
+    pub fn create_texture(
+        mem: &Memory,
+        flags: impl Into<TextureFlags>,
+        skip: u8,
+        info: &mut TextureInfo,
+    ) -> Texture {
+        let session = crate::context::creation_session();
         unsafe {
-            let _info = std::mem::transmute(info);
-            let _ret = (*g_vtbl.bgfx_create_texture).unwrap()(mem.handle, flags, skip, _info);
-            Texture { handle: _ret }
+            let _info = &mut *info as *mut TextureInfo as *mut bgfx_sys::bgfx_texture_info_s;
+            let _ret = vtbl().create_texture.unwrap()(mem.handle, flags.into().bits(), skip, _info);
+            Texture {
+                handle: _ret,
+                session,
+                size: TextureSize::from_info(info),
+            }
         }
     }
     /// * `width`:
@@ -2526,6 +2744,7 @@ impl Texture {
     ///   mode.
     /// - [SamplerFlags::[MIN/MAG/MIP]_[POINT/ANISOTROPIC]] - Point or anisotropic
     ///   sampling.
+    ///   Takes a [SamplerDesc] for the sampler flags alone, or [TextureFlags].
     /// * `mem`:
     /// Texture data. If `_mem` is non-NULL, created texture will be immutable. If
     /// `_mem` is NULL content of the texture is uninitialized. When `_numLayers` is more than
@@ -2536,20 +2755,27 @@ impl Texture {
         has_mips: bool,
         num_layers: u16,
         format: TextureFormat,
-        flags: u64,
+        flags: impl Into<TextureFlags>,
         mem: &Memory,
     ) -> Texture {
+        let session = crate::context::creation_session();
         unsafe {
-            let _ret = bgfx_sys::bgfx_create_texture_2d(
+            let _ret = vtbl().create_texture_2d.unwrap()(
                 width,
                 height,
                 has_mips,
                 num_layers,
                 format as _,
-                flags,
+                flags.into().bits(),
                 mem.handle,
             );
-            Texture { handle: _ret }
+            Texture {
+                handle: _ret,
+                session,
+                size: Some(TextureSize::new(
+                    width, height, 1, num_layers, has_mips, false, format,
+                )),
+            }
         }
     }
     /// * `ratio`:
@@ -2568,22 +2794,28 @@ impl Texture {
     ///   mode.
     /// - [SamplerFlags::[MIN/MAG/MIP]_[POINT/ANISOTROPIC]] - Point or anisotropic
     ///   sampling.
+    ///   Takes a [SamplerDesc] for the sampler flags alone, or [TextureFlags].
     pub fn create_texture_2d_scaled(
         ratio: BackbufferRatio,
         has_mips: bool,
         num_layers: u16,
         format: TextureFormat,
-        flags: u64,
+        flags: impl Into<TextureFlags>,
     ) -> Texture {
+        let session = crate::context::creation_session();
         unsafe {
-            let _ret = bgfx_sys::bgfx_create_texture_2d_scaled(
+            let _ret = vtbl().create_texture_2d_scaled.unwrap()(
                 ratio as _,
                 has_mips,
                 num_layers,
                 format as _,
-                flags,
+                flags.into().bits(),
             );
-            Texture { handle: _ret }
+            Texture {
+                handle: _ret,
+                session,
+                size: None,
+            }
         }
     }
     /// * `width`:
@@ -2603,6 +2835,7 @@ impl Texture {
     ///   mode.
     /// - [SamplerFlags::[MIN/MAG/MIP]_[POINT/ANISOTROPIC]] - Point or anisotropic
     ///   sampling.
+    ///   A [SamplerDesc] converts to the sampler flags alone with `into()`.
     /// * `mem`:
     /// Texture data. If `_mem` is non-NULL, created texture will be immutable. If
     /// `_mem` is NULL content of the texture is uninitialized. When `_numLayers` is more than
@@ -2615,22 +2848,29 @@ impl Texture {
         format: TextureFormat,
         params: CreateTexture3DArgs,
     ) -> Texture {
+        let session = crate::context::creation_session();
         unsafe {
             let _mem = if let Some(h) = params.mem {
                 h.handle
             } else {
                 std::ptr::null()
             };
-            let _ret = bgfx_sys::bgfx_create_texture_3d(
+            let _ret = vtbl().create_texture_3d.unwrap()(
                 width,
                 height,
                 depth,
                 has_mips,
                 format as _,
-                params.flags,
+                params.flags.bits(),
                 _mem,
             );
-            Texture { handle: _ret }
+            Texture {
+                handle: _ret,
+                session,
+                size: Some(TextureSize::new(
+                    width, height, depth, 1, has_mips, false, format,
+                )),
+            }
         }
     }
     /// * `size`:
@@ -2649,6 +2889,7 @@ impl Texture {
     ///   mode.
     /// - [SamplerFlags::[MIN/MAG/MIP]_[POINT/ANISOTROPIC]] - Point or anisotropic
     ///   sampling.
+    ///   A [SamplerDesc] converts to the sampler flags alone with `into()`.
     /// * `mem`:
     /// Texture data. If `_mem` is non-NULL, created texture will be immutable. If
     /// `_mem` is NULL content of the texture is uninitialized. When `_numLayers` is more than
@@ -2660,21 +2901,28 @@ impl Texture {
         format: TextureFormat,
         params: CreateTextureCubeArgs,
     ) -> Texture {
+        let session = crate::context::creation_session();
         unsafe {
             let _mem = if let Some(h) = params.mem {
                 h.handle
             } else {
                 std::ptr::null()
             };
-            let _ret = bgfx_sys::bgfx_create_texture_cube(
+            let _ret = vtbl().create_texture_cube.unwrap()(
                 size,
                 has_mips,
                 num_layers,
                 format as _,
-                params.flags,
+                params.flags.bits(),
                 _mem,
             );
-            Texture { handle: _ret }
+            Texture {
+                handle: _ret,
+                session,
+                size: Some(TextureSize::new(
+                    size, size, 1, num_layers, has_mips, true, format,
+                )),
+            }
         }
     }
     /// * `handle`:
@@ -2708,7 +2956,7 @@ impl Texture {
         pitch: u16,
     ) {
         unsafe {
-            bgfx_sys::bgfx_update_texture_2d(
+            vtbl().update_texture_2d.unwrap()(
                 self.handle,
                 layer,
                 mip,
@@ -2751,7 +2999,7 @@ impl Texture {
         mem: &Memory,
     ) {
         unsafe {
-            bgfx_sys::bgfx_update_texture_3d(
+            vtbl().update_texture_3d.unwrap()(
                 self.handle,
                 mip,
                 x,
@@ -2816,7 +3064,7 @@ impl Texture {
         pitch: u16,
     ) {
         unsafe {
-            bgfx_sys::bgfx_update_texture_cube(
+            vtbl().update_texture_cube.unwrap()(
                 self.handle,
                 layer,
                 side,
@@ -2839,24 +3087,31 @@ impl Texture {
     /// that _name is zero terminated string.
     pub fn set_name(&self, name: &str) {
         unsafe {
-            (*g_vtbl.bgfx_set_texture_name).unwrap()(self.handle, name.as_ptr() as _, name.len() as i32)
+            vtbl().set_texture_name.unwrap()(self.handle, name.as_ptr() as _, name.len() as i32)
         }
     }
     /// * `handle`:
     /// Frame buffer handle.
     /// * `attachment`:
     pub fn get_texture(handle: &FrameBuffer, attachment: u8) -> Texture {
+        let session = crate::context::creation_session();
         unsafe {
-            let _ret = (*g_vtbl.bgfx_get_texture).unwrap()(handle.handle, attachment);
-            Texture { handle: _ret }
+            let _ret = vtbl().get_texture.unwrap()(handle.handle, attachment);
+            Texture {
+                handle: _ret,
+                session,
+                size: None,
+            }
         }
     }
 }
 
 impl Drop for Texture {
     fn drop(&mut self) {
-        unsafe {
-            (*g_vtbl.bgfx_destroy_texture).unwrap()(self.handle);
+        if self.is_valid() && crate::context::is_current(self.session) {
+            unsafe {
+                vtbl().destroy_texture.unwrap()(self.handle);
+            }
         }
     }
 }
@@ -2867,17 +3122,16 @@ impl Uniform {
     /// * `info`:
     /// Uniform info.
     pub fn get_uniform_info(&self, info: &mut UniformInfo) {
-        unsafe {
-            let _info = std::mem::transmute(info);
-            (*g_vtbl.bgfx_get_uniform_info).unwrap()(self.handle, _info);
-        }
+        *info = uniform_info(self.handle);
     }
 }
 
 impl Drop for Uniform {
     fn drop(&mut self) {
-        unsafe {
-            (*g_vtbl.bgfx_destroy_uniform).unwrap()(self.handle);
+        if self.is_valid() && crate::context::is_current(self.session) {
+            unsafe {
+                vtbl().destroy_uniform.unwrap()(self.handle);
+            }
         }
     }
 }
@@ -2901,12 +3155,16 @@ impl VertexBuffer {
     pub fn create_vertex_buffer(
         mem: &Memory,
         layout: &BuiltVertexLayout,
-        flags: u16,
+        flags: BufferFlags,
     ) -> VertexBuffer {
+        let session = crate::context::creation_session();
         unsafe {
             let _layout = std::mem::transmute(layout);
-            let _ret = (*g_vtbl.bgfx_create_vertex_buffer).unwrap()(mem.handle, _layout, flags);
-            VertexBuffer { handle: _ret }
+            let _ret = vtbl().create_vertex_buffer.unwrap()(mem.handle, _layout, flags.bits());
+            VertexBuffer {
+                handle: _ret,
+                session,
+            }
         }
     }
     /// * `handle`:
@@ -2918,7 +3176,7 @@ impl VertexBuffer {
     /// that _name is zero terminated string.
     pub fn set_name(&self, name: &str) {
         unsafe {
-            bgfx_sys::bgfx_set_vertex_buffer_name(
+            vtbl().set_vertex_buffer_name.unwrap()(
                 self.handle,
                 name.as_ptr() as _,
                 name.len() as i32,
@@ -2935,15 +3193,17 @@ impl VertexBuffer {
     /// Dynamic vertex buffer.
     pub fn set_instance_data_from_vertex_buffer(&self, start_vertex: u32, num: u32) {
         unsafe {
-            (*g_vtbl.bgfx_set_instance_data_from_vertex_buffer).unwrap()(self.handle, start_vertex, num);
+            vtbl().set_instance_data_from_vertex_buffer.unwrap()(self.handle, start_vertex, num);
         }
     }
 }
 
 impl Drop for VertexBuffer {
     fn drop(&mut self) {
-        unsafe {
-            (*g_vtbl.bgfx_destroy_vertex_buffer).unwrap()(self.handle);
+        if self.is_valid() && crate::context::is_current(self.session) {
+            unsafe {
+                vtbl().destroy_vertex_buffer.unwrap()(self.handle);
+            }
         }
     }
 }
@@ -2952,18 +3212,24 @@ impl VertexLayout {
     /// * `layout`:
     /// Vertex layout.
     pub fn create_vertex_layout(layout: &BuiltVertexLayout) -> VertexLayout {
+        let session = crate::context::creation_session();
         unsafe {
             let _layout = std::mem::transmute(layout);
-            let _ret = (*g_vtbl.bgfx_create_vertex_layout).unwrap()(_layout);
-            VertexLayout { handle: _ret }
+            let _ret = vtbl().create_vertex_layout.unwrap()(_layout);
+            VertexLayout {
+                handle: _ret,
+                session,
+            }
         }
     }
 }
 
 impl Drop for VertexLayout {
     fn drop(&mut self) {
-        unsafe {
-            (*g_vtbl.bgfx_destroy_vertex_layout).unwrap()(self.handle);
+        if self.is_valid() && crate::context::is_current(self.session) {
+            unsafe {
+                vtbl().destroy_vertex_layout.unwrap()(self.handle);
+            }
         }
     }
 }
@@ -3030,7 +3296,7 @@ impl Init {
         let t = unsafe { t.assume_init() };
         unsafe {
             let _init = std::mem::transmute(&t);
-            (*g_vtbl.bgfx_init_ctor).unwrap()(_init);
+            vtbl().init_ctor.unwrap()(_init);
         }
         t
     }
@@ -3078,9 +3344,11 @@ impl TextureInfo {
 
 impl UniformInfo {
     pub fn new() -> UniformInfo {
-        let t = MaybeUninit::<UniformInfo>::zeroed();
-        let t = unsafe { t.assume_init() };
-        t
+        UniformInfo {
+            name: String::new(),
+            type_r: UniformType::Sampler,
+            num: 0,
+        }
     }
 }
 
@@ -3106,14 +3374,14 @@ impl Attachment {
     pub fn init(&self, handle: &Texture, params: InitArgs) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_attachment_init(
+            vtbl().attachment_init.unwrap()(
                 _self,
                 handle.handle,
                 params.access as _,
                 params.layer,
                 params.num_layers,
                 params.mip,
-                params.resolve,
+                params.resolve.bits(),
             );
         }
     }
@@ -3163,7 +3431,7 @@ impl Encoder {
     pub fn set_marker(&self, marker: *const i8) {
         unsafe {
             let _self = std::mem::transmute(self);
-            (*g_vtbl.bgfx_encoder_set_marker).unwrap()(_self, marker);
+            vtbl().encoder_set_marker.unwrap()(_self, marker as _);
         }
     }
     /// * `state`:
@@ -3182,7 +3450,7 @@ impl Encoder {
     pub fn set_state(&self, state: u64, rgba: u32) {
         unsafe {
             let _self = std::mem::transmute(self);
-            (*g_vtbl.bgfx_encoder_set_state).unwrap()(_self, state, rgba);
+            vtbl().encoder_set_state.unwrap()(_self, state, rgba);
         }
     }
     /// * `handle`:
@@ -3192,7 +3460,7 @@ impl Encoder {
     pub fn set_condition(&self, handle: &OcclusionQuery, visible: bool) {
         unsafe {
             let _self = std::mem::transmute(self);
-            (*g_vtbl.bgfx_encoder_set_condition).unwrap()(_self, handle.handle, visible);
+            vtbl().encoder_set_condition.unwrap()(_self, handle.handle, visible);
         }
     }
     /// * `fstencil`:
@@ -3203,7 +3471,7 @@ impl Encoder {
     pub fn set_stencil(&self, fstencil: u32, bstencil: u32) {
         unsafe {
             let _self = std::mem::transmute(self);
-            (*g_vtbl.bgfx_encoder_set_stencil).unwrap()(_self, fstencil, bstencil);
+            vtbl().encoder_set_stencil.unwrap()(_self, fstencil, bstencil);
         }
     }
     /// * `x`:
@@ -3217,7 +3485,7 @@ impl Encoder {
     pub fn set_scissor(&self, x: u16, y: u16, width: u16, height: u16) -> u16 {
         unsafe {
             let _self = std::mem::transmute(self);
-            let _ret = (*g_vtbl.bgfx_encoder_set_scissor).unwrap()(_self, x, y, width, height);
+            let _ret = vtbl().encoder_set_scissor.unwrap()(_self, x, y, width, height);
             _ret
         }
     }
@@ -3226,7 +3494,7 @@ impl Encoder {
     pub fn set_scissor_cached(&self, cache: u16) {
         unsafe {
             let _self = std::mem::transmute(self);
-            (*g_vtbl.bgfx_encoder_set_scissor_cached).unwrap()(_self, cache);
+            vtbl().encoder_set_scissor_cached.unwrap()(_self, cache);
         }
     }
     /// * `transform`:
@@ -3237,7 +3505,7 @@ impl Encoder {
         unsafe {
             let _self = std::mem::transmute(self);
             let _transform = std::mem::transmute(transform);
-            let _ret = (*g_vtbl.bgfx_encoder_alloc_transform).unwrap()(_self, _transform, num);
+            let _ret = vtbl().encoder_alloc_transform.unwrap()(_self, _transform, num);
             _ret
         }
     }
@@ -3247,10 +3515,20 @@ impl Encoder {
     /// First index to render.
     /// * `num_indices`:
     /// Number of indices to render.
-    pub fn set_index_buffer(&self, handle: &IndexBuffer, first_index: u32, num_indices: u32) {
+    pub fn set_index_buffer(
+        &self,
+        handle: &impl AsRef<IndexBuffer>,
+        first_index: u32,
+        num_indices: u32,
+    ) {
         unsafe {
             let _self = std::mem::transmute(self);
-            (*g_vtbl.bgfx_encoder_set_index_buffer).unwrap()(_self, handle.handle, first_index, num_indices);
+            vtbl().encoder_set_index_buffer.unwrap()(
+                _self,
+                handle.as_ref().handle,
+                first_index,
+                num_indices,
+            );
         }
     }
     /// * `handle`:
@@ -3267,7 +3545,7 @@ impl Encoder {
     ) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_set_dynamic_index_buffer(
+            vtbl().encoder_set_dynamic_index_buffer.unwrap()(
                 _self,
                 handle.handle,
                 first_index,
@@ -3290,7 +3568,7 @@ impl Encoder {
         unsafe {
             let _self = std::mem::transmute(self);
             let _tib = std::mem::transmute(tib);
-            bgfx_sys::bgfx_encoder_set_transient_index_buffer(
+            vtbl().encoder_set_transient_index_buffer.unwrap()(
                 _self,
                 _tib,
                 first_index,
@@ -3309,16 +3587,16 @@ impl Encoder {
     pub fn set_vertex_buffer(
         &self,
         stream: u8,
-        handle: &VertexBuffer,
+        handle: &impl AsRef<VertexBuffer>,
         start_vertex: u32,
         num_vertices: u32,
     ) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_set_vertex_buffer(
+            vtbl().encoder_set_vertex_buffer.unwrap()(
                 _self,
                 stream,
-                handle.handle,
+                handle.as_ref().handle,
                 start_vertex,
                 num_vertices,
             );
@@ -3346,7 +3624,7 @@ impl Encoder {
     ) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_set_vertex_buffer_with_layout(
+            vtbl().encoder_set_vertex_buffer_with_layout.unwrap()(
                 _self,
                 stream,
                 handle.handle,
@@ -3380,7 +3658,7 @@ impl Encoder {
     ) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_set_dynamic_vertex_buffer(
+            vtbl().encoder_set_dynamic_vertex_buffer.unwrap()(
                 _self,
                 stream,
                 handle.handle,
@@ -3411,7 +3689,9 @@ impl Encoder {
     ) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_set_dynamic_vertex_buffer_with_layout(
+            vtbl()
+                .encoder_set_dynamic_vertex_buffer_with_layout
+                .unwrap()(
                 _self,
                 stream,
                 handle.handle,
@@ -3439,7 +3719,7 @@ impl Encoder {
         unsafe {
             let _self = std::mem::transmute(self);
             let _tvb = std::mem::transmute(tvb);
-            bgfx_sys::bgfx_encoder_set_transient_vertex_buffer(
+            vtbl().encoder_set_transient_vertex_buffer.unwrap()(
                 _self,
                 stream,
                 _tvb,
@@ -3471,7 +3751,9 @@ impl Encoder {
         unsafe {
             let _self = std::mem::transmute(self);
             let _tvb = std::mem::transmute(tvb);
-            bgfx_sys::bgfx_encoder_set_transient_vertex_buffer_with_layout(
+            vtbl()
+                .encoder_set_transient_vertex_buffer_with_layout
+                .unwrap()(
                 _self,
                 stream,
                 _tvb,
@@ -3486,7 +3768,7 @@ impl Encoder {
     pub fn set_vertex_count(&self, num_vertices: u32) {
         unsafe {
             let _self = std::mem::transmute(self);
-            (*g_vtbl.bgfx_encoder_set_vertex_count).unwrap()(_self, num_vertices);
+            vtbl().encoder_set_vertex_count.unwrap()(_self, num_vertices);
         }
     }
     /// * `idb`:
@@ -3499,7 +3781,7 @@ impl Encoder {
         unsafe {
             let _self = std::mem::transmute(self);
             let _idb = std::mem::transmute(idb);
-            (*g_vtbl.bgfx_encoder_set_instance_data_buffer).unwrap()(_self, _idb, start, num);
+            vtbl().encoder_set_instance_data_buffer.unwrap()(_self, _idb, start, num);
         }
     }
     /// * `handle`:
@@ -3518,7 +3800,7 @@ impl Encoder {
     ) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_set_instance_data_from_vertex_buffer(
+            vtbl().encoder_set_instance_data_from_vertex_buffer.unwrap()(
                 _self,
                 handle.handle,
                 start_vertex,
@@ -3540,19 +3822,16 @@ impl Encoder {
     ) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_set_instance_data_from_dynamic_vertex_buffer(
-                _self,
-                handle.handle,
-                start_vertex,
-                num,
-            );
+            vtbl()
+                .encoder_set_instance_data_from_dynamic_vertex_buffer
+                .unwrap()(_self, handle.handle, start_vertex, num);
         }
     }
     /// * `num_instances`:
     pub fn set_instance_count(&self, num_instances: u32) {
         unsafe {
             let _self = std::mem::transmute(self);
-            (*g_vtbl.bgfx_encoder_set_instance_count).unwrap()(_self, num_instances);
+            vtbl().encoder_set_instance_count.unwrap()(_self, num_instances);
         }
     }
     /// * `stage`:
@@ -3562,16 +3841,28 @@ impl Encoder {
     /// * `handle`:
     /// Texture handle.
     /// * `flags`:
-    /// Texture sampling mode. Default value UINT32_MAX uses
+    /// Texture sampling mode, see [Sampler]. [Sampler::Inherit] uses the
     ///   texture sampling settings from the texture.
     ///   - [SamplerFlags::[U/V/W]_[MIRROR/CLAMP]] - Mirror or clamp to edge wrap
     ///     mode.
     ///   - [SamplerFlags::[MIN/MAG/MIP]_[POINT/ANISOTROPIC]] - Point or anisotropic
     ///     sampling.
-    pub fn set_texture(&self, stage: u8, sampler: &Uniform, handle: &Texture, flags: u32) {
+    pub fn set_texture(
+        &self,
+        stage: u8,
+        sampler: &Uniform,
+        handle: &Texture,
+        flags: impl Into<Sampler>,
+    ) {
         unsafe {
             let _self = std::mem::transmute(self);
-            (*g_vtbl.bgfx_encoder_set_texture).unwrap()(_self, stage, sampler.handle, handle.handle, flags);
+            vtbl().encoder_set_texture.unwrap()(
+                _self,
+                stage,
+                sampler.handle,
+                handle.handle,
+                flags.into().to_bits(),
+            );
         }
     }
     /// * `id`:
@@ -3579,7 +3870,7 @@ impl Encoder {
     pub fn touch(&self, id: ViewId) {
         unsafe {
             let _self = std::mem::transmute(self);
-            (*g_vtbl.bgfx_encoder_touch).unwrap()(_self, id);
+            vtbl().encoder_touch.unwrap()(_self, id);
         }
     }
     /// * `id`:
@@ -3593,7 +3884,13 @@ impl Encoder {
     pub fn submit(&self, id: ViewId, program: &Program, params: SubmitArgs) {
         unsafe {
             let _self = std::mem::transmute(self);
-            (*g_vtbl.bgfx_encoder_submit).unwrap()(_self, id, program.handle, params.depth, params.flags);
+            vtbl().encoder_submit.unwrap()(
+                _self,
+                id,
+                program.handle,
+                params.depth,
+                params.flags.bits(),
+            );
         }
     }
     /// * `id`:
@@ -3615,15 +3912,16 @@ impl Encoder {
     ) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_submit_occlusion_query(
+            vtbl().encoder_submit_occlusion_query.unwrap()(
                 _self,
                 id,
                 program.handle,
                 occlusion_query.handle,
                 params.depth,
-                params.flags,
+                params.flags.bits(),
             );
         }
+        crate::pending::query_submitted(occlusion_query.handle.idx);
     }
     /// * `id`:
     /// View id.
@@ -3648,7 +3946,7 @@ impl Encoder {
     ) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_submit_indirect(
+            vtbl().encoder_submit_indirect.unwrap()(
                 _self,
                 id,
                 program.handle,
@@ -3656,7 +3954,7 @@ impl Encoder {
                 params.start,
                 params.num,
                 params.depth,
-                params.flags,
+                params.flags.bits(),
             );
         }
     }
@@ -3690,7 +3988,7 @@ impl Encoder {
     ) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_submit_indirect_count(
+            vtbl().encoder_submit_indirect_count.unwrap()(
                 _self,
                 id,
                 program.handle,
@@ -3700,7 +3998,7 @@ impl Encoder {
                 params.num_index,
                 params.num_max,
                 params.depth,
-                params.flags,
+                params.flags.bits(),
             );
         }
     }
@@ -3713,7 +4011,7 @@ impl Encoder {
     pub fn set_compute_index_buffer(&self, stage: u8, handle: &IndexBuffer, access: Access) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_set_compute_index_buffer(
+            vtbl().encoder_set_compute_index_buffer.unwrap()(
                 _self,
                 stage,
                 handle.handle,
@@ -3730,7 +4028,7 @@ impl Encoder {
     pub fn set_compute_vertex_buffer(&self, stage: u8, handle: &VertexBuffer, access: Access) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_set_compute_vertex_buffer(
+            vtbl().encoder_set_compute_vertex_buffer.unwrap()(
                 _self,
                 stage,
                 handle.handle,
@@ -3752,7 +4050,7 @@ impl Encoder {
     ) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_set_compute_dynamic_index_buffer(
+            vtbl().encoder_set_compute_dynamic_index_buffer.unwrap()(
                 _self,
                 stage,
                 handle.handle,
@@ -3774,7 +4072,7 @@ impl Encoder {
     ) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_set_compute_dynamic_vertex_buffer(
+            vtbl().encoder_set_compute_dynamic_vertex_buffer.unwrap()(
                 _self,
                 stage,
                 handle.handle,
@@ -3791,7 +4089,7 @@ impl Encoder {
     pub fn set_compute_indirect_buffer(&self, stage: u8, handle: &IndirectBuffer, access: Access) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_set_compute_indirect_buffer(
+            vtbl().encoder_set_compute_indirect_buffer.unwrap()(
                 _self,
                 stage,
                 handle.handle,
@@ -3819,7 +4117,7 @@ impl Encoder {
     ) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_set_image(
+            vtbl().encoder_set_image.unwrap()(
                 _self,
                 stage,
                 handle.handle,
@@ -3844,14 +4142,14 @@ impl Encoder {
     pub fn dispatch(&self, id: ViewId, program: &Program, params: DispatchArgs) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_dispatch(
+            vtbl().encoder_dispatch.unwrap()(
                 _self,
                 id,
                 program.handle,
                 params.num_x,
                 params.num_y,
                 params.num_z,
-                params.flags,
+                params.flags.bits(),
             );
         }
     }
@@ -3876,23 +4174,23 @@ impl Encoder {
     ) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_dispatch_indirect(
+            vtbl().encoder_dispatch_indirect.unwrap()(
                 _self,
                 id,
                 program.handle,
                 indirect_handle.handle,
                 params.start,
                 params.num,
-                params.flags,
+                params.flags.bits(),
             );
         }
     }
     /// * `flags`:
     /// Discard or preserve states. See [DiscardFlags].
-    pub fn discard(&self, flags: u8) {
+    pub fn discard(&self, flags: DiscardFlags) {
         unsafe {
             let _self = std::mem::transmute(self);
-            (*g_vtbl.bgfx_encoder_discard).unwrap()(_self, flags);
+            vtbl().encoder_discard.unwrap()(_self, flags.bits());
         }
     }
     /// * `id`:
@@ -3941,7 +4239,7 @@ impl Encoder {
     ) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_blit(
+            vtbl().encoder_blit.unwrap()(
                 _self,
                 id,
                 dst.handle,
@@ -3964,17 +4262,40 @@ impl Encoder {
 
 /// * `init`:
 /// Initialization parameters. See: `bgfx::Init` for more info.
+///
+/// Returns `false` if bgfx is already initialized, such as by a [Bgfx] context.
+#[deprecated(note = "use `Bgfx::init`, which shuts bgfx down when dropped")]
 pub fn init(init: &Init) -> bool {
+    crate::context::begin_session(|| init_bgfx(init)).is_ok()
+}
+/// Initializes bgfx for [crate::context::begin_session].
+pub(crate) fn init_bgfx(init: &Init) -> bool {
     unsafe {
         let _init = std::mem::transmute(init);
-        let _ret = (*g_vtbl.bgfx_init).unwrap()(_init);
+        let _ret = vtbl().init.unwrap()(_init);
+        if _ret {
+            crate::callback::INTERFACES.set_active(init.callback);
+            crate::allocator::INTERFACES.set_active(init.allocator);
+        }
         _ret
     }
 }
-pub fn shutdown() {
-    unsafe {
-        (*g_vtbl.bgfx_shutdown).unwrap()();
-    }
+/// Does nothing if bgfx isn't initialized.
+///
+/// # Safety
+///
+/// No [Bgfx] context or [Frame] may be alive, as encoders and [Transient] buffers borrowing
+/// them would be used after bgfx shut down.
+#[deprecated(note = "drop the `Bgfx` context instead")]
+pub unsafe fn shutdown() {
+    crate::context::end_session(|| {
+        unsafe {
+            vtbl().shutdown.unwrap()();
+        }
+        crate::callback::INTERFACES.release_active();
+        crate::allocator::INTERFACES.release_active();
+        crate::pending::shutdown();
+    });
 }
 /// * `width`:
 /// Back-buffer width.
@@ -3997,32 +4318,37 @@ pub fn shutdown() {
 /// Texture format. See: [TextureFormat].
 pub fn reset(width: u32, height: u32, params: ResetArgs) {
     unsafe {
-        (*g_vtbl.bgfx_reset).unwrap()(width, height, params.flags, params.format as _);
+        vtbl().reset.unwrap()(width, height, params.flags.bits(), params.format as _);
     }
 }
 /// * `capture`:
 /// Capture frame with graphics debugger.
-pub fn frame(capture: bool) -> u32 {
-    unsafe {
-        let _ret = (*g_vtbl.bgfx_frame).unwrap()(capture);
-        _ret
-    }
+///
+/// # Safety
+///
+/// No [Frame] may be alive, as encoders and [Transient] buffers borrowing it would be used
+/// after their frame ended.
+#[deprecated(note = "use `Bgfx::frame` or `Frame::frame`")]
+pub unsafe fn frame(capture: bool) -> u32 {
+    let _ret = vtbl().frame.unwrap()(capture);
+    crate::pending::frame_done(_ret);
+    _ret
 }
 pub fn get_renderer_type() -> RendererType {
     unsafe {
-        let _ret = (*g_vtbl.bgfx_get_renderer_type).unwrap()();
+        let _ret = vtbl().get_renderer_type.unwrap()();
         std::mem::transmute(_ret)
     }
 }
 pub fn get_caps() -> &'static Caps {
     unsafe {
-        let _ret = (*g_vtbl.bgfx_get_caps).unwrap()();
+        let _ret = vtbl().get_caps.unwrap()();
         std::mem::transmute(_ret)
     }
 }
 pub fn get_stats() -> &'static Stats {
     unsafe {
-        let _ret = (*g_vtbl.bgfx_get_stats).unwrap()();
+        let _ret = vtbl().get_stats.unwrap()();
         std::mem::transmute(_ret)
     }
 }
@@ -4036,9 +4362,9 @@ pub fn get_stats() -> &'static Stats {
 ///   - [DebugFlags::TEXT] - Display debug text.
 ///   - [DebugFlags::WIREFRAME] - Wireframe rendering. All rendering
 ///     primitives will be rendered as lines.
-pub fn set_debug(debug: u32) {
+pub fn set_debug(debug: DebugFlags) {
     unsafe {
-        (*g_vtbl.bgfx_set_debug).unwrap()(debug);
+        vtbl().set_debug.unwrap()(debug.bits());
     }
 }
 /// * `attr`:
@@ -4047,7 +4373,7 @@ pub fn set_debug(debug: u32) {
 /// Default 8x16 or 8x8 font.
 pub fn dbg_text_clear(params: DbgTextClearArgs) {
     unsafe {
-        (*g_vtbl.bgfx_dbg_text_clear).unwrap()(params.attr, params.small);
+        vtbl().dbg_text_clear.unwrap()(params.attr, params.small);
     }
 }
 /// * `x`:
@@ -4064,7 +4390,7 @@ pub fn dbg_text_clear(params: DbgTextClearArgs) {
 /// Image pitch in bytes.
 pub fn dbg_text_image(x: u16, y: u16, width: u16, height: u16, data: *const c_void, pitch: u16) {
     unsafe {
-        (*g_vtbl.bgfx_dbg_text_image).unwrap()(x, y, width, height, data, pitch);
+        vtbl().dbg_text_image.unwrap()(x, y, width, height, data, pitch);
     }
 }
 /// * `mem`:
@@ -4082,19 +4408,27 @@ pub fn dbg_text_image(x: u16, y: u16, width: u16, height: u16, data: *const c_vo
 ///       buffers.
 ///   - [BufferFlags::INDEX32] - Buffer is using 32-bit indices. This flag has effect only on
 ///       index buffers.
-pub fn create_index_buffer(mem: &Memory, flags: u16) -> IndexBuffer {
+pub fn create_index_buffer(mem: &Memory, flags: BufferFlags) -> IndexBuffer {
+    let session = crate::context::creation_session();
     unsafe {
-        let _ret = (*g_vtbl.bgfx_create_index_buffer).unwrap()(mem.handle, flags);
-        IndexBuffer { handle: _ret }
+        let _ret = vtbl().create_index_buffer.unwrap()(mem.handle, flags.bits());
+        IndexBuffer {
+            handle: _ret,
+            session,
+        }
     }
 }
 /// * `layout`:
 /// Vertex layout.
 pub fn create_vertex_layout(layout: &BuiltVertexLayout) -> VertexLayout {
+    let session = crate::context::creation_session();
     unsafe {
         let _layout = std::mem::transmute(layout);
-        let _ret = (*g_vtbl.bgfx_create_vertex_layout).unwrap()(_layout);
-        VertexLayout { handle: _ret }
+        let _ret = vtbl().create_vertex_layout.unwrap()(_layout);
+        VertexLayout {
+            handle: _ret,
+            session,
+        }
     }
 }
 /// * `mem`:
@@ -4112,11 +4446,19 @@ pub fn create_vertex_layout(layout: &BuiltVertexLayout) -> VertexLayout {
 ///      data is passed. If this flag is not specified, and more data is passed on update, the buffer
 ///      will be trimmed to fit the existing buffer size. This flag has effect only on dynamic buffers.
 ///  - [BufferFlags::INDEX32] - Buffer is using 32-bit indices. This flag has effect only on index buffers.
-pub fn create_vertex_buffer(mem: &Memory, layout: &BuiltVertexLayout, flags: u16) -> VertexBuffer {
+pub fn create_vertex_buffer(
+    mem: &Memory,
+    layout: &BuiltVertexLayout,
+    flags: BufferFlags,
+) -> VertexBuffer {
+    let session = crate::context::creation_session();
     unsafe {
         let _layout = std::mem::transmute(layout);
-        let _ret = (*g_vtbl.bgfx_create_vertex_buffer).unwrap()(mem.handle, _layout, flags);
-        VertexBuffer { handle: _ret }
+        let _ret = vtbl().create_vertex_buffer.unwrap()(mem.handle, _layout, flags.bits());
+        VertexBuffer {
+            handle: _ret,
+            session,
+        }
     }
 }
 /// * `num`:
@@ -4134,10 +4476,14 @@ pub fn create_vertex_buffer(mem: &Memory, layout: &BuiltVertexLayout, flags: u16
 ///       buffers.
 ///   - [BufferFlags::INDEX32] - Buffer is using 32-bit indices. This flag has effect only on
 ///       index buffers.
-pub fn create_dynamic_index_buffer(num: u32, flags: u16) -> DynamicIndexBuffer {
+pub fn create_dynamic_index_buffer(num: u32, flags: BufferFlags) -> DynamicIndexBuffer {
+    let session = crate::context::creation_session();
     unsafe {
-        let _ret = (*g_vtbl.bgfx_create_dynamic_index_buffer).unwrap()(num, flags);
-        DynamicIndexBuffer { handle: _ret }
+        let _ret = vtbl().create_dynamic_index_buffer.unwrap()(num, flags.bits());
+        DynamicIndexBuffer {
+            handle: _ret,
+            session,
+        }
     }
 }
 /// * `mem`:
@@ -4155,10 +4501,14 @@ pub fn create_dynamic_index_buffer(num: u32, flags: u16) -> DynamicIndexBuffer {
 ///       buffers.
 ///   - [BufferFlags::INDEX32] - Buffer is using 32-bit indices. This flag has effect only on
 ///       index buffers.
-pub fn create_dynamic_index_buffer_mem(mem: &Memory, flags: u16) -> DynamicIndexBuffer {
+pub fn create_dynamic_index_buffer_mem(mem: &Memory, flags: BufferFlags) -> DynamicIndexBuffer {
+    let session = crate::context::creation_session();
     unsafe {
-        let _ret = (*g_vtbl.bgfx_create_dynamic_index_buffer_mem).unwrap()(mem.handle, flags);
-        DynamicIndexBuffer { handle: _ret }
+        let _ret = vtbl().create_dynamic_index_buffer_mem.unwrap()(mem.handle, flags.bits());
+        DynamicIndexBuffer {
+            handle: _ret,
+            session,
+        }
     }
 }
 /// * `handle`:
@@ -4169,7 +4519,7 @@ pub fn create_dynamic_index_buffer_mem(mem: &Memory, flags: u16) -> DynamicIndex
 /// Index buffer data.
 pub fn update_dynamic_index_buffer(handle: &DynamicIndexBuffer, start_index: u32, mem: &Memory) {
     unsafe {
-        (*g_vtbl.bgfx_update_dynamic_index_buffer).unwrap()(handle.handle, start_index, mem.handle);
+        vtbl().update_dynamic_index_buffer.unwrap()(handle.handle, start_index, mem.handle);
     }
 }
 /// * `num`:
@@ -4192,12 +4542,16 @@ pub fn update_dynamic_index_buffer(handle: &DynamicIndexBuffer, start_index: u32
 pub fn create_dynamic_vertex_buffer(
     num: u32,
     layout: &BuiltVertexLayout,
-    flags: u16,
+    flags: BufferFlags,
 ) -> DynamicVertexBuffer {
+    let session = crate::context::creation_session();
     unsafe {
         let _layout = std::mem::transmute(layout);
-        let _ret = (*g_vtbl.bgfx_create_dynamic_vertex_buffer).unwrap()(num, _layout, flags);
-        DynamicVertexBuffer { handle: _ret }
+        let _ret = vtbl().create_dynamic_vertex_buffer.unwrap()(num, _layout, flags.bits());
+        DynamicVertexBuffer {
+            handle: _ret,
+            session,
+        }
     }
 }
 /// * `mem`:
@@ -4220,12 +4574,17 @@ pub fn create_dynamic_vertex_buffer(
 pub fn create_dynamic_vertex_buffer_mem(
     mem: &Memory,
     layout: &BuiltVertexLayout,
-    flags: u16,
+    flags: BufferFlags,
 ) -> DynamicVertexBuffer {
+    let session = crate::context::creation_session();
     unsafe {
         let _layout = std::mem::transmute(layout);
-        let _ret = (*g_vtbl.bgfx_create_dynamic_vertex_buffer_mem).unwrap()(mem.handle, _layout, flags);
-        DynamicVertexBuffer { handle: _ret }
+        let _ret =
+            vtbl().create_dynamic_vertex_buffer_mem.unwrap()(mem.handle, _layout, flags.bits());
+        DynamicVertexBuffer {
+            handle: _ret,
+            session,
+        }
     }
 }
 /// * `handle`:
@@ -4236,7 +4595,7 @@ pub fn create_dynamic_vertex_buffer_mem(
 /// Vertex buffer data.
 pub fn update_dynamic_vertex_buffer(handle: &DynamicVertexBuffer, start_vertex: u32, mem: &Memory) {
     unsafe {
-        (*g_vtbl.bgfx_update_dynamic_vertex_buffer).unwrap()(handle.handle, start_vertex, mem.handle);
+        vtbl().update_dynamic_vertex_buffer.unwrap()(handle.handle, start_vertex, mem.handle);
     }
 }
 /// * `num`:
@@ -4245,7 +4604,7 @@ pub fn update_dynamic_vertex_buffer(handle: &DynamicVertexBuffer, start_vertex:
 /// Set to `true` if input indices will be 32-bit.
 pub fn get_avail_transient_index_buffer(num: u32, index_32: bool) -> u32 {
     unsafe {
-        let _ret = (*g_vtbl.bgfx_get_avail_transient_index_buffer).unwrap()(num, index_32);
+        let _ret = vtbl().get_avail_transient_index_buffer.unwrap()(num, index_32);
         _ret
     }
 }
@@ -4256,7 +4615,7 @@ pub fn get_avail_transient_index_buffer(num: u32, index_32: bool) -> u32 {
 pub fn get_avail_transient_vertex_buffer(num: u32, layout: &BuiltVertexLayout) -> u32 {
     unsafe {
         let _layout = std::mem::transmute(layout);
-        let _ret = (*g_vtbl.bgfx_get_avail_transient_vertex_buffer).unwrap()(num, _layout);
+        let _ret = vtbl().get_avail_transient_vertex_buffer.unwrap()(num, _layout);
         _ret
     }
 }
@@ -4266,7 +4625,7 @@ pub fn get_avail_transient_vertex_buffer(num: u32, layout: &BuiltVertexLayout) -
 /// Stride per instance.
 pub fn get_avail_instance_data_buffer(num: u32, stride: u16) -> u32 {
     unsafe {
-        let _ret = (*g_vtbl.bgfx_get_avail_instance_data_buffer).unwrap()(num, stride);
+        let _ret = vtbl().get_avail_instance_data_buffer.unwrap()(num, stride);
         _ret
     }
 }
@@ -4281,7 +4640,7 @@ pub fn get_avail_instance_data_buffer(num: u32, stride: u16) -> u32 {
 pub fn alloc_transient_index_buffer(tib: &mut TransientIndexBuffer, num: u32, index_32: bool) {
     unsafe {
         let _tib = std::mem::transmute(tib);
-        (*g_vtbl.bgfx_alloc_transient_index_buffer).unwrap()(_tib, num, index_32);
+        vtbl().alloc_transient_index_buffer.unwrap()(_tib, num, index_32);
     }
 }
 /// * `tvb`:
@@ -4300,7 +4659,7 @@ pub fn alloc_transient_vertex_buffer(
     unsafe {
         let _tvb = std::mem::transmute(tvb);
         let _layout = std::mem::transmute(layout);
-        (*g_vtbl.bgfx_alloc_transient_vertex_buffer).unwrap()(_tvb, num, _layout);
+        vtbl().alloc_transient_vertex_buffer.unwrap()(_tvb, num, _layout);
     }
 }
 /// * `tvb`:
@@ -4331,7 +4690,7 @@ pub fn alloc_transient_buffers(
         let _tvb = std::mem::transmute(tvb);
         let _layout = std::mem::transmute(layout);
         let _tib = std::mem::transmute(tib);
-        let _ret = bgfx_sys::bgfx_alloc_transient_buffers(
+        let _ret = vtbl().alloc_transient_buffers.unwrap()(
             _tvb,
             _layout,
             num_vertices,
@@ -4353,23 +4712,31 @@ pub fn alloc_transient_buffers(
 pub fn alloc_instance_data_buffer(idb: &mut InstanceDataBuffer, num: u32, stride: u16) {
     unsafe {
         let _idb = std::mem::transmute(idb);
-        (*g_vtbl.bgfx_alloc_instance_data_buffer).unwrap()(_idb, num, stride);
+        vtbl().alloc_instance_data_buffer.unwrap()(_idb, num, stride);
     }
 }
 /// * `num`:
 /// Number of indirect calls.
 pub fn create_indirect_buffer(num: u32) -> IndirectBuffer {
+    let session = crate::context::creation_session();
     unsafe {
-        let _ret = (*g_vtbl.bgfx_create_indirect_buffer).unwrap()(num);
-        IndirectBuffer { handle: _ret }
+        let _ret = vtbl().create_indirect_buffer.unwrap()(num);
+        IndirectBuffer {
+            handle: _ret,
+            session,
+        }
     }
 }
 /// * `mem`:
 /// Shader binary.
 pub fn create_shader(mem: &Memory) -> Shader {
+    let session = crate::context::creation_session();
     unsafe {
-        let _ret = (*g_vtbl.bgfx_create_shader).unwrap()(mem.handle);
-        Shader { handle: _ret }
+        let _ret = vtbl().create_shader.unwrap()(mem.handle);
+        Shader {
+            handle: _ret,
+            session,
+        }
     }
 }
 /// * `vsh`:
@@ -4379,9 +4746,13 @@ pub fn create_shader(mem: &Memory) -> Shader {
 /// * `destroy_shaders`:
 /// If true, shaders will be destroyed when program is destroyed.
 pub fn create_program(vsh: &Shader, fsh: &Shader, destroy_shaders: bool) -> Program {
+    let session = crate::context::creation_session();
     unsafe {
-        let _ret = (*g_vtbl.bgfx_create_program).unwrap()(vsh.handle, fsh.handle, destroy_shaders);
-        Program { handle: _ret }
+        let _ret = vtbl().create_program.unwrap()(vsh.handle, fsh.handle, destroy_shaders);
+        Program {
+            handle: _ret,
+            session,
+        }
     }
 }
 /// * `csh`:
@@ -4389,9 +4760,13 @@ pub fn create_program(vsh: &Shader, fsh: &Shader, destroy_shaders: bool) -> Prog
 /// * `destroy_shaders`:
 /// If true, shaders will be destroyed when program is destroyed.
 pub fn create_compute_program(csh: &Shader, destroy_shaders: bool) -> Program {
+    let session = crate::context::creation_session();
     unsafe {
-        let _ret = (*g_vtbl.bgfx_create_compute_program).unwrap()(csh.handle, destroy_shaders);
-        Program { handle: _ret }
+        let _ret = vtbl().create_compute_program.unwrap()(csh.handle, destroy_shaders);
+        Program {
+            handle: _ret,
+            session,
+        }
     }
 }
 /// * `depth`:
@@ -4409,10 +4784,16 @@ pub fn is_texture_valid(
     cube_map: bool,
     num_layers: u16,
     format: TextureFormat,
-    flags: u64,
+    flags: TextureFlags,
 ) -> bool {
     unsafe {
-        let _ret = (*g_vtbl.bgfx_is_texture_valid).unwrap()(depth, cube_map, num_layers, format as _, flags);
+        let _ret = vtbl().is_texture_valid.unwrap()(
+            depth,
+            cube_map,
+            num_layers,
+            format as _,
+            flags.bits(),
+        );
         _ret
     }
 }
@@ -4423,7 +4804,7 @@ pub fn is_texture_valid(
 pub fn is_frame_buffer_valid(num: u8, attachment: &Attachment) -> bool {
     unsafe {
         let _attachment = std::mem::transmute(attachment);
-        let _ret = (*g_vtbl.bgfx_is_frame_buffer_valid).unwrap()(num, _attachment);
+        let _ret = vtbl().is_frame_buffer_valid.unwrap()(num, _attachment);
         _ret
     }
 }
@@ -4455,7 +4836,7 @@ pub fn calc_texture_size(
 ) {
     unsafe {
         let _info = std::mem::transmute(info);
-        bgfx_sys::bgfx_calc_texture_size(
+        vtbl().calc_texture_size.unwrap()(
             _info,
             width,
             height,
@@ -4476,15 +4857,26 @@ pub fn calc_texture_size(
 ///   mode.
 /// - [SamplerFlags::[MIN/MAG/MIP]_[POINT/ANISOTROPIC]] - Point or anisotropic
 ///   sampling.
+///   Takes a [SamplerDesc] for the sampler flags alone, or [TextureFlags].
 /// * `skip`:
 /// Skip top level mips when parsing texture.
 /// * `info`:
 /// When non-`NULL` is specified it returns parsed texture information.
-pub fn create_texture(mem: &Memory, flags: u64, skip: u8, info: &mut TextureInfo) -> Texture {
+pub fn create_texture(
+    mem: &Memory,
+    flags: impl Into<TextureFlags>,
+    skip: u8,
+    info: &mut TextureInfo,
+) -> Texture {
+    let session = crate::context::creation_session();
     unsafe {
-        let _info = std::mem::transmute(info);
-        let _ret = (*g_vtbl.bgfx_create_texture).unwrap()(mem.handle, flags, skip, _info);
-        Texture { handle: _ret }
+        let _info = &mut *info as *mut TextureInfo as *mut bgfx_sys::bgfx_texture_info_s;
+        let _ret = vtbl().create_texture.unwrap()(mem.handle, flags.into().bits(), skip, _info);
+        Texture {
+            handle: _ret,
+            session,
+            size: TextureSize::from_info(info),
+        }
     }
 }
 /// * `width`:
@@ -4505,6 +4897,7 @@ pub fn create_texture(mem: &Memory, flags: u64, skip: u8, info: &mut TextureInfo
 ///   mode.
 /// - [SamplerFlags::[MIN/MAG/MIP]_[POINT/ANISOTROPIC]] - Point or anisotropic
 ///   sampling.
+///   Takes a [SamplerDesc] for the sampler flags alone, or [TextureFlags].
 /// * `mem`:
 /// Texture data. If `_mem` is non-NULL, created texture will be immutable. If
 /// `_mem` is NULL content of the texture is uninitialized. When `_numLayers` is more than
@@ -4515,20 +4908,27 @@ pub fn create_texture_2d(
     has_mips: bool,
     num_layers: u16,
     format: TextureFormat,
-    flags: u64,
+    flags: impl Into<TextureFlags>,
     mem: &Memory,
 ) -> Texture {
+    let session = crate::context::creation_session();
     unsafe {
-        let _ret = bgfx_sys::bgfx_create_texture_2d(
+        let _ret = vtbl().create_texture_2d.unwrap()(
             width,
             height,
             has_mips,
             num_layers,
             format as _,
-            flags,
+            flags.into().bits(),
             mem.handle,
         );
-        Texture { handle: _ret }
+        Texture {
+            handle: _ret,
+            session,
+            size: Some(TextureSize::new(
+                width, height, 1, num_layers, has_mips, false, format,
+            )),
+        }
     }
 }
 /// * `ratio`:
@@ -4547,22 +4947,28 @@ pub fn create_texture_2d(
 ///   mode.
 /// - [SamplerFlags::[MIN/MAG/MIP]_[POINT/ANISOTROPIC]] - Point or anisotropic
 ///   sampling.
+///   Takes a [SamplerDesc] for the sampler flags alone, or [TextureFlags].
 pub fn create_texture_2d_scaled(
     ratio: BackbufferRatio,
     has_mips: bool,
     num_layers: u16,
     format: TextureFormat,
-    flags: u64,
+    flags: impl Into<TextureFlags>,
 ) -> Texture {
+    let session = crate::context::creation_session();
     unsafe {
-        let _ret = bgfx_sys::bgfx_create_texture_2d_scaled(
+        let _ret = vtbl().create_texture_2d_scaled.unwrap()(
             ratio as _,
             has_mips,
             num_layers,
             format as _,
-            flags,
+            flags.into().bits(),
         );
-        Texture { handle: _ret }
+        Texture {
+            handle: _ret,
+            session,
+            size: None,
+        }
     }
 }
 /// * `width`:
@@ -4582,6 +4988,7 @@ pub fn create_texture_2d_scaled(
 ///   mode.
 /// - [SamplerFlags::[MIN/MAG/MIP]_[POINT/ANISOTROPIC]] - Point or anisotropic
 ///   sampling.
+///   A [SamplerDesc] converts to the sampler flags alone with `into()`.
 /// * `mem`:
 /// Texture data. If `_mem` is non-NULL, created texture will be immutable. If
 /// `_mem` is NULL content of the texture is uninitialized. When `_numLayers` is more than
@@ -4594,22 +5001,29 @@ pub fn create_texture_3d(
     format: TextureFormat,
     params: CreateTexture3DArgs,
 ) -> Texture {
+    let session = crate::context::creation_session();
     unsafe {
         let _mem = if let Some(h) = params.mem {
             h.handle
         } else {
             std::ptr::null()
         };
-        let _ret = bgfx_sys::bgfx_create_texture_3d(
+        let _ret = vtbl().create_texture_3d.unwrap()(
             width,
             height,
             depth,
             has_mips,
             format as _,
-            params.flags,
+            params.flags.bits(),
             _mem,
         );
-        Texture { handle: _ret }
+        Texture {
+            handle: _ret,
+            session,
+            size: Some(TextureSize::new(
+                width, height, depth, 1, has_mips, false, format,
+            )),
+        }
     }
 }
 /// * `size`:
@@ -4628,6 +5042,7 @@ pub fn create_texture_3d(
 ///   mode.
 /// - [SamplerFlags::[MIN/MAG/MIP]_[POINT/ANISOTROPIC]] - Point or anisotropic
 ///   sampling.
+///   A [SamplerDesc] converts to the sampler flags alone with `into()`.
 /// * `mem`:
 /// Texture data. If `_mem` is non-NULL, created texture will be immutable. If
 /// `_mem` is NULL content of the texture is uninitialized. When `_numLayers` is more than
@@ -4639,21 +5054,28 @@ pub fn create_texture_cube(
     format: TextureFormat,
     params: CreateTextureCubeArgs,
 ) -> Texture {
+    let session = crate::context::creation_session();
     unsafe {
         let _mem = if let Some(h) = params.mem {
             h.handle
         } else {
             std::ptr::null()
         };
-        let _ret = bgfx_sys::bgfx_create_texture_cube(
+        let _ret = vtbl().create_texture_cube.unwrap()(
             size,
             has_mips,
             num_layers,
             format as _,
-            params.flags,
+            params.flags.bits(),
             _mem,
         );
-        Texture { handle: _ret }
+        Texture {
+            handle: _ret,
+            session,
+            size: Some(TextureSize::new(
+                size, size, 1, num_layers, has_mips, true, format,
+            )),
+        }
     }
 }
 /// * `handle`:
@@ -4687,7 +5109,7 @@ pub fn update_texture_2d(
     pitch: u16,
 ) {
     unsafe {
-        bgfx_sys::bgfx_update_texture_2d(
+        vtbl().update_texture_2d.unwrap()(
             handle.handle,
             layer,
             mip,
@@ -4730,7 +5152,7 @@ pub fn update_texture_3d(
     mem: &Memory,
 ) {
     unsafe {
-        bgfx_sys::bgfx_update_texture_3d(
+        vtbl().update_texture_3d.unwrap()(
             handle.handle,
             mip,
             x,
@@ -4795,7 +5217,7 @@ pub fn update_texture_cube(
     pitch: u16,
 ) {
     unsafe {
-        bgfx_sys::bgfx_update_texture_cube(
+        vtbl().update_texture_cube.unwrap()(
             handle.handle,
             layer,
             side,
@@ -4826,11 +5248,16 @@ pub fn create_frame_buffer(
     width: u16,
     height: u16,
     format: TextureFormat,
-    texture_flags: u64,
+    texture_flags: TextureFlags,
 ) -> FrameBuffer {
+    let session = crate::context::creation_session();
     unsafe {
-        let _ret = (*g_vtbl.bgfx_create_frame_buffer).unwrap()(width, height, format as _, texture_flags);
-        FrameBuffer { handle: _ret }
+        let _ret =
+            vtbl().create_frame_buffer.unwrap()(width, height, format as _, texture_flags.bits());
+        FrameBuffer {
+            handle: _ret,
+            session,
+        }
     }
 }
 /// * `ratio`:
@@ -4848,12 +5275,19 @@ pub fn create_frame_buffer(
 pub fn create_frame_buffer_scaled(
     ratio: BackbufferRatio,
     format: TextureFormat,
-    texture_flags: u64,
+    texture_flags: TextureFlags,
 ) -> FrameBuffer {
+    let session = crate::context::creation_session();
     unsafe {
-        let _ret =
-            (*g_vtbl.bgfx_create_frame_buffer_scaled).unwrap()(ratio as _, format as _, texture_flags);
-        FrameBuffer { handle: _ret }
+        let _ret = vtbl().create_frame_buffer_scaled.unwrap()(
+            ratio as _,
+            format as _,
+            texture_flags.bits(),
+        );
+        FrameBuffer {
+            handle: _ret,
+            session,
+        }
     }
 }
 /// * `num`:
@@ -4868,10 +5302,14 @@ pub fn create_frame_buffer_from_handles(
     handles: &Texture,
     destroy_texture: bool,
 ) -> FrameBuffer {
+    let session = crate::context::creation_session();
     unsafe {
         let _ret =
-            (*g_vtbl.bgfx_create_frame_buffer_from_handles).unwrap()(num, &handles.handle, destroy_texture);
-        FrameBuffer { handle: _ret }
+            vtbl().create_frame_buffer_from_handles.unwrap()(num, &handles.handle, destroy_texture);
+        FrameBuffer {
+            handle: _ret,
+            session,
+        }
     }
 }
 /// * `num`:
@@ -4886,11 +5324,15 @@ pub fn create_frame_buffer_from_attachment(
     attachment: &Attachment,
     destroy_texture: bool,
 ) -> FrameBuffer {
+    let session = crate::context::creation_session();
     unsafe {
         let _attachment = std::mem::transmute(attachment);
         let _ret =
-            (*g_vtbl.bgfx_create_frame_buffer_from_attachment).unwrap()(num, _attachment, destroy_texture);
-        FrameBuffer { handle: _ret }
+            vtbl().create_frame_buffer_from_attachment.unwrap()(num, _attachment, destroy_texture);
+        FrameBuffer {
+            handle: _ret,
+            session,
+        }
     }
 }
 /// * `nwh`:
@@ -4909,24 +5351,33 @@ pub fn create_frame_buffer_from_nwh(
     height: u16,
     params: CreateFrameBufferFromNwhArgs,
 ) -> FrameBuffer {
+    let session = crate::context::creation_session();
     unsafe {
-        let _ret = bgfx_sys::bgfx_create_frame_buffer_from_nwh(
-            nwh,
+        let _ret = vtbl().create_frame_buffer_from_nwh.unwrap()(
+            nwh as _,
             width,
             height,
             params.format as _,
             params.depth_format as _,
         );
-        FrameBuffer { handle: _ret }
+        FrameBuffer {
+            handle: _ret,
+            session,
+        }
     }
 }
 /// * `handle`:
 /// Frame buffer handle.
 /// * `attachment`:
 pub fn get_texture(handle: &FrameBuffer, attachment: u8) -> Texture {
+    let session = crate::context::creation_session();
     unsafe {
-        let _ret = (*g_vtbl.bgfx_get_texture).unwrap()(handle.handle, attachment);
-        Texture { handle: _ret }
+        let _ret = vtbl().get_texture.unwrap()(handle.handle, attachment);
+        Texture {
+            handle: _ret,
+            session,
+            size: None,
+        }
     }
 }
 /// * `handle`:
@@ -4934,15 +5385,16 @@ pub fn get_texture(handle: &FrameBuffer, attachment: u8) -> Texture {
 /// * `info`:
 /// Uniform info.
 pub fn get_uniform_info(handle: &Uniform, info: &mut UniformInfo) {
-    unsafe {
-        let _info = std::mem::transmute(info);
-        (*g_vtbl.bgfx_get_uniform_info).unwrap()(handle.handle, _info);
-    }
+    *info = uniform_info(handle.handle);
 }
 pub fn create_occlusion_query() -> OcclusionQuery {
+    let session = crate::context::creation_session();
     unsafe {
-        let _ret = (*g_vtbl.bgfx_create_occlusion_query).unwrap()();
-        OcclusionQuery { handle: _ret }
+        let _ret = vtbl().create_occlusion_query.unwrap()();
+        OcclusionQuery {
+            handle: _ret,
+            session,
+        }
     }
 }
 /// * `handle`:
@@ -4952,7 +5404,7 @@ pub fn create_occlusion_query() -> OcclusionQuery {
 /// can be `NULL` if result of occlusion query is not needed.
 pub fn get_result(handle: &OcclusionQuery, result: *mut i32) -> OcclusionQueryResult {
     unsafe {
-        let _ret = (*g_vtbl.bgfx_get_result).unwrap()(handle.handle, result);
+        let _ret = vtbl().get_result.unwrap()(handle.handle, result);
         std::mem::transmute(_ret)
     }
 }
@@ -4968,7 +5420,7 @@ pub fn get_result(handle: &OcclusionQuery, result: *mut i32) -> OcclusionQueryRe
 /// Height of view port region.
 pub fn set_view_rect(id: ViewId, x: u16, y: u16, width: u16, height: u16) {
     unsafe {
-        (*g_vtbl.bgfx_set_view_rect).unwrap()(id, x, y, width, height);
+        vtbl().set_view_rect.unwrap()(id, x, y, width, height);
     }
 }
 /// * `id`:
@@ -4982,7 +5434,7 @@ pub fn set_view_rect(id: ViewId, x: u16, y: u16, width: u16, height: u16) {
 /// See: [BackbufferRatio].
 pub fn set_view_rect_ratio(id: ViewId, x: u16, y: u16, ratio: BackbufferRatio) {
     unsafe {
-        (*g_vtbl.bgfx_set_view_rect_ratio).unwrap()(id, x, y, ratio as _);
+        vtbl().set_view_rect_ratio.unwrap()(id, x, y, ratio as _);
     }
 }
 /// * `id`:
@@ -4997,7 +5449,7 @@ pub fn set_view_rect_ratio(id: ViewId, x: u16, y: u16, ratio: BackbufferRatio) {
 /// Height of view scissor region.
 pub fn set_view_scissor(id: ViewId, params: SetViewScissorArgs) {
     unsafe {
-        (*g_vtbl.bgfx_set_view_scissor).unwrap()(id, params.x, params.y, params.width, params.height);
+        vtbl().set_view_scissor.unwrap()(id, params.x, params.y, params.width, params.height);
     }
 }
 /// * `id`:
@@ -5011,9 +5463,15 @@ pub fn set_view_scissor(id: ViewId, params: SetViewScissorArgs) {
 /// Depth clear value.
 /// * `stencil`:
 /// Stencil clear value.
-pub fn set_view_clear(id: ViewId, flags: u16, params: SetViewClearArgs) {
+pub fn set_view_clear(id: ViewId, flags: ClearFlags, params: SetViewClearArgs) {
     unsafe {
-        (*g_vtbl.bgfx_set_view_clear).unwrap()(id, flags, params.rgba, params.depth, params.stencil);
+        vtbl().set_view_clear.unwrap()(
+            id,
+            flags.bits(),
+            params.rgba.to_rgba32(),
+            params.depth,
+            params.stencil,
+        );
     }
 }
 /// * `id`:
@@ -5043,15 +5501,25 @@ pub fn set_view_clear(id: ViewId, flags: u16, params: SetViewClearArgs) {
 /// Palette index for frame buffer attachment 7.
 pub fn set_view_clear_mrt(
     id: ViewId,
-    flags: u16,
+    flags: ClearFlags,
     depth: f32,
     stencil: u8,
     params: SetViewClearMrtArgs,
 ) {
     unsafe {
-        bgfx_sys::bgfx_set_view_clear_mrt(
-            id, flags, depth, stencil, params.c_0, params.c_1, params.c_2, params.c_3, params.c_4,
-            params.c_5, params.c_6, params.c_7,
+        vtbl().set_view_clear_mrt.unwrap()(
+            id,
+            flags.bits(),
+            depth,
+            stencil,
+            params.c_0,
+            params.c_1,
+            params.c_2,
+            params.c_3,
+            params.c_4,
+            params.c_5,
+            params.c_6,
+            params.c_7,
         );
     }
 }
@@ -5061,7 +5529,7 @@ pub fn set_view_clear_mrt(
 /// View sort mode. See [ViewMode].
 pub fn set_view_mode(id: ViewId, mode: ViewMode) {
     unsafe {
-        (*g_vtbl.bgfx_set_view_mode).unwrap()(id, mode as _);
+        vtbl().set_view_mode.unwrap()(id, mode as _);
     }
 }
 /// * `id`:
@@ -5072,46 +5540,54 @@ pub fn set_view_mode(id: ViewId, mode: ViewMode) {
 /// default back buffer.
 pub fn set_view_frame_buffer(id: ViewId, handle: &FrameBuffer) {
     unsafe {
-        (*g_vtbl.bgfx_set_view_frame_buffer).unwrap()(id, handle.handle);
+        vtbl().set_view_frame_buffer.unwrap()(id, handle.handle);
     }
 }
 /// * `id`:
 pub fn reset_view(id: ViewId) {
     unsafe {
-        (*g_vtbl.bgfx_reset_view).unwrap()(id);
+        vtbl().reset_view.unwrap()(id);
     }
 }
 /// * `for_thread`:
 /// Explicitly request an encoder for a worker thread.
-pub fn encoder_begin(for_thread: bool) -> &'static Encoder {
-    unsafe {
-        let _ret = (*g_vtbl.bgfx_encoder_begin).unwrap()(for_thread);
-        std::mem::transmute(_ret)
-    }
+///
+/// # Safety
+///
+/// The encoder must not be used after it's ended with [encoder_end], or after the frame is
+/// advanced.
+#[deprecated(note = "the encoder can outlive the frame, use `Frame::encoder` instead")]
+pub unsafe fn encoder_begin(for_thread: bool) -> &'static Encoder {
+    let _ret = vtbl().encoder_begin.unwrap()(for_thread);
+    std::mem::transmute(_ret)
 }
 /// * `encoder`:
 /// Encoder.
-pub fn encoder_end(encoder: &Encoder) {
-    unsafe {
-        let _encoder = std::mem::transmute(encoder);
-        (*g_vtbl.bgfx_encoder_end).unwrap()(_encoder);
-    }
+///
+/// # Safety
+///
+/// `encoder` must have been returned by [encoder_begin] and not be ended yet.
+#[deprecated(note = "use `Frame::encoder`, which ends the encoder on drop")]
+pub unsafe fn encoder_end(encoder: &Encoder) {
+    let _encoder = std::mem::transmute(encoder);
+    vtbl().encoder_end.unwrap()(_encoder);
 }
 /// * `handle`:
 /// Frame buffer handle. If handle is ]BGFX_INVALID_HANDLE] request will be
 /// made for main window back buffer.
 /// * `file_path`:
-/// Will be passed to `bgfx::uint8_t::screenShot` callback.
-pub fn request_screen_shot(handle: &FrameBuffer, file_path: *const i8) {
+/// Will be passed to [Callback::screen_shot].
+pub fn request_screen_shot(handle: &FrameBuffer, file_path: &str) {
     unsafe {
-        (*g_vtbl.bgfx_request_screen_shot).unwrap()(handle.handle, file_path);
+        let file_path_ = CFixedString::from_str(file_path);
+        vtbl().request_screen_shot.unwrap()(handle.handle, file_path_.as_ptr());
     }
 }
 /// * `msecs`:
 /// Timeout in milliseconds.
 pub fn render_frame(msecs: i32) -> RenderFrame {
     unsafe {
-        let _ret = (*g_vtbl.bgfx_render_frame).unwrap()(msecs);
+        let _ret = vtbl().render_frame.unwrap()(msecs);
         std::mem::transmute(_ret)
     }
 }
@@ -5120,12 +5596,12 @@ pub fn render_frame(msecs: i32) -> RenderFrame {
 pub fn set_platform_data(data: &PlatformData) {
     unsafe {
         let _data = std::mem::transmute(data);
-        (*g_vtbl.bgfx_set_platform_data).unwrap()(_data);
+        vtbl().set_platform_data.unwrap()(_data);
     }
 }
 pub fn get_internal_data() -> &'static InternalData {
     unsafe {
-        let _ret = (*g_vtbl.bgfx_get_internal_data).unwrap()();
+        let _ret = vtbl().get_internal_data.unwrap()();
         std::mem::transmute(_ret)
     }
 }
@@ -5133,7 +5609,7 @@ pub fn get_internal_data() -> &'static InternalData {
 /// Marker string.
 pub fn set_marker(marker: *const i8) {
     unsafe {
-        (*g_vtbl.bgfx_set_marker).unwrap()(marker);
+        vtbl().set_marker.unwrap()(marker as _);
     }
 }
 /// * `state`:
@@ -5151,7 +5627,7 @@ pub fn set_marker(marker: *const i8) {
 ///   [StateBlendFlags::INV_FACTOR] blend modes.
 pub fn set_state(state: u64, rgba: u32) {
     unsafe {
-        (*g_vtbl.bgfx_set_state).unwrap()(state, rgba);
+        vtbl().set_state.unwrap()(state, rgba);
     }
 }
 /// * `handle`:
@@ -5160,7 +5636,7 @@ pub fn set_state(state: u64, rgba: u32) {
 /// Render if occlusion query is visible.
 pub fn set_condition(handle: &OcclusionQuery, visible: bool) {
     unsafe {
-        (*g_vtbl.bgfx_set_condition).unwrap()(handle.handle, visible);
+        vtbl().set_condition.unwrap()(handle.handle, visible);
     }
 }
 /// * `fstencil`:
@@ -5170,7 +5646,7 @@ pub fn set_condition(handle: &OcclusionQuery, visible: bool) {
 /// _fstencil is applied to both front and back facing primitives.
 pub fn set_stencil(fstencil: u32, bstencil: u32) {
     unsafe {
-        (*g_vtbl.bgfx_set_stencil).unwrap()(fstencil, bstencil);
+        vtbl().set_stencil.unwrap()(fstencil, bstencil);
     }
 }
 /// * `x`:
@@ -5183,7 +5659,7 @@ pub fn set_stencil(fstencil: u32, bstencil: u32) {
 /// Height of view scissor region.
 pub fn set_scissor(x: u16, y: u16, width: u16, height: u16) -> u16 {
     unsafe {
-        let _ret = (*g_vtbl.bgfx_set_scissor).unwrap()(x, y, width, height);
+        let _ret = vtbl().set_scissor.unwrap()(x, y, width, height);
         _ret
     }
 }
@@ -5191,7 +5667,7 @@ pub fn set_scissor(x: u16, y: u16, width: u16, height: u16) -> u16 {
 /// Index in scissor cache.
 pub fn set_scissor_cached(cache: u16) {
     unsafe {
-        (*g_vtbl.bgfx_set_scissor_cached).unwrap()(cache);
+        vtbl().set_scissor_cached.unwrap()(cache);
     }
 }
 /// * `transform`:
@@ -5201,7 +5677,7 @@ pub fn set_scissor_cached(cache: u16) {
 pub fn alloc_transform(transform: &mut Transform, num: u16) -> u32 {
     unsafe {
         let _transform = std::mem::transmute(transform);
-        let _ret = (*g_vtbl.bgfx_alloc_transform).unwrap()(_transform, num);
+        let _ret = vtbl().alloc_transform.unwrap()(_transform, num);
         _ret
     }
 }
@@ -5211,9 +5687,9 @@ pub fn alloc_transform(transform: &mut Transform, num: u16) -> u32 {
 /// First index to render.
 /// * `num_indices`:
 /// Number of indices to render.
-pub fn set_index_buffer(handle: &IndexBuffer, first_index: u32, num_indices: u32) {
+pub fn set_index_buffer(handle: &impl AsRef<IndexBuffer>, first_index: u32, num_indices: u32) {
     unsafe {
-        (*g_vtbl.bgfx_set_index_buffer).unwrap()(handle.handle, first_index, num_indices);
+        vtbl().set_index_buffer.unwrap()(handle.as_ref().handle, first_index, num_indices);
     }
 }
 /// * `handle`:
@@ -5224,7 +5700,7 @@ pub fn set_index_buffer(handle: &IndexBuffer, first_index: u32, num_indices: u32
 /// Number of indices to render.
 pub fn set_dynamic_index_buffer(handle: &DynamicIndexBuffer, first_index: u32, num_indices: u32) {
     unsafe {
-        (*g_vtbl.bgfx_set_dynamic_index_buffer).unwrap()(handle.handle, first_index, num_indices);
+        vtbl().set_dynamic_index_buffer.unwrap()(handle.handle, first_index, num_indices);
     }
 }
 /// * `tib`:
@@ -5236,7 +5712,7 @@ pub fn set_dynamic_index_buffer(handle: &DynamicIndexBuffer, first_index: u32, n
 pub fn set_transient_index_buffer(tib: &TransientIndexBuffer, first_index: u32, num_indices: u32) {
     unsafe {
         let _tib = std::mem::transmute(tib);
-        (*g_vtbl.bgfx_set_transient_index_buffer).unwrap()(_tib, first_index, num_indices);
+        vtbl().set_transient_index_buffer.unwrap()(_tib, first_index, num_indices);
     }
 }
 /// * `stream`:
@@ -5247,9 +5723,19 @@ pub fn set_transient_index_buffer(tib: &TransientIndexBuffer, first_index: u32,
 /// First vertex to render.
 /// * `num_vertices`:
 /// Number of vertices to render.
-pub fn set_vertex_buffer(stream: u8, handle: &VertexBuffer, start_vertex: u32, num_vertices: u32) {
+pub fn set_vertex_buffer(
+    stream: u8,
+    handle: &impl AsRef<VertexBuffer>,
+    start_vertex: u32,
+    num_vertices: u32,
+) {
     unsafe {
-        (*g_vtbl.bgfx_set_vertex_buffer).unwrap()(stream, handle.handle, start_vertex, num_vertices);
+        vtbl().set_vertex_buffer.unwrap()(
+            stream,
+            handle.as_ref().handle,
+            start_vertex,
+            num_vertices,
+        );
     }
 }
 /// * `stream`:
@@ -5272,7 +5758,7 @@ pub fn set_vertex_buffer_with_layout(
     layout_handle: &VertexLayout,
 ) {
     unsafe {
-        bgfx_sys::bgfx_set_vertex_buffer_with_layout(
+        vtbl().set_vertex_buffer_with_layout.unwrap()(
             stream,
             handle.handle,
             start_vertex,
@@ -5296,7 +5782,12 @@ pub fn set_dynamic_vertex_buffer(
     num_vertices: u32,
 ) {
     unsafe {
-        (*g_vtbl.bgfx_set_dynamic_vertex_buffer).unwrap()(stream, handle.handle, start_vertex, num_vertices);
+        vtbl().set_dynamic_vertex_buffer.unwrap()(
+            stream,
+            handle.handle,
+            start_vertex,
+            num_vertices,
+        );
     }
 }
 /// * `stream`:
@@ -5319,7 +5810,7 @@ pub fn set_dynamic_vertex_buffer_with_layout(
     layout_handle: &VertexLayout,
 ) {
     unsafe {
-        bgfx_sys::bgfx_set_dynamic_vertex_buffer_with_layout(
+        vtbl().set_dynamic_vertex_buffer_with_layout.unwrap()(
             stream,
             handle.handle,
             start_vertex,
@@ -5344,7 +5835,7 @@ pub fn set_transient_vertex_buffer(
 ) {
     unsafe {
         let _tvb = std::mem::transmute(tvb);
-        (*g_vtbl.bgfx_set_transient_vertex_buffer).unwrap()(stream, _tvb, start_vertex, num_vertices);
+        vtbl().set_transient_vertex_buffer.unwrap()(stream, _tvb, start_vertex, num_vertices);
     }
 }
 /// * `stream`:
@@ -5368,7 +5859,7 @@ pub fn set_transient_vertex_buffer_with_layout(
 ) {
     unsafe {
         let _tvb = std::mem::transmute(tvb);
-        bgfx_sys::bgfx_set_transient_vertex_buffer_with_layout(
+        vtbl().set_transient_vertex_buffer_with_layout.unwrap()(
             stream,
             _tvb,
             start_vertex,
@@ -5381,7 +5872,7 @@ pub fn set_transient_vertex_buffer_with_layout(
 /// Number of vertices.
 pub fn set_vertex_count(num_vertices: u32) {
     unsafe {
-        (*g_vtbl.bgfx_set_vertex_count).unwrap()(num_vertices);
+        vtbl().set_vertex_count.unwrap()(num_vertices);
     }
 }
 /// * `idb`:
@@ -5393,7 +5884,7 @@ pub fn set_vertex_count(num_vertices: u32) {
 pub fn set_instance_data_buffer(idb: &InstanceDataBuffer, start: u32, num: u32) {
     unsafe {
         let _idb = std::mem::transmute(idb);
-        (*g_vtbl.bgfx_set_instance_data_buffer).unwrap()(_idb, start, num);
+        vtbl().set_instance_data_buffer.unwrap()(_idb, start, num);
     }
 }
 /// * `handle`:
@@ -5406,7 +5897,7 @@ pub fn set_instance_data_buffer(idb: &InstanceDataBuffer, start: u32, num: u32)
 /// Dynamic vertex buffer.
 pub fn set_instance_data_from_vertex_buffer(handle: &VertexBuffer, start_vertex: u32, num: u32) {
     unsafe {
-        (*g_vtbl.bgfx_set_instance_data_from_vertex_buffer).unwrap()(handle.handle, start_vertex, num);
+        vtbl().set_instance_data_from_vertex_buffer.unwrap()(handle.handle, start_vertex, num);
     }
 }
 /// * `handle`:
@@ -5421,7 +5912,7 @@ pub fn set_instance_data_from_dynamic_vertex_buffer(
     num: u32,
 ) {
     unsafe {
-        bgfx_sys::bgfx_set_instance_data_from_dynamic_vertex_buffer(
+        vtbl().set_instance_data_from_dynamic_vertex_buffer.unwrap()(
             handle.handle,
             start_vertex,
             num,
@@ -5431,7 +5922,7 @@ pub fn set_instance_data_from_dynamic_vertex_buffer(
 /// * `num_instances`:
 pub fn set_instance_count(num_instances: u32) {
     unsafe {
-        (*g_vtbl.bgfx_set_instance_count).unwrap()(num_instances);
+        vtbl().set_instance_count.unwrap()(num_instances);
     }
 }
 /// * `stage`:
@@ -5441,22 +5932,22 @@ pub fn set_instance_count(num_instances: u32) {
 /// * `handle`:
 /// Texture handle.
 /// * `flags`:
-/// Texture sampling mode. Default value UINT32_MAX uses
+/// Texture sampling mode, see [Sampler]. [Sampler::Inherit] uses the
 ///   texture sampling settings from the texture.
 ///   - [SamplerFlags::[U/V/W]_[MIRROR/CLAMP]] - Mirror or clamp to edge wrap
 ///     mode.
 ///   - [SamplerFlags::[MIN/MAG/MIP]_[POINT/ANISOTROPIC]] - Point or anisotropic
 ///     sampling.
-pub fn set_texture(stage: u8, sampler: &Uniform, handle: &Texture, flags: u32) {
+pub fn set_texture(stage: u8, sampler: &Uniform, handle: &Texture, flags: impl Into<Sampler>) {
     unsafe {
-        (*g_vtbl.bgfx_set_texture).unwrap()(stage, sampler.handle, handle.handle, flags);
+        vtbl().set_texture.unwrap()(stage, sampler.handle, handle.handle, flags.into().to_bits());
     }
 }
 /// * `id`:
 /// View id.
 pub fn touch(id: ViewId) {
     unsafe {
-        (*g_vtbl.bgfx_touch).unwrap()(id);
+        vtbl().touch.unwrap()(id);
     }
 }
 /// * `id`:
@@ -5469,7 +5960,7 @@ pub fn touch(id: ViewId) {
 /// Which states to discard for next draw. See [DiscardFlags].
 pub fn submit(id: ViewId, program: &Program, params: SubmitArgs) {
     unsafe {
-        (*g_vtbl.bgfx_submit).unwrap()(id, program.handle, params.depth, params.flags);
+        vtbl().submit.unwrap()(id, program.handle, params.depth, params.flags.bits());
     }
 }
 /// * `id`:
@@ -5489,14 +5980,15 @@ pub fn submit_occlusion_query(
     params: SubmitOcclusionQueryArgs,
 ) {
     unsafe {
-        bgfx_sys::bgfx_submit_occlusion_query(
+        vtbl().submit_occlusion_query.unwrap()(
             id,
             program.handle,
             occlusion_query.handle,
             params.depth,
-            params.flags,
+            params.flags.bits(),
         );
     }
+    crate::pending::query_submitted(occlusion_query.handle.idx);
 }
 /// * `id`:
 /// View id.
@@ -5519,14 +6011,14 @@ pub fn submit_indirect(
     params: SubmitIndirectArgs,
 ) {
     unsafe {
-        bgfx_sys::bgfx_submit_indirect(
+        vtbl().submit_indirect.unwrap()(
             id,
             program.handle,
             indirect_handle.handle,
             params.start,
             params.num,
             params.depth,
-            params.flags,
+            params.flags.bits(),
         );
     }
 }
@@ -5558,7 +6050,7 @@ pub fn submit_indirect_count(
     params: SubmitIndirectCountArgs,
 ) {
     unsafe {
-        bgfx_sys::bgfx_submit_indirect_count(
+        vtbl().submit_indirect_count.unwrap()(
             id,
             program.handle,
             indirect_handle.handle,
@@ -5567,7 +6059,7 @@ pub fn submit_indirect_count(
             params.num_index,
             params.num_max,
             params.depth,
-            params.flags,
+            params.flags.bits(),
         );
     }
 }
@@ -5579,7 +6071,7 @@ pub fn submit_indirect_count(
 /// Buffer access. See [Access].
 pub fn set_compute_index_buffer(stage: u8, handle: &IndexBuffer, access: Access) {
     unsafe {
-        (*g_vtbl.bgfx_set_compute_index_buffer).unwrap()(stage, handle.handle, access as _);
+        vtbl().set_compute_index_buffer.unwrap()(stage, handle.handle, access as _);
     }
 }
 /// * `stage`:
@@ -5590,7 +6082,7 @@ pub fn set_compute_index_buffer(stage: u8, handle: &IndexBuffer, access: Access)
 /// Buffer access. See [Access].
 pub fn set_compute_vertex_buffer(stage: u8, handle: &VertexBuffer, access: Access) {
     unsafe {
-        (*g_vtbl.bgfx_set_compute_vertex_buffer).unwrap()(stage, handle.handle, access as _);
+        vtbl().set_compute_vertex_buffer.unwrap()(stage, handle.handle, access as _);
     }
 }
 /// * `stage`:
@@ -5601,7 +6093,7 @@ pub fn set_compute_vertex_buffer(stage: u8, handle: &VertexBuffer, access: Acces
 /// Buffer access. See [Access].
 pub fn set_compute_dynamic_index_buffer(stage: u8, handle: &DynamicIndexBuffer, access: Access) {
     unsafe {
-        (*g_vtbl.bgfx_set_compute_dynamic_index_buffer).unwrap()(stage, handle.handle, access as _);
+        vtbl().set_compute_dynamic_index_buffer.unwrap()(stage, handle.handle, access as _);
     }
 }
 /// * `stage`:
@@ -5612,7 +6104,7 @@ pub fn set_compute_dynamic_index_buffer(stage: u8, handle: &DynamicIndexBuffer,
 /// Buffer access. See [Access].
 pub fn set_compute_dynamic_vertex_buffer(stage: u8, handle: &DynamicVertexBuffer, access: Access) {
     unsafe {
-        (*g_vtbl.bgfx_set_compute_dynamic_vertex_buffer).unwrap()(stage, handle.handle, access as _);
+        vtbl().set_compute_dynamic_vertex_buffer.unwrap()(stage, handle.handle, access as _);
     }
 }
 /// * `stage`:
@@ -5623,7 +6115,7 @@ pub fn set_compute_dynamic_vertex_buffer(stage: u8, handle: &DynamicVertexBuffer
 /// Buffer access. See [Access].
 pub fn set_compute_indirect_buffer(stage: u8, handle: &IndirectBuffer, access: Access) {
     unsafe {
-        (*g_vtbl.bgfx_set_compute_indirect_buffer).unwrap()(stage, handle.handle, access as _);
+        vtbl().set_compute_indirect_buffer.unwrap()(stage, handle.handle, access as _);
     }
 }
 /// * `stage`:
@@ -5638,7 +6130,7 @@ pub fn set_compute_indirect_buffer(stage: u8, handle: &IndirectBuffer, access: A
 /// Texture format. See: [TextureFormat].
 pub fn set_image(stage: u8, handle: &Texture, mip: u8, access: Access, format: TextureFormat) {
     unsafe {
-        (*g_vtbl.bgfx_set_image).unwrap()(stage, handle.handle, mip, access as _, format as _);
+        vtbl().set_image.unwrap()(stage, handle.handle, mip, access as _, format as _);
     }
 }
 /// * `id`:
@@ -5655,13 +6147,13 @@ pub fn set_image(stage: u8, handle: &Texture, mip: u8, access: Access, format: T
 /// Discard or preserve states. See [DiscardFlags].
 pub fn dispatch(id: ViewId, program: &Program, params: DispatchArgs) {
     unsafe {
-        bgfx_sys::bgfx_dispatch(
+        vtbl().dispatch.unwrap()(
             id,
             program.handle,
             params.num_x,
             params.num_y,
             params.num_z,
-            params.flags,
+            params.flags.bits(),
         );
     }
 }
@@ -5684,21 +6176,21 @@ pub fn dispatch_indirect(
     params: DispatchIndirectArgs,
 ) {
     unsafe {
-        bgfx_sys::bgfx_dispatch_indirect(
+        vtbl().dispatch_indirect.unwrap()(
             id,
             program.handle,
             indirect_handle.handle,
             params.start,
             params.num,
-            params.flags,
+            params.flags.bits(),
         );
     }
 }
 /// * `flags`:
 /// Draw/compute states to discard.
-pub fn discard(flags: u8) {
+pub fn discard(flags: DiscardFlags) {
     unsafe {
-        (*g_vtbl.bgfx_discard).unwrap()(flags);
+        vtbl().discard.unwrap()(flags.bits());
     }
 }
 /// * `id`:
@@ -5745,7 +6237,7 @@ pub fn blit(
     params: BlitArgs,
 ) {
     unsafe {
-        bgfx_sys::bgfx_blit(
+        vtbl().blit.unwrap()(
             id,
             dst.handle,
             dst_mip,
@@ -5766,6 +6258,306 @@ pub fn blit(
 
 pub type ViewId = u16;
 
+/// Sets the palette color at `index`, from 0 to 15. Frame buffer attachments are cleared with
+/// palette colors by [set_view_clear_mrt]. See [Palette] to keep track of the used indices.
+///
+/// Panics if `index` is not less than [Palette::SIZE].
+pub fn set_palette_color(index: u8, color: Color) {
+    assert!(index < Palette::SIZE, "palette index must be less than 16");
+    unsafe {
+        vtbl().set_palette_color_rgba8.unwrap()(index, color.to_rgba32());
+    }
+}
+
+pub use crate::allocator::{Allocator, GlobalAllocAdapter};
+pub use crate::buffer::{Index, Pod, Transient, TypedIndexBuffer, TypedVertexBuffer};
+pub use crate::callback::Callback;
+pub use crate::color::{linear_to_srgb, srgb_to_linear, Color, Palette};
+pub use crate::context::{Bgfx, InitError};
+pub use crate::debug_draw::DebugDraw;
+#[cfg(feature = "egui")]
+pub use crate::egui_painter::EguiPainter;
+pub use crate::error::{BgfxError, CapsLimit};
+pub use crate::headless::{Headless, RgbaImage};
+#[cfg(feature = "imgui")]
+pub use crate::imgui_renderer::ImguiRenderer;
+pub use crate::pending::{OcclusionResult, OcclusionStatus, PendingRead};
+pub use crate::render_state::{
+    Blend, BlendEquation, BlendFactor, Cull, DepthTest, Primitive, RenderState, TargetBlend,
+};
+pub use crate::sampler::{AddressMode, CompareFunc, Filter, MipFilter, Sampler, SamplerDesc};
+pub use crate::shader::{
+    shader_dir, EmbeddedShader, ShaderBinary, ShaderBundle, ShaderBundleError, ShaderCodeFormat,
+    ShaderError, ShaderKind, ShaderUniform,
+};
+pub use crate::stencil_state::{StencilOp, StencilState, StencilTest};
+#[doc(hidden)]
+pub use crate::vertex::attrib_size as vertex_attrib_size;
+#[doc(hidden)]
+pub use crate::vertex::attrib_size_varies as vertex_attrib_size_varies;
+pub use crate::vertex::{Vertex, VertexAttribute};
+#[cfg(feature = "raw-window-handle")]
+pub use crate::window::WindowHandleError;
+#[cfg(feature = "derive")]
+pub use bgfx_rs_derive::Vertex;
+
+impl DynamicIndexBuffer {
+    /// Returns `false` if bgfx failed to create the dynamic index buffer.
+    pub fn is_valid(&self) -> bool {
+        self.handle.idx != u16::MAX
+    }
+}
+
+impl DynamicVertexBuffer {
+    /// Returns `false` if bgfx failed to create the dynamic vertex buffer.
+    pub fn is_valid(&self) -> bool {
+        self.handle.idx != u16::MAX
+    }
+}
+
+impl FrameBuffer {
+    /// Returns `false` if bgfx failed to create the frame buffer.
+    pub fn is_valid(&self) -> bool {
+        self.handle.idx != u16::MAX
+    }
+
+    /// Creates a frame buffer rendering into `textures`, such as a color and a depth texture.
+    /// The textures stay owned by the caller, so they are not destroyed with the frame buffer.
+    pub fn create_frame_buffer_from_textures(textures: &[&Texture]) -> FrameBuffer {
+        let session = crate::context::creation_session();
+        let handles: Vec<_> = textures.iter().map(|texture| texture.handle).collect();
+        unsafe {
+            let _ret = vtbl().create_frame_buffer_from_handles.unwrap()(
+                handles.len() as u8,
+                handles.as_ptr(),
+                false,
+            );
+            FrameBuffer {
+                handle: _ret,
+                session,
+            }
+        }
+    }
+}
+
+impl IndexBuffer {
+    /// Returns `false` if bgfx failed to create the index buffer.
+    pub fn is_valid(&self) -> bool {
+        self.handle.idx != u16::MAX
+    }
+}
+
+impl IndirectBuffer {
+    /// Returns `false` if bgfx failed to create the indirect buffer.
+    pub fn is_valid(&self) -> bool {
+        self.handle.idx != u16::MAX
+    }
+}
+
+impl OcclusionQuery {
+    /// Returns `false` if bgfx failed to create the occlusion query.
+    pub fn is_valid(&self) -> bool {
+        self.handle.idx != u16::MAX
+    }
+
+    /// Returns the result of the last submitted query once `frame` has returned the frame its
+    /// result is available in, like a [PendingRead].
+    pub fn poll(&self) -> OcclusionStatus {
+        if !crate::context::is_current(self.session) || !crate::pending::query_done(self.handle.idx)
+        {
+            return OcclusionStatus::Pending;
+        }
+        let mut num_pixels = 0;
+        match self.get_result(&mut num_pixels) {
+            OcclusionQueryResult::Invisible => OcclusionStatus::Ready(OcclusionResult {
+                visible: false,
+                num_pixels,
+            }),
+            OcclusionQueryResult::Visible => OcclusionStatus::Ready(OcclusionResult {
+                visible: true,
+                num_pixels,
+            }),
+            _ => OcclusionStatus::Pending,
+        }
+    }
+}
+
+impl Program {
+    /// Returns `false` if bgfx failed to create the program.
+    pub fn is_valid(&self) -> bool {
+        self.handle.idx != u16::MAX
+    }
+}
+
+impl Shader {
+    /// Returns `false` if bgfx failed to create the shader.
+    pub fn is_valid(&self) -> bool {
+        self.handle.idx != u16::MAX
+    }
+}
+
+impl Texture {
+    /// Returns `false` if bgfx failed to create the texture.
+    pub fn is_valid(&self) -> bool {
+        self.handle.idx != u16::MAX
+    }
+
+    /// Reads back the contents of mip level `mip`, into a buffer sized for the mip level from
+    /// the size and format the texture was created with. The texture must have been created
+    /// with [TextureFlags::READ_BACK], and the renderer must support
+    /// [CapsFlags::TEXTURE_READ_BACK].
+    ///
+    /// Returns `None` if `mip` is not a mip level of the texture, or if the size of the texture
+    /// isn't known, as for [Texture::create_texture_2d_scaled] and [FrameBuffer::get_texture].
+    /// Use [Texture::read_with_size] for those.
+    pub fn read(&self, mip: u8) -> Option<PendingRead> {
+        let size = self.size?.mip_size(mip)?;
+        Some(unsafe { self.read_with_size(mip, size) })
+    }
+
+    /// Reads back the contents of mip level `mip` into a buffer of `size` bytes. See
+    /// [Texture::read].
+    ///
+    /// # Safety
+    ///
+    /// bgfx writes the whole mip level to the buffer, so `size` must be at least the size of
+    /// the mip level in bytes, including all layers and cube map sides.
+    pub unsafe fn read_with_size(&self, mip: u8, size: usize) -> PendingRead {
+        let mut data = vec![0u8; size].into_boxed_slice();
+        let _ret = vtbl().read_texture.unwrap()(self.handle, data.as_mut_ptr() as _, mip);
+        PendingRead::new(data, _ret)
+    }
+}
+
+/// Size and format a texture was created with, to size the buffers of [Texture::read].
+#[derive(Clone, Copy, Debug)]
+struct TextureSize {
+    width: u16,
+    height: u16,
+    depth: u16,
+    num_layers: u16,
+    num_mips: u8,
+    cube_map: bool,
+    format: TextureFormat,
+}
+
+impl TextureSize {
+    fn new(
+        width: u16,
+        height: u16,
+        depth: u16,
+        num_layers: u16,
+        has_mips: bool,
+        cube_map: bool,
+        format: TextureFormat,
+    ) -> TextureSize {
+        let largest = width.max(height).max(depth).max(1);
+        TextureSize {
+            width,
+            height,
+            depth,
+            num_layers: num_layers.max(1),
+            num_mips: if has_mips {
+                (16 - largest.leading_zeros()) as u8
+            } else {
+                1
+            },
+            cube_map,
+            format,
+        }
+    }
+
+    /// Size parsed by [Texture::create_texture], or `None` if the texture data couldn't be
+    /// parsed.
+    fn from_info(info: &TextureInfo) -> Option<TextureSize> {
+        if info.storage_size == 0 {
+            return None;
+        }
+        Some(TextureSize {
+            width: info.width,
+            height: info.height,
+            depth: info.depth,
+            num_layers: info.num_layers.max(1),
+            num_mips: info.num_mips.max(1),
+            cube_map: info.cube_map,
+            format: info.format,
+        })
+    }
+
+    /// Returns the size of mip level `mip` in bytes, including all layers and cube map sides.
+    fn mip_size(&self, mip: u8) -> Option<usize> {
+        if mip >= self.num_mips {
+            return None;
+        }
+        let mut info = TextureInfo::new();
+        calc_texture_size(
+            &mut info,
+            (self.width >> mip).max(1),
+            (self.height >> mip).max(1),
+            (self.depth >> mip).max(1),
+            self.cube_map,
+            false,
+            self.num_layers,
+            self.format,
+        );
+        Some(info.storage_size as usize)
+    }
+}
+
+impl Uniform {
+    /// Returns `false` if bgfx failed to create the uniform.
+    pub fn is_valid(&self) -> bool {
+        self.handle.idx != u16::MAX
+    }
+}
+
+impl VertexBuffer {
+    /// Returns `false` if bgfx failed to create the vertex buffer.
+    pub fn is_valid(&self) -> bool {
+        self.handle.idx != u16::MAX
+    }
+}
+
+impl VertexLayout {
+    /// Returns `false` if bgfx failed to create the vertex layout.
+    pub fn is_valid(&self) -> bool {
+        self.handle.idx != u16::MAX
+    }
+}
+
+impl Init {
+    /// Installs a Rust [Callback] for bgfx to report fatal errors, traces, profiler events,
+    /// shader cache requests, screenshots and video capture to.
+    ///
+    /// The callback is shared between this `Init` and bgfx while bgfx is initialized with it,
+    /// and dropped once both are done with it: when this `Init` is dropped or gets another
+    /// callback, and after [shutdown]. The same `Init` can be used for another [init] after
+    /// [shutdown].
+    pub fn set_callback(&mut self, callback: Box<dyn Callback>) {
+        crate::callback::INTERFACES.release(self.callback);
+        self.callback = crate::callback::into_raw(callback);
+    }
+
+    /// Installs a Rust [Allocator] that bgfx uses for all of its internal allocations. Use
+    /// [GlobalAllocAdapter] to forward to a [std::alloc::GlobalAlloc].
+    ///
+    /// The allocator is shared between this `Init` and bgfx while bgfx is initialized with it,
+    /// and dropped once both are done with it: when this `Init` is dropped or gets another
+    /// allocator, and after [shutdown] has released all of the memory of bgfx. The same `Init`
+    /// can be used for another [init] after [shutdown].
+    pub fn set_allocator(&mut self, allocator: Box<dyn Allocator>) {
+        crate::allocator::INTERFACES.release(self.allocator);
+        self.allocator = crate::allocator::into_raw(allocator);
+    }
+}
+
+impl Drop for Init {
+    fn drop(&mut self) {
+        crate::callback::INTERFACES.release(self.callback);
+        crate::allocator::INTERFACES.release(self.allocator);
+    }
+}
+
 pub struct BuiltVertexLayout(VertexLayoutBuilder);
 
 impl VertexLayoutBuilder {
@@ -5776,7 +6568,7 @@ impl VertexLayoutBuilder {
         let t = unsafe { t.assume_init() };
         unsafe {
             let _t = std::mem::transmute(&t);
-            let _ret = (*g_vtbl.bgfx_vertex_layout_begin).unwrap()(_t, renderer_type as _);
+            let _ret = vtbl().vertex_layout_begin.unwrap()(_t, renderer_type as _);
         }
         t
     }
@@ -5799,7 +6591,7 @@ impl VertexLayoutBuilder {
     pub fn add(mut self, attrib: Attrib, num: u8, type_r: AttribType, params: AddArgs) -> Self {
         unsafe {
             let _self = std::mem::transmute(&mut self);
-            let _ret = bgfx_sys::bgfx_vertex_layout_add(
+            let _ret = vtbl().vertex_layout_add.unwrap()(
                 _self,
                 attrib as _,
                 num,
@@ -5815,7 +6607,7 @@ impl VertexLayoutBuilder {
     pub fn has(&self, attrib: Attrib) -> bool {
         unsafe {
             let _self = std::mem::transmute(self);
-            let _ret = (*g_vtbl.bgfx_vertex_layout_has).unwrap()(_self, attrib as _);
+            let _ret = vtbl().vertex_layout_has.unwrap()(_self, attrib as _);
             _ret
         }
     }
@@ -5824,27 +6616,245 @@ impl VertexLayoutBuilder {
     pub fn skip(mut self, num: u8) -> Self {
         unsafe {
             let _self = std::mem::transmute(&mut self);
-            let _ret = (*g_vtbl.bgfx_vertex_layout_skip).unwrap()(_self, num);
+            let _ret = vtbl().vertex_layout_skip.unwrap()(_self, num);
             self
         }
     }
     pub fn end(mut self) -> BuiltVertexLayout {
         unsafe {
             let _self = std::mem::transmute(&mut self);
-            (*g_vtbl.bgfx_vertex_layout_end).unwrap()(_self);
+            vtbl().vertex_layout_end.unwrap()(_self);
         }
         BuiltVertexLayout(self)
     }
 }
 
-/// Returns the number of uniforms and uniform handles used inside a shader.
-///
-/// Notice that only non-predefined uniforms are returned.
+impl BuiltVertexLayout {
+    /// Returns the size of a single vertex in bytes.
+    pub fn stride(&self) -> u16 {
+        self.0.stride
+    }
+
+    /// Returns `true` if the layout contains `attrib`.
+    pub fn has(&self, attrib: Attrib) -> bool {
+        attrib != Attrib::Count && self.0.attributes[attrib as usize] != u16::MAX
+    }
+
+    /// Returns the offset of `attrib` within a vertex, or `None` if the layout doesn't contain
+    /// it.
+    pub fn offset(&self, attrib: Attrib) -> Option<u16> {
+        if self.has(attrib) {
+            Some(self.0.offset[attrib as usize])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of whole vertices in `data`.
+    pub fn num_vertices(&self, data: &[u8]) -> u32 {
+        data.len().checked_div(self.0.stride as usize).unwrap_or(0) as u32
+    }
+
+    fn as_raw(&self) -> *const bgfx_sys::bgfx_vertex_layout_t {
+        &self.0 as *const VertexLayoutBuilder as *const _
+    }
+
+    fn check_index(&self, data: &[u8], index: u32) {
+        assert!(
+            index < self.num_vertices(data),
+            "vertex index {} out of range for {} bytes of vertex data",
+            index,
+            data.len()
+        );
+    }
+
+    /// Packs `input` into `attrib` of vertex `index` in `data`, converting it to the attribute
+    /// type of the layout. Does nothing if the layout doesn't contain `attrib`.
+    ///
+    /// Set `normalized` if the input values are normalized (in range -1.0 to 1.0) and should be
+    /// packed as such into attributes of a fixed point type.
+    ///
+    /// Panics if `data` doesn't hold vertex `index`.
+    pub fn pack(
+        &self,
+        attrib: Attrib,
+        input: &[f32; 4],
+        normalized: bool,
+        data: &mut [u8],
+        index: u32,
+    ) {
+        self.check_index(data, index);
+        if !self.has(attrib) {
+            return;
+        }
+        unsafe {
+            vtbl().vertex_pack.unwrap()(
+                input.as_ptr(),
+                normalized,
+                attrib as _,
+                self.as_raw(),
+                data.as_mut_ptr() as *mut c_void,
+                index,
+            );
+        }
+    }
+
+    /// Unpacks `attrib` of vertex `index` in `data` to floats. Components that are not part of
+    /// the attribute, or all of them if the layout doesn't contain `attrib`, are 0.0.
+    ///
+    /// [AttribType::Uint8] and [AttribType::Int16] attributes always unpack to normalized
+    /// values: in range 0.0 to 1.0, or -1.0 to 1.0 if they were added with [AddArgs::as_int].
+    ///
+    /// Panics if `data` doesn't hold vertex `index`.
+    pub fn unpack(&self, attrib: Attrib, data: &[u8], index: u32) -> [f32; 4] {
+        self.check_index(data, index);
+        let mut output = [0.0; 4];
+        if !self.has(attrib) {
+            return output;
+        }
+        unsafe {
+            vtbl().vertex_unpack.unwrap()(
+                output.as_mut_ptr(),
+                attrib as _,
+                self.as_raw(),
+                data.as_ptr() as *const c_void,
+                index,
+            );
+        }
+        output
+    }
+
+    /// Converts `data` from `src_layout` to this layout. Attributes that are missing from
+    /// `src_layout` are zeroed.
+    pub fn convert(&self, src_layout: &BuiltVertexLayout, data: &[u8]) -> Vec<u8> {
+        let num = src_layout.num_vertices(data);
+        let mut output = vec![0u8; num as usize * self.0.stride as usize];
+        unsafe {
+            vtbl().vertex_convert.unwrap()(
+                self.as_raw(),
+                output.as_mut_ptr() as *mut c_void,
+                src_layout.as_raw(),
+                data.as_ptr() as *const c_void,
+                num,
+            );
+        }
+        output
+    }
+
+    /// Finds vertices in `data` with the same position, within a distance of `epsilon`.
+    ///
+    /// Returns a remapping table with an entry for each vertex, holding the index of the vertex
+    /// it was welded to. Unique vertices are the ones that map to themselves, and each one is
+    /// the first vertex at its position.
+    ///
+    /// Unlike `bgfx::weldVertices` this doesn't use the bgfx allocator, so it can be called
+    /// without bgfx being initialized.
+    ///
+    /// Panics if the layout doesn't contain [Attrib::Position].
+    pub fn weld(&self, data: &[u8], epsilon: f32) -> Vec<u32> {
+        use std::collections::HashMap;
+
+        assert!(
+            self.has(Attrib::Position),
+            "vertex layout has no position to weld"
+        );
+        let num = self.num_vertices(data);
+        let epsilon_sq = epsilon * epsilon;
+        // Positions are bucketed into cells of `epsilon` size, so matches can only be in the
+        // same or a neighbouring cell. Without an epsilon only exact matches are welded.
+        let cell = |pos: &[f32; 4]| -> [i64; 3] {
+            let mut key = [0; 3];
+            for (k, p) in key.iter_mut().zip(pos) {
+                *k = if epsilon > 0.0 {
+                    (p / epsilon).floor() as i64
+                } else {
+                    // Adding 0.0 turns -0.0 into 0.0.
+                    (p + 0.0).to_bits() as i64
+                };
+            }
+            key
+        };
+        let range = if epsilon > 0.0 { -1..=1 } else { 0..=0 };
+
+        let mut cells: HashMap<[i64; 3], Vec<u32>> = HashMap::new();
+        let mut output = Vec::with_capacity(num as usize);
+        for index in 0..num {
+            let pos = self.unpack(Attrib::Position, data, index);
+            let key = cell(&pos);
+
+            let mut found = None;
+            'search: for x in range.clone() {
+                for y in range.clone() {
+                    for z in range.clone() {
+                        let neighbour = [
+                            key[0].wrapping_add(x),
+                            key[1].wrapping_add(y),
+                            key[2].wrapping_add(z),
+                        ];
+                        for &other in cells.get(&neighbour).into_iter().flatten() {
+                            let test = self.unpack(Attrib::Position, data, other);
+                            let dist_sq: f32 = (0..3).map(|i| (test[i] - pos[i]).powi(2)).sum();
+                            if dist_sq <= epsilon_sq {
+                                found = Some(other);
+                                break 'search;
+                            }
+                        }
+                    }
+                }
+            }
+
+            output.push(found.unwrap_or_else(|| {
+                cells.entry(key).or_default().push(index);
+                index
+            }));
+        }
+        output
+    }
+}
 
 impl Shader {
-    //pub fn get_uniforms(&self, uniforms: &mut [Uniform]) -> u16 {
-    //	unsafe { (*g_vtbl.bgfx_get_shader_uniforms).unwrap()(self.handle, uniforms.as_ptr(), uniforms.len() as u16) }
-    //}
+    /// Returns the uniforms used inside the shader, for example to bind material parameters
+    /// by name using [Uniform::info].
+    ///
+    /// Notice that only non-predefined uniforms are returned.
+    pub fn uniforms(&self) -> Vec<Uniform> {
+        unsafe {
+            let num = vtbl().get_shader_uniforms.unwrap()(self.handle, std::ptr::null_mut(), 0);
+            let mut handles = vec![bgfx_sys::bgfx_uniform_handle_t { idx: u16::MAX }; num as usize];
+            let num = vtbl().get_shader_uniforms.unwrap()(self.handle, handles.as_mut_ptr(), num);
+            handles.truncate(num as usize);
+            handles
+                .into_iter()
+                .map(|handle| {
+                    // The shader holds its own reference to the uniforms, so take another one
+                    // for the returned handle by creating the uniform again.
+                    let info = uniform_info(handle);
+                    Uniform::create(&info.name, info.type_r, info.num)
+                })
+                .collect()
+        }
+    }
+}
+
+impl Uniform {
+    /// Returns the name, type and number of elements of the uniform.
+    pub fn info(&self) -> UniformInfo {
+        uniform_info(self.handle)
+    }
+}
+
+fn uniform_info(handle: bgfx_sys::bgfx_uniform_handle_t) -> UniformInfo {
+    unsafe {
+        let mut info = MaybeUninit::<bgfx_sys::bgfx_uniform_info_t>::zeroed().assume_init();
+        vtbl().get_uniform_info.unwrap()(handle, &mut info);
+        UniformInfo {
+            name: std::ffi::CStr::from_ptr(info.name.as_ptr())
+                .to_string_lossy()
+                .into_owned(),
+            type_r: std::mem::transmute::<u32, UniformType>(info.type_),
+            num: info.num,
+        }
+    }
 }
 
 /// bgfx-managed buffer of memory.
@@ -5867,7 +6877,7 @@ impl Memory {
     #[inline]
     pub fn copy<T>(data: &[T]) -> Memory {
         unsafe {
-            let handle = bgfx_sys::bgfx_copy(
+            let handle = vtbl().copy.unwrap()(
                 data.as_ptr() as *const c_void,
                 std::mem::size_of_val(data) as u32,
             );
@@ -5883,12 +6893,22 @@ impl Memory {
     /// what you are doing. Thus this function is marked as unsafe because of this reason.
     #[inline]
     pub unsafe fn reference<T>(data: &[T]) -> Memory {
-        let handle = bgfx_sys::bgfx_make_ref(
+        let handle = vtbl().make_ref.unwrap()(
             data.as_ptr() as *const c_void,
             std::mem::size_of_val(data) as u32,
         );
         Memory { handle }
     }
+
+    /// Returns the data bgfx will consume, which is empty for [Memory::new].
+    pub(crate) fn as_slice(&self) -> &[u8] {
+        match unsafe { self.handle.as_ref() } {
+            Some(memory) if !memory.data.is_null() => unsafe {
+                std::slice::from_raw_parts(memory.data, memory.size as usize)
+            },
+            _ => &[],
+        }
+    }
 }
 
 /// * `x`:
@@ -5902,7 +6922,7 @@ impl Memory {
 pub fn dbg_text(x: u16, y: u16, attr: u8, text: &str) {
     unsafe {
         let c_text = std::ffi::CString::new(text).unwrap();
-        (*g_vtbl.bgfx_dbg_text_printf).unwrap()(x, y, attr, c_text.as_ptr());
+        vtbl().dbg_text_printf.unwrap()(x, y, attr, c_text.as_ptr());
     }
 }
 
@@ -5916,7 +6936,7 @@ pub fn set_view_transform(id: ViewId, view: &[f32; 16], proj: &[f32; 16]) {
     unsafe {
         let _view = std::mem::transmute(view);
         let _proj = std::mem::transmute(proj);
-        (*g_vtbl.bgfx_set_view_transform).unwrap()(id, _view, _proj);
+        vtbl().set_view_transform.unwrap()(id, _view, _proj);
     }
 }
 
@@ -5927,7 +6947,7 @@ pub fn set_view_transform(id: ViewId, view: &[f32; 16], proj: &[f32; 16]) {
 pub fn set_transform(mtx: &[f32; 16], num: u16) -> u32 {
     unsafe {
         let _mtx = std::mem::transmute(mtx);
-        (*g_vtbl.bgfx_set_transform).unwrap()(_mtx, num)
+        vtbl().set_transform.unwrap()(_mtx, num)
     }
 }
 
@@ -5938,7 +6958,87 @@ impl Encoder {
     pub fn set_uniform(&self, handle: &Uniform, value: &[f32], num: u16) {
         unsafe {
             let _self = std::mem::transmute(self);
-            (*g_vtbl.bgfx_encoder_set_uniform).unwrap()(_self, handle.handle, value.as_ptr() as _, num);
+            vtbl().encoder_set_uniform.unwrap()(_self, handle.handle, value.as_ptr() as _, num);
+        }
+    }
+}
+
+/// Token for the frame that is currently being submitted. Encoders and [Transient] buffers
+/// borrow it, so the frame can't be advanced with [Frame::frame] while any [EncoderGuard] is
+/// still open or transient buffer is still alive.
+pub struct Frame {
+    _not_send: std::marker::PhantomData<*const ()>,
+}
+
+// Encoders can be requested from any thread, only advancing the frame is tied to the API thread.
+unsafe impl Sync for Frame {}
+
+impl Frame {
+    /// Creates the frame token.
+    ///
+    /// # Safety
+    ///
+    /// bgfx must be initialized and this must be called on the API thread. Only one `Frame` may
+    /// exist at a time, and [frame] and [encoder_begin] must not be used while it's alive.
+    pub unsafe fn new() -> Frame {
+        Frame {
+            _not_send: std::marker::PhantomData,
+        }
+    }
+
+    /// Begins submitting draw calls through a dedicated encoder, which is ended when the
+    /// returned guard is dropped. The guard can be moved to a worker thread.
+    ///
+    /// Returns `None` when all encoders are in use. See: [InitLimits::max_encoders]
+    pub fn encoder(&self) -> Option<EncoderGuard<'_>> {
+        unsafe {
+            let _ret = vtbl().encoder_begin.unwrap()(true);
+            std::ptr::NonNull::new(_ret as *mut Encoder).map(|encoder| EncoderGuard {
+                encoder,
+                frame: self,
+            })
+        }
+    }
+
+    /// Advances to next frame and returns the current frame number. When `capture` is set the
+    /// frame is captured with the graphics debugger.
+    pub fn frame(&mut self, capture: bool) -> u32 {
+        // The frame is borrowed mutably, so nothing borrows it anymore.
+        #[allow(deprecated)]
+        unsafe {
+            frame(capture)
+        }
+    }
+}
+
+/// Encoder returned by [Frame::encoder]. Ends the encoder when dropped and derefs to [Encoder]
+/// for submitting draw calls.
+pub struct EncoderGuard<'frame> {
+    encoder: std::ptr::NonNull<Encoder>,
+    frame: &'frame Frame,
+}
+
+unsafe impl Send for EncoderGuard<'_> {}
+
+impl<'frame> EncoderGuard<'frame> {
+    /// Returns the frame the encoder submits to, for allocating [Transient] buffers.
+    pub fn frame(&self) -> &'frame Frame {
+        self.frame
+    }
+}
+
+impl std::ops::Deref for EncoderGuard<'_> {
+    type Target = Encoder;
+
+    fn deref(&self) -> &Encoder {
+        unsafe { self.encoder.as_ref() }
+    }
+}
+
+impl Drop for EncoderGuard<'_> {
+    fn drop(&mut self) {
+        unsafe {
+            vtbl().encoder_end.unwrap()(self.encoder.as_ptr() as _);
         }
     }
 }
@@ -5951,10 +7051,14 @@ impl Uniform {
     /// * `num`:
     /// Number of elements in array.
     pub fn create(name: &str, type_r: UniformType, num: u16) -> Uniform {
+        let session = crate::context::creation_session();
         unsafe {
             let name_ = CFixedString::from_str(name);
-            let _ret = (*g_vtbl.bgfx_create_uniform).unwrap()(name_ as _, type_r as _, num);
-            Uniform { handle: _ret }
+            let _ret = vtbl().create_uniform.unwrap()(name_.as_ptr(), type_r as _, num);
+            Uniform {
+                handle: _ret,
+                session,
+            }
         }
     }
 
@@ -5963,7 +7067,7 @@ impl Uniform {
     /// * `num`: Number of elements. Passing `u16::MAX` will use the _num passed on uniform creation.
     pub fn set_uniform(&self, value: &[f32], num: u16) {
         unsafe {
-            (*g_vtbl.bgfx_set_uniform).unwrap()(self.handle, value.as_ptr() as _, num);
+            vtbl().set_uniform.unwrap()(self.handle, value.as_ptr() as _, num);
         }
     }
 }
@@ -5975,10 +7079,14 @@ impl Uniform {
 /// * `num`:
 /// Number of elements in array.
 pub fn create_uniform(name: &str, type_r: UniformType, num: u16) -> Uniform {
+    let session = crate::context::creation_session();
     unsafe {
         let name_ = CFixedString::from_str(name);
-        let _ret = (*g_vtbl.bgfx_create_uniform).unwrap()(name_ as _, type_r as _, num);
-        Uniform { handle: _ret }
+        let _ret = vtbl().create_uniform.unwrap()(name_.as_ptr(), type_r as _, num);
+        Uniform {
+            handle: _ret,
+            session,
+        }
     }
 }
 
@@ -5987,7 +7095,7 @@ pub fn create_uniform(name: &str, type_r: UniformType, num: u16) -> Uniform {
 /// * `num`: Number of elements. Passing `u16::MAX` will use the _num passed on uniform creation.
 pub fn set_uniform(handle: &Uniform, value: &[f32], num: u16) {
     unsafe {
-        (*g_vtbl.bgfx_set_uniform).unwrap()(handle.handle, value.as_ptr() as _, num);
+        vtbl().set_uniform.unwrap()(handle.handle, value.as_ptr() as _, num);
     }
 }
 
@@ -6074,3 +7182,25 @@ pub fn state_blend_linear_burn() -> u64 {
     state_blend_func(StateBlendFlags::DST_COLOR, StateBlendFlags::INV_DST_COLOR)
         | state_blend_equation(StateBlendEquationFlags::SUB)
 }
+
+/// Converts flags that are combined with other flags into the type taking them, such as
+/// [TextureRtFlags] into [TextureFlags], keeping the bits as they are.
+macro_rules! impl_flags_from {
+    ($($from:ident => $to:ident,)*) => {
+        $(
+            impl From<$from> for $to {
+                fn from(flags: $from) -> $to {
+                    unsafe { $to::from_bits_unchecked(flags.bits()) }
+                }
+            }
+        )*
+    };
+}
+
+impl_flags_from! {
+    BufferComputeFormatFlags => BufferFlags,
+    BufferComputeTypeFlags => BufferFlags,
+    TextureRtFlags => TextureFlags,
+    TextureRtMsaaFlags => TextureFlags,
+    ResetMsaaFlags => ResetFlags,
+}
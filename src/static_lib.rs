@@ -184,16 +184,32 @@ pub enum TextureFormat {
     ATCI,
     /// ASTC 4x4 8.0 BPP
     ASTC4x4,
+    /// ASTC 5x4 6.40 BPP
+    ASTC5x4,
     /// ASTC 5x5 5.12 BPP
     ASTC5x5,
+    /// ASTC 6x5 4.27 BPP
+    ASTC6x5,
     /// ASTC 6x6 3.56 BPP
     ASTC6x6,
     /// ASTC 8x5 3.20 BPP
     ASTC8x5,
     /// ASTC 8x6 2.67 BPP
     ASTC8x6,
+    /// ASTC 8x8 2.00 BPP
+    ASTC8x8,
     /// ASTC 10x5 2.56 BPP
     ASTC10x5,
+    /// ASTC 10x6 2.13 BPP
+    ASTC10x6,
+    /// ASTC 10x8 1.60 BPP
+    ASTC10x8,
+    /// ASTC 10x10 1.28 BPP
+    ASTC10x10,
+    /// ASTC 12x10 1.07 BPP
+    ASTC12x10,
+    /// ASTC 12x12 0.89 BPP
+    ASTC12x12,
     /// Compressed formats above.
     Unknown,
     R1,
@@ -402,6 +418,18 @@ pub enum RenderFrame {
     Count,
 }
 
+/// Native window handle type.
+#[repr(u32)]
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum NativeWindowHandleType {
+    /// Platform default handle type (X11 on Linux).
+    Default,
+    /// Wayland.
+    Wayland,
+    /// Number of entries in the enum
+    Count,
+}
+
 bitflags! {
 /// Color RGB/alpha/depth write. When it's not specified write will be disabled.
     pub struct StateWriteFlags : u64 {
@@ -905,6 +933,7 @@ bitflags! {
 }
 
 bitflags! {
+    #[repr(transparent)]
     pub struct ResetFlags : u32 {
         /// No reset flags.
         const NONE = bgfx_sys::BGFX_RESET_NONE as _;
@@ -1042,6 +1071,7 @@ bitflags! {
 }
 
 bitflags! {
+    #[repr(transparent)]
     pub struct ResolveFlags : u8 {
         /// No resolve flags.
         const NONE = bgfx_sys::BGFX_RESOLVE_NONE as _;
@@ -1051,6 +1081,7 @@ bitflags! {
 }
 
 bitflags! {
+    #[repr(transparent)]
     pub struct PciIdFlags : u16 {
         /// Autoselect adapter.
         const NONE = bgfx_sys::BGFX_PCI_ID_NONE as _;
@@ -1091,61 +1122,74 @@ bitflags! {
 #[derive(Clone, Debug)]
 pub struct DynamicIndexBuffer {
     handle: bgfx_sys::bgfx_dynamic_index_buffer_handle_t,
+    session: u32,
 }
 
 #[derive(Clone, Debug)]
 pub struct DynamicVertexBuffer {
     handle: bgfx_sys::bgfx_dynamic_vertex_buffer_handle_t,
+    session: u32,
 }
 
 #[derive(Clone, Debug)]
 pub struct FrameBuffer {
     handle: bgfx_sys::bgfx_frame_buffer_handle_t,
+    session: u32,
 }
 
 #[derive(Clone, Debug)]
 pub struct IndexBuffer {
     handle: bgfx_sys::bgfx_index_buffer_handle_t,
+    session: u32,
 }
 
 #[derive(Clone, Debug)]
 pub struct IndirectBuffer {
     handle: bgfx_sys::bgfx_indirect_buffer_handle_t,
+    session: u32,
 }
 
 #[derive(Clone, Debug)]
 pub struct OcclusionQuery {
     handle: bgfx_sys::bgfx_occlusion_query_handle_t,
+    session: u32,
 }
 
 #[derive(Clone, Debug)]
 pub struct Program {
     handle: bgfx_sys::bgfx_program_handle_t,
+    session: u32,
 }
 
 #[derive(Clone, Debug)]
 pub struct Shader {
     handle: bgfx_sys::bgfx_shader_handle_t,
+    session: u32,
 }
 
 #[derive(Clone, Debug)]
 pub struct Texture {
     handle: bgfx_sys::bgfx_texture_handle_t,
+    session: u32,
+    size: Option<TextureSize>,
 }
 
 #[derive(Clone, Debug)]
 pub struct Uniform {
     handle: bgfx_sys::bgfx_uniform_handle_t,
+    session: u32,
 }
 
 #[derive(Clone, Debug)]
 pub struct VertexBuffer {
     handle: bgfx_sys::bgfx_vertex_buffer_handle_t,
+    session: u32,
 }
 
 #[derive(Clone, Debug)]
 pub struct VertexLayout {
     handle: bgfx_sys::bgfx_vertex_layout_handle_t,
+    session: u32,
 }
 
 pub struct InitArgs {
@@ -1158,7 +1202,7 @@ pub struct InitArgs {
     /// Mip level.
     pub mip: u16,
     /// Resolve flags. See: [ResolveFlags]
-    pub resolve: u8,
+    pub resolve: ResolveFlags,
 }
 
 impl Default for InitArgs {
@@ -1168,7 +1212,7 @@ impl Default for InitArgs {
             layer: 0,
             num_layers: 1,
             mip: 0,
-            resolve: ResolveFlags::AUTO_GEN_MIPS.bits(),
+            resolve: ResolveFlags::AUTO_GEN_MIPS,
         }
     }
 }
@@ -1207,7 +1251,7 @@ pub struct ResetArgs {
     ///     occurs. Default behaviour is that flip occurs before rendering new
     ///     frame. This flag only has effect when `BGFX_CONFIG_MULTITHREADED=0`.
     ///   - [ResetFlags::SRGB_BACKBUFFER] - Enable sRGB back-buffer.
-    pub flags: u32,
+    pub flags: ResetFlags,
     /// Texture format. See: [TextureFormat].
     pub format: TextureFormat,
 }
@@ -1215,7 +1259,7 @@ pub struct ResetArgs {
 impl Default for ResetArgs {
     fn default() -> ResetArgs {
         ResetArgs {
-            flags: ResetFlags::NONE.bits(),
+            flags: ResetFlags::NONE,
             format: TextureFormat::Count,
         }
     }
@@ -1244,7 +1288,8 @@ pub struct CreateTexture3DArgs {
     ///   mode.
     /// - [SamplerFlags::[MIN/MAG/MIP]_[POINT/ANISOTROPIC]] - Point or anisotropic
     ///   sampling.
-    pub flags: u64,
+    ///   A [SamplerDesc] converts to the sampler flags alone with `into()`.
+    pub flags: TextureFlags,
     /// Texture data. If `_mem` is non-NULL, created texture will be immutable. If
     /// `_mem` is NULL content of the texture is uninitialized. When `_numLayers` is more than
     /// 1, expected memory layout is texture and all mips together for each array element.
@@ -1254,7 +1299,7 @@ pub struct CreateTexture3DArgs {
 impl Default for CreateTexture3DArgs {
     fn default() -> CreateTexture3DArgs {
         CreateTexture3DArgs {
-            flags: TextureFlags::NONE.bits() as u64 | SamplerFlags::NONE.bits() as u64,
+            flags: TextureFlags::NONE,
             mem: None,
         }
     }
@@ -1267,7 +1312,8 @@ pub struct CreateTextureCubeArgs {
     ///   mode.
     /// - [SamplerFlags::[MIN/MAG/MIP]_[POINT/ANISOTROPIC]] - Point or anisotropic
     ///   sampling.
-    pub flags: u64,
+    ///   A [SamplerDesc] converts to the sampler flags alone with `into()`.
+    pub flags: TextureFlags,
     /// Texture data. If `_mem` is non-NULL, created texture will be immutable. If
     /// `_mem` is NULL content of the texture is uninitialized. When `_numLayers` is more than
     /// 1, expected memory layout is texture and all mips together for each array element.
@@ -1277,7 +1323,7 @@ pub struct CreateTextureCubeArgs {
 impl Default for CreateTextureCubeArgs {
     fn default() -> CreateTextureCubeArgs {
         CreateTextureCubeArgs {
-            flags: TextureFlags::NONE.bits() as u64 | SamplerFlags::NONE.bits() as u64,
+            flags: TextureFlags::NONE,
             mem: None,
         }
     }
@@ -1322,8 +1368,8 @@ impl Default for SetViewScissorArgs {
 }
 
 pub struct SetViewClearArgs {
-    /// Color clear value.
-    pub rgba: u32,
+    /// Color clear value. A `u32` packed as `0xRRGGBBAA` converts with `into()`.
+    pub rgba: Color,
     /// Depth clear value.
     pub depth: f32,
     /// Stencil clear value.
@@ -1333,7 +1379,7 @@ pub struct SetViewClearArgs {
 impl Default for SetViewClearArgs {
     fn default() -> SetViewClearArgs {
         SetViewClearArgs {
-            rgba: 0x000000ff,
+            rgba: Color::BLACK,
             depth: 1.0,
             stencil: 0,
         }
@@ -1378,14 +1424,14 @@ pub struct SubmitArgs {
     /// Depth for sorting.
     pub depth: u32,
     /// Discard or preserve states. See [DiscardFlags].
-    pub flags: u8,
+    pub flags: DiscardFlags,
 }
 
 impl Default for SubmitArgs {
     fn default() -> SubmitArgs {
         SubmitArgs {
             depth: 0,
-            flags: DiscardFlags::ALL.bits(),
+            flags: DiscardFlags::ALL,
         }
     }
 }
@@ -1394,14 +1440,14 @@ pub struct SubmitOcclusionQueryArgs {
     /// Depth for sorting.
     pub depth: u32,
     /// Discard or preserve states. See [DiscardFlags].
-    pub flags: u8,
+    pub flags: DiscardFlags,
 }
 
 impl Default for SubmitOcclusionQueryArgs {
     fn default() -> SubmitOcclusionQueryArgs {
         SubmitOcclusionQueryArgs {
             depth: 0,
-            flags: DiscardFlags::ALL.bits(),
+            flags: DiscardFlags::ALL,
         }
     }
 }
@@ -1414,7 +1460,7 @@ pub struct SubmitIndirectArgs {
     /// Depth for sorting.
     pub depth: u32,
     /// Discard or preserve states. See [DiscardFlags].
-    pub flags: u8,
+    pub flags: DiscardFlags,
 }
 
 impl Default for SubmitIndirectArgs {
@@ -1423,7 +1469,7 @@ impl Default for SubmitIndirectArgs {
             start: 0,
             num: 1,
             depth: 0,
-            flags: DiscardFlags::ALL.bits(),
+            flags: DiscardFlags::ALL,
         }
     }
 }
@@ -1436,7 +1482,7 @@ pub struct SubmitIndirectCountArgs {
     /// Depth for sorting.
     pub depth: u32,
     /// Discard or preserve states. See [DiscardFlags].
-    pub flags: u8,
+    pub flags: DiscardFlags,
 }
 
 impl Default for SubmitIndirectCountArgs {
@@ -1445,7 +1491,7 @@ impl Default for SubmitIndirectCountArgs {
             num_index: 0,
             num_max: std::u16::MAX,
             depth: 0,
-            flags: DiscardFlags::ALL.bits(),
+            flags: DiscardFlags::ALL,
         }
     }
 }
@@ -1458,7 +1504,7 @@ pub struct DispatchArgs {
     /// Number of groups Z.
     pub num_z: u32,
     /// Discard or preserve states. See [DiscardFlags].
-    pub flags: u8,
+    pub flags: DiscardFlags,
 }
 
 impl Default for DispatchArgs {
@@ -1467,7 +1513,7 @@ impl Default for DispatchArgs {
             num_x: 1,
             num_y: 1,
             num_z: 1,
-            flags: DiscardFlags::ALL.bits(),
+            flags: DiscardFlags::ALL,
         }
     }
 }
@@ -1478,7 +1524,7 @@ pub struct DispatchIndirectArgs {
     /// Number of dispatches.
     pub num: u16,
     /// Discard or preserve states. See [DiscardFlags].
-    pub flags: u8,
+    pub flags: DiscardFlags,
 }
 
 impl Default for DispatchIndirectArgs {
@@ -1486,7 +1532,7 @@ impl Default for DispatchIndirectArgs {
         DispatchIndirectArgs {
             start: 0,
             num: 1,
-            flags: DiscardFlags::ALL.bits(),
+            flags: DiscardFlags::ALL,
         }
     }
 }
@@ -1657,6 +1703,9 @@ pub struct PlatformData {
     /// Backbuffer depth/stencil. If `NULL`, bgfx will create a back-buffer
     /// depth/stencil surface.
     pub back_buffer_ds: *const c_void,
+    /// Handle type. Needed for platforms having more than one option.
+    /// See: `bgfx::NativeWindowHandleType`
+    pub type_r: NativeWindowHandleType,
 }
 /// Backbuffer resolution and reset parameters.
 #[repr(C)]
@@ -1667,8 +1716,8 @@ pub struct Resolution {
     pub width: u32,
     /// Backbuffer height.
     pub height: u32,
-    /// Reset parameters.
-    pub reset: u32,
+    /// Reset parameters. See: [ResetFlags]
+    pub reset: ResetFlags,
     /// Number of back buffers.
     pub num_back_buffers: u8,
     /// Maximum frame latency.
@@ -1702,7 +1751,7 @@ pub struct Init {
     ///   - [PciIdFlags::INTEL] - Intel adapter.
     ///   - [PciIdFlags::NVIDIA] - NVIDIA adapter.
     ///   - [PciIdFlags::MICROSOFT] - Microsoft adapter.
-    pub vendor_id: u16,
+    pub vendor_id: PciIdFlags,
     /// Device ID. If set to 0 it will select first device, or device with
     /// matching ID.
     pub device_id: u16,
@@ -1719,11 +1768,12 @@ pub struct Init {
     /// Configurable runtime limits parameters.
     pub limits: InitLimits,
     /// Provide application specific callback interface.
-    /// See: `bgfx::uint8_t`
+    /// See: [Init::set_callback]
     pub callback: *const u8,
     /// Custom allocator. When a custom allocator is not
     /// specified, bgfx uses the CRT allocator. Bgfx assumes
     /// custom allocator is thread safe.
+    /// See: [Init::set_allocator]
     pub allocator: *const u8,
 }
 /// Transient index buffer.
@@ -1795,10 +1845,10 @@ pub struct TextureInfo {
     pub cube_map: bool,
 }
 /// Uniform info.
-#[repr(C)]
+#[derive(Clone, PartialEq, Debug)]
 pub struct UniformInfo {
     /// Uniform name.
-    pub name: [i8; 256usize],
+    pub name: String,
     /// Uniform type.
     pub type_r: UniformType,
     /// Number of elements in array.
@@ -1818,7 +1868,7 @@ pub struct Attachment {
     /// Number of texture layer/slice(s) in array to use.
     pub num_layers: u16,
     /// Resolve flags. See: [ResolveFlags]
-    pub resolve: u8,
+    pub resolve: ResolveFlags,
 }
 /// Transform data.
 #[repr(C)]
@@ -1954,9 +2004,11 @@ pub struct VertexLayoutBuilder {
     pub attributes: [u16; 18usize],
 }
 /// Encoders are used for submitting draw calls from multiple threads. Only one encoder
-/// per thread should be used. Use `bgfx::begin()` to obtain an encoder for a thread.
+/// per thread should be used. Use [Frame::encoder] to obtain an encoder for a thread.
 #[repr(C)]
-pub struct Encoder {}
+pub struct Encoder {
+    _not_sync: std::marker::PhantomData<std::cell::Cell<()>>,
+}
 impl DynamicIndexBuffer {
     /// * `num`:
     /// Number of indices.
@@ -1973,10 +2025,14 @@ impl DynamicIndexBuffer {
     ///       buffers.
     ///   - [BufferFlags::INDEX32] - Buffer is using 32-bit indices. This flag has effect only on
     ///       index buffers.
-    pub fn create_dynamic_index_buffer(num: u32, flags: u16) -> DynamicIndexBuffer {
+    pub fn create_dynamic_index_buffer(num: u32, flags: BufferFlags) -> DynamicIndexBuffer {
+        let session = crate::context::creation_session();
         unsafe {
-            let _ret = bgfx_sys::bgfx_create_dynamic_index_buffer(num, flags);
-            DynamicIndexBuffer { handle: _ret }
+            let _ret = bgfx_sys::bgfx_create_dynamic_index_buffer(num, flags.bits());
+            DynamicIndexBuffer {
+                handle: _ret,
+                session,
+            }
         }
     }
     /// * `mem`:
@@ -1994,10 +2050,14 @@ impl DynamicIndexBuffer {
     ///       buffers.
     ///   - [BufferFlags::INDEX32] - Buffer is using 32-bit indices. This flag has effect only on
     ///       index buffers.
-    pub fn create_dynamic_index_buffer_mem(mem: &Memory, flags: u16) -> DynamicIndexBuffer {
+    pub fn create_dynamic_index_buffer_mem(mem: &Memory, flags: BufferFlags) -> DynamicIndexBuffer {
+        let session = crate::context::creation_session();
         unsafe {
-            let _ret = bgfx_sys::bgfx_create_dynamic_index_buffer_mem(mem.handle, flags);
-            DynamicIndexBuffer { handle: _ret }
+            let _ret = bgfx_sys::bgfx_create_dynamic_index_buffer_mem(mem.handle, flags.bits());
+            DynamicIndexBuffer {
+                handle: _ret,
+                session,
+            }
         }
     }
     /// * `handle`:
@@ -2026,8 +2086,10 @@ impl DynamicIndexBuffer {
 
 impl Drop for DynamicIndexBuffer {
     fn drop(&mut self) {
-        unsafe {
-            bgfx_sys::bgfx_destroy_dynamic_index_buffer(self.handle);
+        if self.is_valid() && crate::context::is_current(self.session) {
+            unsafe {
+                bgfx_sys::bgfx_destroy_dynamic_index_buffer(self.handle);
+            }
         }
     }
 }
@@ -2053,12 +2115,16 @@ impl DynamicVertexBuffer {
     pub fn create_dynamic_vertex_buffer(
         num: u32,
         layout: &BuiltVertexLayout,
-        flags: u16,
+        flags: BufferFlags,
     ) -> DynamicVertexBuffer {
+        let session = crate::context::creation_session();
         unsafe {
             let _layout = std::mem::transmute(layout);
-            let _ret = bgfx_sys::bgfx_create_dynamic_vertex_buffer(num, _layout, flags);
-            DynamicVertexBuffer { handle: _ret }
+            let _ret = bgfx_sys::bgfx_create_dynamic_vertex_buffer(num, _layout, flags.bits());
+            DynamicVertexBuffer {
+                handle: _ret,
+                session,
+            }
         }
     }
     /// * `mem`:
@@ -2081,12 +2147,17 @@ impl DynamicVertexBuffer {
     pub fn create_dynamic_vertex_buffer_mem(
         mem: &Memory,
         layout: &BuiltVertexLayout,
-        flags: u16,
+        flags: BufferFlags,
     ) -> DynamicVertexBuffer {
+        let session = crate::context::creation_session();
         unsafe {
             let _layout = std::mem::transmute(layout);
-            let _ret = bgfx_sys::bgfx_create_dynamic_vertex_buffer_mem(mem.handle, _layout, flags);
-            DynamicVertexBuffer { handle: _ret }
+            let _ret =
+                bgfx_sys::bgfx_create_dynamic_vertex_buffer_mem(mem.handle, _layout, flags.bits());
+            DynamicVertexBuffer {
+                handle: _ret,
+                session,
+            }
         }
     }
     /// * `handle`:
@@ -2119,8 +2190,10 @@ impl DynamicVertexBuffer {
 
 impl Drop for DynamicVertexBuffer {
     fn drop(&mut self) {
-        unsafe {
-            bgfx_sys::bgfx_destroy_dynamic_vertex_buffer(self.handle);
+        if self.is_valid() && crate::context::is_current(self.session) {
+            unsafe {
+                bgfx_sys::bgfx_destroy_dynamic_vertex_buffer(self.handle);
+            }
         }
     }
 }
@@ -2143,12 +2216,20 @@ impl FrameBuffer {
         width: u16,
         height: u16,
         format: TextureFormat,
-        texture_flags: u64,
+        texture_flags: TextureFlags,
     ) -> FrameBuffer {
+        let session = crate::context::creation_session();
         unsafe {
-            let _ret =
-                bgfx_sys::bgfx_create_frame_buffer(width, height, format as _, texture_flags);
-            FrameBuffer { handle: _ret }
+            let _ret = bgfx_sys::bgfx_create_frame_buffer(
+                width,
+                height,
+                format as _,
+                texture_flags.bits(),
+            );
+            FrameBuffer {
+                handle: _ret,
+                session,
+            }
         }
     }
     /// * `ratio`:
@@ -2166,12 +2247,19 @@ impl FrameBuffer {
     pub fn create_frame_buffer_scaled(
         ratio: BackbufferRatio,
         format: TextureFormat,
-        texture_flags: u64,
+        texture_flags: TextureFlags,
     ) -> FrameBuffer {
+        let session = crate::context::creation_session();
         unsafe {
-            let _ret =
-                bgfx_sys::bgfx_create_frame_buffer_scaled(ratio as _, format as _, texture_flags);
-            FrameBuffer { handle: _ret }
+            let _ret = bgfx_sys::bgfx_create_frame_buffer_scaled(
+                ratio as _,
+                format as _,
+                texture_flags.bits(),
+            );
+            FrameBuffer {
+                handle: _ret,
+                session,
+            }
         }
     }
     /// * `num`:
@@ -2186,13 +2274,17 @@ impl FrameBuffer {
         handles: &Texture,
         destroy_texture: bool,
     ) -> FrameBuffer {
+        let session = crate::context::creation_session();
         unsafe {
             let _ret = bgfx_sys::bgfx_create_frame_buffer_from_handles(
                 num,
                 &handles.handle,
                 destroy_texture,
             );
-            FrameBuffer { handle: _ret }
+            FrameBuffer {
+                handle: _ret,
+                session,
+            }
         }
     }
     /// * `num`:
@@ -2207,6 +2299,7 @@ impl FrameBuffer {
         attachment: &Attachment,
         destroy_texture: bool,
     ) -> FrameBuffer {
+        let session = crate::context::creation_session();
         unsafe {
             let _attachment = std::mem::transmute(attachment);
             let _ret = bgfx_sys::bgfx_create_frame_buffer_from_attachment(
@@ -2214,7 +2307,10 @@ impl FrameBuffer {
                 _attachment,
                 destroy_texture,
             );
-            FrameBuffer { handle: _ret }
+            FrameBuffer {
+                handle: _ret,
+                session,
+            }
         }
     }
     /// * `nwh`:
@@ -2233,6 +2329,7 @@ impl FrameBuffer {
         height: u16,
         params: CreateFrameBufferFromNwhArgs,
     ) -> FrameBuffer {
+        let session = crate::context::creation_session();
         unsafe {
             let _ret = bgfx_sys::bgfx_create_frame_buffer_from_nwh(
                 nwh as _,
@@ -2241,7 +2338,10 @@ impl FrameBuffer {
                 params.format as _,
                 params.depth_format as _,
             );
-            FrameBuffer { handle: _ret }
+            FrameBuffer {
+                handle: _ret,
+                session,
+            }
         }
     }
     /// * `handle`:
@@ -2260,27 +2360,35 @@ impl FrameBuffer {
     /// Frame buffer handle.
     /// * `attachment`:
     pub fn get_texture(&self, attachment: u8) -> Texture {
+        let session = crate::context::creation_session();
         unsafe {
             let _ret = bgfx_sys::bgfx_get_texture(self.handle, attachment);
-            Texture { handle: _ret }
+            Texture {
+                handle: _ret,
+                session,
+                size: None,
+            }
         }
     }
     /// * `handle`:
     /// Frame buffer handle. If handle is ]BGFX_INVALID_HANDLE] request will be
     /// made for main window back buffer.
     /// * `file_path`:
-    /// Will be passed to `bgfx::uint8_t::screenShot` callback.
-    pub fn request_screen_shot(&self, file_path: *const i8) {
+    /// Will be passed to [Callback::screen_shot].
+    pub fn request_screen_shot(&self, file_path: &str) {
         unsafe {
-            bgfx_sys::bgfx_request_screen_shot(self.handle, file_path as _)
+            let file_path_ = CFixedString::from_str(file_path);
+            bgfx_sys::bgfx_request_screen_shot(self.handle, file_path_.as_ptr());
         }
     }
 }
 
 impl Drop for FrameBuffer {
     fn drop(&mut self) {
-        unsafe {
-            bgfx_sys::bgfx_destroy_frame_buffer(self.handle);
+        if self.is_valid() && crate::context::is_current(self.session) {
+            unsafe {
+                bgfx_sys::bgfx_destroy_frame_buffer(self.handle);
+            }
         }
     }
 }
@@ -2301,10 +2409,14 @@ impl IndexBuffer {
     ///       buffers.
     ///   - [BufferFlags::INDEX32] - Buffer is using 32-bit indices. This flag has effect only on
     ///       index buffers.
-    pub fn create_index_buffer(mem: &Memory, flags: u16) -> IndexBuffer {
+    pub fn create_index_buffer(mem: &Memory, flags: BufferFlags) -> IndexBuffer {
+        let session = crate::context::creation_session();
         unsafe {
-            let _ret = bgfx_sys::bgfx_create_index_buffer(mem.handle, flags);
-            IndexBuffer { handle: _ret }
+            let _ret = bgfx_sys::bgfx_create_index_buffer(mem.handle, flags.bits());
+            IndexBuffer {
+                handle: _ret,
+                session,
+            }
         }
     }
     /// * `handle`:
@@ -2334,8 +2446,10 @@ impl IndexBuffer {
 
 impl Drop for IndexBuffer {
     fn drop(&mut self) {
-        unsafe {
-            bgfx_sys::bgfx_destroy_index_buffer(self.handle);
+        if self.is_valid() && crate::context::is_current(self.session) {
+            unsafe {
+                bgfx_sys::bgfx_destroy_index_buffer(self.handle);
+            }
         }
     }
 }
@@ -2344,26 +2458,36 @@ impl IndirectBuffer {
     /// * `num`:
     /// Number of indirect calls.
     pub fn create_indirect_buffer(num: u32) -> IndirectBuffer {
+        let session = crate::context::creation_session();
         unsafe {
             let _ret = bgfx_sys::bgfx_create_indirect_buffer(num);
-            IndirectBuffer { handle: _ret }
+            IndirectBuffer {
+                handle: _ret,
+                session,
+            }
         }
     }
 }
 
 impl Drop for IndirectBuffer {
     fn drop(&mut self) {
-        unsafe {
-            bgfx_sys::bgfx_destroy_indirect_buffer(self.handle);
+        if self.is_valid() && crate::context::is_current(self.session) {
+            unsafe {
+                bgfx_sys::bgfx_destroy_indirect_buffer(self.handle);
+            }
         }
     }
 }
 
 impl OcclusionQuery {
     pub fn create_occlusion_query() -> OcclusionQuery {
+        let session = crate::context::creation_session();
         unsafe {
             let _ret = bgfx_sys::bgfx_create_occlusion_query();
-            OcclusionQuery { handle: _ret }
+            OcclusionQuery {
+                handle: _ret,
+                session,
+            }
         }
     }
     /// * `handle`:
@@ -2390,8 +2514,10 @@ impl OcclusionQuery {
 
 impl Drop for OcclusionQuery {
     fn drop(&mut self) {
-        unsafe {
-            bgfx_sys::bgfx_destroy_occlusion_query(self.handle);
+        if self.is_valid() && crate::context::is_current(self.session) {
+            unsafe {
+                bgfx_sys::bgfx_destroy_occlusion_query(self.handle);
+            }
         }
     }
 }
@@ -2404,9 +2530,13 @@ impl Program {
     /// * `destroy_shaders`:
     /// If true, shaders will be destroyed when program is destroyed.
     pub fn create_program(vsh: &Shader, fsh: &Shader, destroy_shaders: bool) -> Program {
+        let session = crate::context::creation_session();
         unsafe {
             let _ret = bgfx_sys::bgfx_create_program(vsh.handle, fsh.handle, destroy_shaders);
-            Program { handle: _ret }
+            Program {
+                handle: _ret,
+                session,
+            }
         }
     }
     /// * `csh`:
@@ -2414,17 +2544,23 @@ impl Program {
     /// * `destroy_shaders`:
     /// If true, shaders will be destroyed when program is destroyed.
     pub fn create_compute_program(csh: &Shader, destroy_shaders: bool) -> Program {
+        let session = crate::context::creation_session();
         unsafe {
             let _ret = bgfx_sys::bgfx_create_compute_program(csh.handle, destroy_shaders);
-            Program { handle: _ret }
+            Program {
+                handle: _ret,
+                session,
+            }
         }
     }
 }
 
 impl Drop for Program {
     fn drop(&mut self) {
-        unsafe {
-            bgfx_sys::bgfx_destroy_program(self.handle);
+        if self.is_valid() && crate::context::is_current(self.session) {
+            unsafe {
+                bgfx_sys::bgfx_destroy_program(self.handle);
+            }
         }
     }
 }
@@ -2433,9 +2569,13 @@ impl Shader {
     /// * `mem`:
     /// Shader binary.
     pub fn create_shader(mem: &Memory) -> Shader {
+        let session = crate::context::creation_session();
         unsafe {
             let _ret = bgfx_sys::bgfx_create_shader(mem.handle);
-            Shader { handle: _ret }
+            Shader {
+                handle: _ret,
+                session,
+            }
         }
     }
     /// * `handle`:
@@ -2457,9 +2597,13 @@ impl Shader {
     /// * `destroy_shaders`:
     /// If true, shaders will be destroyed when program is destroyed.
     pub fn create_program(&self, fsh: &Shader, destroy_shaders: bool) -> Program {
+        let session = crate::context::creation_session();
         unsafe {
             let _ret = bgfx_sys::bgfx_create_program(self.handle, fsh.handle, destroy_shaders);
-            Program { handle: _ret }
+            Program {
+                handle: _ret,
+                session,
+            }
         }
     }
     /// * `csh`:
@@ -2467,17 +2611,23 @@ impl Shader {
     /// * `destroy_shaders`:
     /// If true, shaders will be destroyed when program is destroyed.
     pub fn create_compute_program(&self, destroy_shaders: bool) -> Program {
+        let session = crate::context::creation_session();
         unsafe {
             let _ret = bgfx_sys::bgfx_create_compute_program(self.handle, destroy_shaders);
-            Program { handle: _ret }
+            Program {
+                handle: _ret,
+                session,
+            }
         }
     }
 }
 
 impl Drop for Shader {
     fn drop(&mut self) {
-        unsafe {
-            bgfx_sys::bgfx_destroy_shader(self.handle);
+        if self.is_valid() && crate::context::is_current(self.session) {
+            unsafe {
+                bgfx_sys::bgfx_destroy_shader(self.handle);
+            }
         }
     }
 }
@@ -2492,15 +2642,26 @@ impl Texture {
     ///   mode.
     /// - [SamplerFlags::[MIN/MAG/MIP]_[POINT/ANISOTROPIC]] - Point or anisotropic
     ///   sampling.
+    ///   Takes a [SamplerDesc] for the sampler flags alone, or [TextureFlags].
     /// * `skip`:
     /// Skip top level mips when parsing texture.
     /// * `info`:
     /// When non-`NULL` is specified it returns parsed texture information.
-    pub fn create_texture(mem: &Memory, flags: u64, skip: u8, info: &mut TextureInfo) -> Texture {
+    pub fn create_texture(
+        mem: &Memory,
+        flags: impl Into<TextureFlags>,
+        skip: u8,
+        info: &mut TextureInfo,
+    ) -> Texture {
+        let session = crate::context::creation_session();
         unsafe {
-            let _info = std::mem::transmute(info);
-            let _ret = bgfx_sys::bgfx_create_texture(mem.handle, flags, skip, _info);
-            Texture { handle: _ret }
+            let _info = &mut *info as *mut TextureInfo as *mut bgfx_sys::bgfx_texture_info_s;
+            let _ret = bgfx_sys::bgfx_create_texture(mem.handle, flags.into().bits(), skip, _info);
+            Texture {
+                handle: _ret,
+                session,
+                size: TextureSize::from_info(info),
+            }
         }
     }
     /// * `width`:
@@ -2521,6 +2682,7 @@ impl Texture {
     ///   mode.
     /// - [SamplerFlags::[MIN/MAG/MIP]_[POINT/ANISOTROPIC]] - Point or anisotropic
     ///   sampling.
+    ///   Takes a [SamplerDesc] for the sampler flags alone, or [TextureFlags].
     /// * `mem`:
     /// Texture data. If `_mem` is non-NULL, created texture will be immutable. If
     /// `_mem` is NULL content of the texture is uninitialized. When `_numLayers` is more than
@@ -2531,9 +2693,10 @@ impl Texture {
         has_mips: bool,
         num_layers: u16,
         format: TextureFormat,
-        flags: u64,
+        flags: impl Into<TextureFlags>,
         mem: &Memory,
     ) -> Texture {
+        let session = crate::context::creation_session();
         unsafe {
             let _ret = bgfx_sys::bgfx_create_texture_2d(
                 width,
@@ -2541,10 +2704,16 @@ impl Texture {
                 has_mips,
                 num_layers,
                 format as _,
-                flags,
+                flags.into().bits(),
                 mem.handle,
             );
-            Texture { handle: _ret }
+            Texture {
+                handle: _ret,
+                session,
+                size: Some(TextureSize::new(
+                    width, height, 1, num_layers, has_mips, false, format,
+                )),
+            }
         }
     }
     /// * `ratio`:
@@ -2563,22 +2732,28 @@ impl Texture {
     ///   mode.
     /// - [SamplerFlags::[MIN/MAG/MIP]_[POINT/ANISOTROPIC]] - Point or anisotropic
     ///   sampling.
+    ///   Takes a [SamplerDesc] for the sampler flags alone, or [TextureFlags].
     pub fn create_texture_2d_scaled(
         ratio: BackbufferRatio,
         has_mips: bool,
         num_layers: u16,
         format: TextureFormat,
-        flags: u64,
+        flags: impl Into<TextureFlags>,
     ) -> Texture {
+        let session = crate::context::creation_session();
         unsafe {
             let _ret = bgfx_sys::bgfx_create_texture_2d_scaled(
                 ratio as _,
                 has_mips,
                 num_layers,
                 format as _,
-                flags,
+                flags.into().bits(),
             );
-            Texture { handle: _ret }
+            Texture {
+                handle: _ret,
+                session,
+                size: None,
+            }
         }
     }
     /// * `width`:
@@ -2598,6 +2773,7 @@ impl Texture {
     ///   mode.
     /// - [SamplerFlags::[MIN/MAG/MIP]_[POINT/ANISOTROPIC]] - Point or anisotropic
     ///   sampling.
+    ///   A [SamplerDesc] converts to the sampler flags alone with `into()`.
     /// * `mem`:
     /// Texture data. If `_mem` is non-NULL, created texture will be immutable. If
     /// `_mem` is NULL content of the texture is uninitialized. When `_numLayers` is more than
@@ -2610,6 +2786,7 @@ impl Texture {
         format: TextureFormat,
         params: CreateTexture3DArgs,
     ) -> Texture {
+        let session = crate::context::creation_session();
         unsafe {
             let _mem = if let Some(h) = params.mem {
                 h.handle
@@ -2622,10 +2799,16 @@ impl Texture {
                 depth,
                 has_mips,
                 format as _,
-                params.flags,
+                params.flags.bits(),
                 _mem,
             );
-            Texture { handle: _ret }
+            Texture {
+                handle: _ret,
+                session,
+                size: Some(TextureSize::new(
+                    width, height, depth, 1, has_mips, false, format,
+                )),
+            }
         }
     }
     /// * `size`:
@@ -2644,6 +2827,7 @@ impl Texture {
     ///   mode.
     /// - [SamplerFlags::[MIN/MAG/MIP]_[POINT/ANISOTROPIC]] - Point or anisotropic
     ///   sampling.
+    ///   A [SamplerDesc] converts to the sampler flags alone with `into()`.
     /// * `mem`:
     /// Texture data. If `_mem` is non-NULL, created texture will be immutable. If
     /// `_mem` is NULL content of the texture is uninitialized. When `_numLayers` is more than
@@ -2655,6 +2839,7 @@ impl Texture {
         format: TextureFormat,
         params: CreateTextureCubeArgs,
     ) -> Texture {
+        let session = crate::context::creation_session();
         unsafe {
             let _mem = if let Some(h) = params.mem {
                 h.handle
@@ -2666,10 +2851,16 @@ impl Texture {
                 has_mips,
                 num_layers,
                 format as _,
-                params.flags,
+                params.flags.bits(),
                 _mem,
             );
-            Texture { handle: _ret }
+            Texture {
+                handle: _ret,
+                session,
+                size: Some(TextureSize::new(
+                    size, size, 1, num_layers, has_mips, true, format,
+                )),
+            }
         }
     }
     /// * `handle`:
@@ -2841,17 +3032,24 @@ impl Texture {
     /// Frame buffer handle.
     /// * `attachment`:
     pub fn get_texture(handle: &FrameBuffer, attachment: u8) -> Texture {
+        let session = crate::context::creation_session();
         unsafe {
             let _ret = bgfx_sys::bgfx_get_texture(handle.handle, attachment);
-            Texture { handle: _ret }
+            Texture {
+                handle: _ret,
+                session,
+                size: None,
+            }
         }
     }
 }
 
 impl Drop for Texture {
     fn drop(&mut self) {
-        unsafe {
-            bgfx_sys::bgfx_destroy_texture(self.handle);
+        if self.is_valid() && crate::context::is_current(self.session) {
+            unsafe {
+                bgfx_sys::bgfx_destroy_texture(self.handle);
+            }
         }
     }
 }
@@ -2862,17 +3060,16 @@ impl Uniform {
     /// * `info`:
     /// Uniform info.
     pub fn get_uniform_info(&self, info: &mut UniformInfo) {
-        unsafe {
-            let _info = std::mem::transmute(info);
-            bgfx_sys::bgfx_get_uniform_info(self.handle, _info);
-        }
+        *info = uniform_info(self.handle);
     }
 }
 
 impl Drop for Uniform {
     fn drop(&mut self) {
-        unsafe {
-            bgfx_sys::bgfx_destroy_uniform(self.handle);
+        if self.is_valid() && crate::context::is_current(self.session) {
+            unsafe {
+                bgfx_sys::bgfx_destroy_uniform(self.handle);
+            }
         }
     }
 }
@@ -2896,12 +3093,16 @@ impl VertexBuffer {
     pub fn create_vertex_buffer(
         mem: &Memory,
         layout: &BuiltVertexLayout,
-        flags: u16,
+        flags: BufferFlags,
     ) -> VertexBuffer {
+        let session = crate::context::creation_session();
         unsafe {
             let _layout = std::mem::transmute(layout);
-            let _ret = bgfx_sys::bgfx_create_vertex_buffer(mem.handle, _layout, flags);
-            VertexBuffer { handle: _ret }
+            let _ret = bgfx_sys::bgfx_create_vertex_buffer(mem.handle, _layout, flags.bits());
+            VertexBuffer {
+                handle: _ret,
+                session,
+            }
         }
     }
     /// * `handle`:
@@ -2937,8 +3138,10 @@ impl VertexBuffer {
 
 impl Drop for VertexBuffer {
     fn drop(&mut self) {
-        unsafe {
-            bgfx_sys::bgfx_destroy_vertex_buffer(self.handle);
+        if self.is_valid() && crate::context::is_current(self.session) {
+            unsafe {
+                bgfx_sys::bgfx_destroy_vertex_buffer(self.handle);
+            }
         }
     }
 }
@@ -2947,18 +3150,24 @@ impl VertexLayout {
     /// * `layout`:
     /// Vertex layout.
     pub fn create_vertex_layout(layout: &BuiltVertexLayout) -> VertexLayout {
+        let session = crate::context::creation_session();
         unsafe {
             let _layout = std::mem::transmute(layout);
             let _ret = bgfx_sys::bgfx_create_vertex_layout(_layout);
-            VertexLayout { handle: _ret }
+            VertexLayout {
+                handle: _ret,
+                session,
+            }
         }
     }
 }
 
 impl Drop for VertexLayout {
     fn drop(&mut self) {
-        unsafe {
-            bgfx_sys::bgfx_destroy_vertex_layout(self.handle);
+        if self.is_valid() && crate::context::is_current(self.session) {
+            unsafe {
+                bgfx_sys::bgfx_destroy_vertex_layout(self.handle);
+            }
         }
     }
 }
@@ -3073,9 +3282,11 @@ impl TextureInfo {
 
 impl UniformInfo {
     pub fn new() -> UniformInfo {
-        let t = MaybeUninit::<UniformInfo>::zeroed();
-        let t = unsafe { t.assume_init() };
-        t
+        UniformInfo {
+            name: String::new(),
+            type_r: UniformType::Sampler,
+            num: 0,
+        }
     }
 }
 
@@ -3108,7 +3319,7 @@ impl Attachment {
                 params.layer,
                 params.num_layers,
                 params.mip,
-                params.resolve,
+                params.resolve.bits(),
             );
         }
     }
@@ -3242,10 +3453,20 @@ impl Encoder {
     /// First index to render.
     /// * `num_indices`:
     /// Number of indices to render.
-    pub fn set_index_buffer(&self, handle: &IndexBuffer, first_index: u32, num_indices: u32) {
+    pub fn set_index_buffer(
+        &self,
+        handle: &impl AsRef<IndexBuffer>,
+        first_index: u32,
+        num_indices: u32,
+    ) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_set_index_buffer(_self, handle.handle, first_index, num_indices);
+            bgfx_sys::bgfx_encoder_set_index_buffer(
+                _self,
+                handle.as_ref().handle,
+                first_index,
+                num_indices,
+            );
         }
     }
     /// * `handle`:
@@ -3304,7 +3525,7 @@ impl Encoder {
     pub fn set_vertex_buffer(
         &self,
         stream: u8,
-        handle: &VertexBuffer,
+        handle: &impl AsRef<VertexBuffer>,
         start_vertex: u32,
         num_vertices: u32,
     ) {
@@ -3313,7 +3534,7 @@ impl Encoder {
             bgfx_sys::bgfx_encoder_set_vertex_buffer(
                 _self,
                 stream,
-                handle.handle,
+                handle.as_ref().handle,
                 start_vertex,
                 num_vertices,
             );
@@ -3557,16 +3778,28 @@ impl Encoder {
     /// * `handle`:
     /// Texture handle.
     /// * `flags`:
-    /// Texture sampling mode. Default value UINT32_MAX uses
+    /// Texture sampling mode, see [Sampler]. [Sampler::Inherit] uses the
     ///   texture sampling settings from the texture.
     ///   - [SamplerFlags::[U/V/W]_[MIRROR/CLAMP]] - Mirror or clamp to edge wrap
     ///     mode.
     ///   - [SamplerFlags::[MIN/MAG/MIP]_[POINT/ANISOTROPIC]] - Point or anisotropic
     ///     sampling.
-    pub fn set_texture(&self, stage: u8, sampler: &Uniform, handle: &Texture, flags: u32) {
+    pub fn set_texture(
+        &self,
+        stage: u8,
+        sampler: &Uniform,
+        handle: &Texture,
+        flags: impl Into<Sampler>,
+    ) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_set_texture(_self, stage, sampler.handle, handle.handle, flags);
+            bgfx_sys::bgfx_encoder_set_texture(
+                _self,
+                stage,
+                sampler.handle,
+                handle.handle,
+                flags.into().to_bits(),
+            );
         }
     }
     /// * `id`:
@@ -3588,7 +3821,13 @@ impl Encoder {
     pub fn submit(&self, id: ViewId, program: &Program, params: SubmitArgs) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_submit(_self, id, program.handle, params.depth, params.flags);
+            bgfx_sys::bgfx_encoder_submit(
+                _self,
+                id,
+                program.handle,
+                params.depth,
+                params.flags.bits(),
+            );
         }
     }
     /// * `id`:
@@ -3616,9 +3855,10 @@ impl Encoder {
                 program.handle,
                 occlusion_query.handle,
                 params.depth,
-                params.flags,
+                params.flags.bits(),
             );
         }
+        crate::pending::query_submitted(occlusion_query.handle.idx);
     }
     /// * `id`:
     /// View id.
@@ -3651,7 +3891,7 @@ impl Encoder {
                 params.start,
                 params.num,
                 params.depth,
-                params.flags,
+                params.flags.bits(),
             );
         }
     }
@@ -3695,7 +3935,7 @@ impl Encoder {
                 params.num_index,
                 params.num_max,
                 params.depth,
-                params.flags,
+                params.flags.bits(),
             );
         }
     }
@@ -3846,7 +4086,7 @@ impl Encoder {
                 params.num_x,
                 params.num_y,
                 params.num_z,
-                params.flags,
+                params.flags.bits(),
             );
         }
     }
@@ -3878,16 +4118,16 @@ impl Encoder {
                 indirect_handle.handle,
                 params.start,
                 params.num,
-                params.flags,
+                params.flags.bits(),
             );
         }
     }
     /// * `flags`:
     /// Discard or preserve states. See [DiscardFlags].
-    pub fn discard(&self, flags: u8) {
+    pub fn discard(&self, flags: DiscardFlags) {
         unsafe {
             let _self = std::mem::transmute(self);
-            bgfx_sys::bgfx_encoder_discard(_self, flags);
+            bgfx_sys::bgfx_encoder_discard(_self, flags.bits());
         }
     }
     /// * `id`:
@@ -3959,17 +4199,40 @@ impl Encoder {
 
 /// * `init`:
 /// Initialization parameters. See: `bgfx::Init` for more info.
+///
+/// Returns `false` if bgfx is already initialized, such as by a [Bgfx] context.
+#[deprecated(note = "use `Bgfx::init`, which shuts bgfx down when dropped")]
 pub fn init(init: &Init) -> bool {
+    crate::context::begin_session(|| init_bgfx(init)).is_ok()
+}
+/// Initializes bgfx for [crate::context::begin_session].
+pub(crate) fn init_bgfx(init: &Init) -> bool {
     unsafe {
         let _init = std::mem::transmute(init);
         let _ret = bgfx_sys::bgfx_init(_init);
+        if _ret {
+            crate::callback::INTERFACES.set_active(init.callback);
+            crate::allocator::INTERFACES.set_active(init.allocator);
+        }
         _ret
     }
 }
-pub fn shutdown() {
-    unsafe {
-        bgfx_sys::bgfx_shutdown();
-    }
+/// Does nothing if bgfx isn't initialized.
+///
+/// # Safety
+///
+/// No [Bgfx] context or [Frame] may be alive, as encoders and [Transient] buffers borrowing
+/// them would be used after bgfx shut down.
+#[deprecated(note = "drop the `Bgfx` context instead")]
+pub unsafe fn shutdown() {
+    crate::context::end_session(|| {
+        unsafe {
+            bgfx_sys::bgfx_shutdown();
+        }
+        crate::callback::INTERFACES.release_active();
+        crate::allocator::INTERFACES.release_active();
+        crate::pending::shutdown();
+    });
 }
 /// * `width`:
 /// Back-buffer width.
@@ -3992,16 +4255,21 @@ pub fn shutdown() {
 /// Texture format. See: [TextureFormat].
 pub fn reset(width: u32, height: u32, params: ResetArgs) {
     unsafe {
-        bgfx_sys::bgfx_reset(width, height, params.flags, params.format as _);
+        bgfx_sys::bgfx_reset(width, height, params.flags.bits(), params.format as _);
     }
 }
 /// * `capture`:
 /// Capture frame with graphics debugger.
-pub fn frame(capture: bool) -> u32 {
-    unsafe {
-        let _ret = bgfx_sys::bgfx_frame(capture);
-        _ret
-    }
+///
+/// # Safety
+///
+/// No [Frame] may be alive, as encoders and [Transient] buffers borrowing it would be used
+/// after their frame ended.
+#[deprecated(note = "use `Bgfx::frame` or `Frame::frame`")]
+pub unsafe fn frame(capture: bool) -> u32 {
+    let _ret = bgfx_sys::bgfx_frame(capture);
+    crate::pending::frame_done(_ret);
+    _ret
 }
 pub fn get_renderer_type() -> RendererType {
     unsafe {
@@ -4031,9 +4299,9 @@ pub fn get_stats() -> &'static Stats {
 ///   - [DebugFlags::TEXT] - Display debug text.
 ///   - [DebugFlags::WIREFRAME] - Wireframe rendering. All rendering
 ///     primitives will be rendered as lines.
-pub fn set_debug(debug: u32) {
+pub fn set_debug(debug: DebugFlags) {
     unsafe {
-        bgfx_sys::bgfx_set_debug(debug);
+        bgfx_sys::bgfx_set_debug(debug.bits());
     }
 }
 /// * `attr`:
@@ -4077,19 +4345,27 @@ pub fn dbg_text_image(x: u16, y: u16, width: u16, height: u16, data: *const c_vo
 ///       buffers.
 ///   - [BufferFlags::INDEX32] - Buffer is using 32-bit indices. This flag has effect only on
 ///       index buffers.
-pub fn create_index_buffer(mem: &Memory, flags: u16) -> IndexBuffer {
+pub fn create_index_buffer(mem: &Memory, flags: BufferFlags) -> IndexBuffer {
+    let session = crate::context::creation_session();
     unsafe {
-        let _ret = bgfx_sys::bgfx_create_index_buffer(mem.handle, flags);
-        IndexBuffer { handle: _ret }
+        let _ret = bgfx_sys::bgfx_create_index_buffer(mem.handle, flags.bits());
+        IndexBuffer {
+            handle: _ret,
+            session,
+        }
     }
 }
 /// * `layout`:
 /// Vertex layout.
 pub fn create_vertex_layout(layout: &BuiltVertexLayout) -> VertexLayout {
+    let session = crate::context::creation_session();
     unsafe {
         let _layout = std::mem::transmute(layout);
         let _ret = bgfx_sys::bgfx_create_vertex_layout(_layout);
-        VertexLayout { handle: _ret }
+        VertexLayout {
+            handle: _ret,
+            session,
+        }
     }
 }
 /// * `mem`:
@@ -4107,11 +4383,19 @@ pub fn create_vertex_layout(layout: &BuiltVertexLayout) -> VertexLayout {
 ///      data is passed. If this flag is not specified, and more data is passed on update, the buffer
 ///      will be trimmed to fit the existing buffer size. This flag has effect only on dynamic buffers.
 ///  - [BufferFlags::INDEX32] - Buffer is using 32-bit indices. This flag has effect only on index buffers.
-pub fn create_vertex_buffer(mem: &Memory, layout: &BuiltVertexLayout, flags: u16) -> VertexBuffer {
+pub fn create_vertex_buffer(
+    mem: &Memory,
+    layout: &BuiltVertexLayout,
+    flags: BufferFlags,
+) -> VertexBuffer {
+    let session = crate::context::creation_session();
     unsafe {
         let _layout = std::mem::transmute(layout);
-        let _ret = bgfx_sys::bgfx_create_vertex_buffer(mem.handle, _layout, flags);
-        VertexBuffer { handle: _ret }
+        let _ret = bgfx_sys::bgfx_create_vertex_buffer(mem.handle, _layout, flags.bits());
+        VertexBuffer {
+            handle: _ret,
+            session,
+        }
     }
 }
 /// * `num`:
@@ -4129,10 +4413,14 @@ pub fn create_vertex_buffer(mem: &Memory, layout: &BuiltVertexLayout, flags: u16
 ///       buffers.
 ///   - [BufferFlags::INDEX32] - Buffer is using 32-bit indices. This flag has effect only on
 ///       index buffers.
-pub fn create_dynamic_index_buffer(num: u32, flags: u16) -> DynamicIndexBuffer {
+pub fn create_dynamic_index_buffer(num: u32, flags: BufferFlags) -> DynamicIndexBuffer {
+    let session = crate::context::creation_session();
     unsafe {
-        let _ret = bgfx_sys::bgfx_create_dynamic_index_buffer(num, flags);
-        DynamicIndexBuffer { handle: _ret }
+        let _ret = bgfx_sys::bgfx_create_dynamic_index_buffer(num, flags.bits());
+        DynamicIndexBuffer {
+            handle: _ret,
+            session,
+        }
     }
 }
 /// * `mem`:
@@ -4150,10 +4438,14 @@ pub fn create_dynamic_index_buffer(num: u32, flags: u16) -> DynamicIndexBuffer {
 ///       buffers.
 ///   - [BufferFlags::INDEX32] - Buffer is using 32-bit indices. This flag has effect only on
 ///       index buffers.
-pub fn create_dynamic_index_buffer_mem(mem: &Memory, flags: u16) -> DynamicIndexBuffer {
+pub fn create_dynamic_index_buffer_mem(mem: &Memory, flags: BufferFlags) -> DynamicIndexBuffer {
+    let session = crate::context::creation_session();
     unsafe {
-        let _ret = bgfx_sys::bgfx_create_dynamic_index_buffer_mem(mem.handle, flags);
-        DynamicIndexBuffer { handle: _ret }
+        let _ret = bgfx_sys::bgfx_create_dynamic_index_buffer_mem(mem.handle, flags.bits());
+        DynamicIndexBuffer {
+            handle: _ret,
+            session,
+        }
     }
 }
 /// * `handle`:
@@ -4187,12 +4479,16 @@ pub fn update_dynamic_index_buffer(handle: &DynamicIndexBuffer, start_index: u32
 pub fn create_dynamic_vertex_buffer(
     num: u32,
     layout: &BuiltVertexLayout,
-    flags: u16,
+    flags: BufferFlags,
 ) -> DynamicVertexBuffer {
+    let session = crate::context::creation_session();
     unsafe {
         let _layout = std::mem::transmute(layout);
-        let _ret = bgfx_sys::bgfx_create_dynamic_vertex_buffer(num, _layout, flags);
-        DynamicVertexBuffer { handle: _ret }
+        let _ret = bgfx_sys::bgfx_create_dynamic_vertex_buffer(num, _layout, flags.bits());
+        DynamicVertexBuffer {
+            handle: _ret,
+            session,
+        }
     }
 }
 /// * `mem`:
@@ -4215,12 +4511,17 @@ pub fn create_dynamic_vertex_buffer(
 pub fn create_dynamic_vertex_buffer_mem(
     mem: &Memory,
     layout: &BuiltVertexLayout,
-    flags: u16,
+    flags: BufferFlags,
 ) -> DynamicVertexBuffer {
+    let session = crate::context::creation_session();
     unsafe {
         let _layout = std::mem::transmute(layout);
-        let _ret = bgfx_sys::bgfx_create_dynamic_vertex_buffer_mem(mem.handle, _layout, flags);
-        DynamicVertexBuffer { handle: _ret }
+        let _ret =
+            bgfx_sys::bgfx_create_dynamic_vertex_buffer_mem(mem.handle, _layout, flags.bits());
+        DynamicVertexBuffer {
+            handle: _ret,
+            session,
+        }
     }
 }
 /// * `handle`:
@@ -4354,17 +4655,25 @@ pub fn alloc_instance_data_buffer(idb: &mut InstanceDataBuffer, num: u32, stride
 /// * `num`:
 /// Number of indirect calls.
 pub fn create_indirect_buffer(num: u32) -> IndirectBuffer {
+    let session = crate::context::creation_session();
     unsafe {
         let _ret = bgfx_sys::bgfx_create_indirect_buffer(num);
-        IndirectBuffer { handle: _ret }
+        IndirectBuffer {
+            handle: _ret,
+            session,
+        }
     }
 }
 /// * `mem`:
 /// Shader binary.
 pub fn create_shader(mem: &Memory) -> Shader {
+    let session = crate::context::creation_session();
     unsafe {
         let _ret = bgfx_sys::bgfx_create_shader(mem.handle);
-        Shader { handle: _ret }
+        Shader {
+            handle: _ret,
+            session,
+        }
     }
 }
 /// * `vsh`:
@@ -4374,9 +4683,13 @@ pub fn create_shader(mem: &Memory) -> Shader {
 /// * `destroy_shaders`:
 /// If true, shaders will be destroyed when program is destroyed.
 pub fn create_program(vsh: &Shader, fsh: &Shader, destroy_shaders: bool) -> Program {
+    let session = crate::context::creation_session();
     unsafe {
         let _ret = bgfx_sys::bgfx_create_program(vsh.handle, fsh.handle, destroy_shaders);
-        Program { handle: _ret }
+        Program {
+            handle: _ret,
+            session,
+        }
     }
 }
 /// * `csh`:
@@ -4384,9 +4697,13 @@ pub fn create_program(vsh: &Shader, fsh: &Shader, destroy_shaders: bool) -> Prog
 /// * `destroy_shaders`:
 /// If true, shaders will be destroyed when program is destroyed.
 pub fn create_compute_program(csh: &Shader, destroy_shaders: bool) -> Program {
+    let session = crate::context::creation_session();
     unsafe {
         let _ret = bgfx_sys::bgfx_create_compute_program(csh.handle, destroy_shaders);
-        Program { handle: _ret }
+        Program {
+            handle: _ret,
+            session,
+        }
     }
 }
 /// * `depth`:
@@ -4404,10 +4721,11 @@ pub fn is_texture_valid(
     cube_map: bool,
     num_layers: u16,
     format: TextureFormat,
-    flags: u64,
+    flags: TextureFlags,
 ) -> bool {
     unsafe {
-        let _ret = bgfx_sys::bgfx_is_texture_valid(depth, cube_map, num_layers, format as _, flags);
+        let _ret =
+            bgfx_sys::bgfx_is_texture_valid(depth, cube_map, num_layers, format as _, flags.bits());
         _ret
     }
 }
@@ -4471,15 +4789,26 @@ pub fn calc_texture_size(
 ///   mode.
 /// - [SamplerFlags::[MIN/MAG/MIP]_[POINT/ANISOTROPIC]] - Point or anisotropic
 ///   sampling.
+///   Takes a [SamplerDesc] for the sampler flags alone, or [TextureFlags].
 /// * `skip`:
 /// Skip top level mips when parsing texture.
 /// * `info`:
 /// When non-`NULL` is specified it returns parsed texture information.
-pub fn create_texture(mem: &Memory, flags: u64, skip: u8, info: &mut TextureInfo) -> Texture {
+pub fn create_texture(
+    mem: &Memory,
+    flags: impl Into<TextureFlags>,
+    skip: u8,
+    info: &mut TextureInfo,
+) -> Texture {
+    let session = crate::context::creation_session();
     unsafe {
-        let _info = std::mem::transmute(info);
-        let _ret = bgfx_sys::bgfx_create_texture(mem.handle, flags, skip, _info);
-        Texture { handle: _ret }
+        let _info = &mut *info as *mut TextureInfo as *mut bgfx_sys::bgfx_texture_info_s;
+        let _ret = bgfx_sys::bgfx_create_texture(mem.handle, flags.into().bits(), skip, _info);
+        Texture {
+            handle: _ret,
+            session,
+            size: TextureSize::from_info(info),
+        }
     }
 }
 /// * `width`:
@@ -4500,6 +4829,7 @@ pub fn create_texture(mem: &Memory, flags: u64, skip: u8, info: &mut TextureInfo
 ///   mode.
 /// - [SamplerFlags::[MIN/MAG/MIP]_[POINT/ANISOTROPIC]] - Point or anisotropic
 ///   sampling.
+///   Takes a [SamplerDesc] for the sampler flags alone, or [TextureFlags].
 /// * `mem`:
 /// Texture data. If `_mem` is non-NULL, created texture will be immutable. If
 /// `_mem` is NULL content of the texture is uninitialized. When `_numLayers` is more than
@@ -4510,9 +4840,10 @@ pub fn create_texture_2d(
     has_mips: bool,
     num_layers: u16,
     format: TextureFormat,
-    flags: u64,
+    flags: impl Into<TextureFlags>,
     mem: &Memory,
 ) -> Texture {
+    let session = crate::context::creation_session();
     unsafe {
         let _ret = bgfx_sys::bgfx_create_texture_2d(
             width,
@@ -4520,10 +4851,16 @@ pub fn create_texture_2d(
             has_mips,
             num_layers,
             format as _,
-            flags,
+            flags.into().bits(),
             mem.handle,
         );
-        Texture { handle: _ret }
+        Texture {
+            handle: _ret,
+            session,
+            size: Some(TextureSize::new(
+                width, height, 1, num_layers, has_mips, false, format,
+            )),
+        }
     }
 }
 /// * `ratio`:
@@ -4542,22 +4879,28 @@ pub fn create_texture_2d(
 ///   mode.
 /// - [SamplerFlags::[MIN/MAG/MIP]_[POINT/ANISOTROPIC]] - Point or anisotropic
 ///   sampling.
+///   Takes a [SamplerDesc] for the sampler flags alone, or [TextureFlags].
 pub fn create_texture_2d_scaled(
     ratio: BackbufferRatio,
     has_mips: bool,
     num_layers: u16,
     format: TextureFormat,
-    flags: u64,
+    flags: impl Into<TextureFlags>,
 ) -> Texture {
+    let session = crate::context::creation_session();
     unsafe {
         let _ret = bgfx_sys::bgfx_create_texture_2d_scaled(
             ratio as _,
             has_mips,
             num_layers,
             format as _,
-            flags,
+            flags.into().bits(),
         );
-        Texture { handle: _ret }
+        Texture {
+            handle: _ret,
+            session,
+            size: None,
+        }
     }
 }
 /// * `width`:
@@ -4577,6 +4920,7 @@ pub fn create_texture_2d_scaled(
 ///   mode.
 /// - [SamplerFlags::[MIN/MAG/MIP]_[POINT/ANISOTROPIC]] - Point or anisotropic
 ///   sampling.
+///   A [SamplerDesc] converts to the sampler flags alone with `into()`.
 /// * `mem`:
 /// Texture data. If `_mem` is non-NULL, created texture will be immutable. If
 /// `_mem` is NULL content of the texture is uninitialized. When `_numLayers` is more than
@@ -4589,6 +4933,7 @@ pub fn create_texture_3d(
     format: TextureFormat,
     params: CreateTexture3DArgs,
 ) -> Texture {
+    let session = crate::context::creation_session();
     unsafe {
         let _mem = if let Some(h) = params.mem {
             h.handle
@@ -4601,10 +4946,16 @@ pub fn create_texture_3d(
             depth,
             has_mips,
             format as _,
-            params.flags,
+            params.flags.bits(),
             _mem,
         );
-        Texture { handle: _ret }
+        Texture {
+            handle: _ret,
+            session,
+            size: Some(TextureSize::new(
+                width, height, depth, 1, has_mips, false, format,
+            )),
+        }
     }
 }
 /// * `size`:
@@ -4623,6 +4974,7 @@ pub fn create_texture_3d(
 ///   mode.
 /// - [SamplerFlags::[MIN/MAG/MIP]_[POINT/ANISOTROPIC]] - Point or anisotropic
 ///   sampling.
+///   A [SamplerDesc] converts to the sampler flags alone with `into()`.
 /// * `mem`:
 /// Texture data. If `_mem` is non-NULL, created texture will be immutable. If
 /// `_mem` is NULL content of the texture is uninitialized. When `_numLayers` is more than
@@ -4634,6 +4986,7 @@ pub fn create_texture_cube(
     format: TextureFormat,
     params: CreateTextureCubeArgs,
 ) -> Texture {
+    let session = crate::context::creation_session();
     unsafe {
         let _mem = if let Some(h) = params.mem {
             h.handle
@@ -4645,10 +4998,16 @@ pub fn create_texture_cube(
             has_mips,
             num_layers,
             format as _,
-            params.flags,
+            params.flags.bits(),
             _mem,
         );
-        Texture { handle: _ret }
+        Texture {
+            handle: _ret,
+            session,
+            size: Some(TextureSize::new(
+                size, size, 1, num_layers, has_mips, true, format,
+            )),
+        }
     }
 }
 /// * `handle`:
@@ -4821,11 +5180,16 @@ pub fn create_frame_buffer(
     width: u16,
     height: u16,
     format: TextureFormat,
-    texture_flags: u64,
+    texture_flags: TextureFlags,
 ) -> FrameBuffer {
+    let session = crate::context::creation_session();
     unsafe {
-        let _ret = bgfx_sys::bgfx_create_frame_buffer(width, height, format as _, texture_flags);
-        FrameBuffer { handle: _ret }
+        let _ret =
+            bgfx_sys::bgfx_create_frame_buffer(width, height, format as _, texture_flags.bits());
+        FrameBuffer {
+            handle: _ret,
+            session,
+        }
     }
 }
 /// * `ratio`:
@@ -4843,12 +5207,19 @@ pub fn create_frame_buffer(
 pub fn create_frame_buffer_scaled(
     ratio: BackbufferRatio,
     format: TextureFormat,
-    texture_flags: u64,
+    texture_flags: TextureFlags,
 ) -> FrameBuffer {
+    let session = crate::context::creation_session();
     unsafe {
-        let _ret =
-            bgfx_sys::bgfx_create_frame_buffer_scaled(ratio as _, format as _, texture_flags);
-        FrameBuffer { handle: _ret }
+        let _ret = bgfx_sys::bgfx_create_frame_buffer_scaled(
+            ratio as _,
+            format as _,
+            texture_flags.bits(),
+        );
+        FrameBuffer {
+            handle: _ret,
+            session,
+        }
     }
 }
 /// * `num`:
@@ -4863,10 +5234,14 @@ pub fn create_frame_buffer_from_handles(
     handles: &Texture,
     destroy_texture: bool,
 ) -> FrameBuffer {
+    let session = crate::context::creation_session();
     unsafe {
         let _ret =
             bgfx_sys::bgfx_create_frame_buffer_from_handles(num, &handles.handle, destroy_texture);
-        FrameBuffer { handle: _ret }
+        FrameBuffer {
+            handle: _ret,
+            session,
+        }
     }
 }
 /// * `num`:
@@ -4881,11 +5256,15 @@ pub fn create_frame_buffer_from_attachment(
     attachment: &Attachment,
     destroy_texture: bool,
 ) -> FrameBuffer {
+    let session = crate::context::creation_session();
     unsafe {
         let _attachment = std::mem::transmute(attachment);
         let _ret =
             bgfx_sys::bgfx_create_frame_buffer_from_attachment(num, _attachment, destroy_texture);
-        FrameBuffer { handle: _ret }
+        FrameBuffer {
+            handle: _ret,
+            session,
+        }
     }
 }
 /// * `nwh`:
@@ -4904,6 +5283,7 @@ pub fn create_frame_buffer_from_nwh(
     height: u16,
     params: CreateFrameBufferFromNwhArgs,
 ) -> FrameBuffer {
+    let session = crate::context::creation_session();
     unsafe {
         let _ret = bgfx_sys::bgfx_create_frame_buffer_from_nwh(
             nwh as _,
@@ -4912,16 +5292,24 @@ pub fn create_frame_buffer_from_nwh(
             params.format as _,
             params.depth_format as _,
         );
-        FrameBuffer { handle: _ret }
+        FrameBuffer {
+            handle: _ret,
+            session,
+        }
     }
 }
 /// * `handle`:
 /// Frame buffer handle.
 /// * `attachment`:
 pub fn get_texture(handle: &FrameBuffer, attachment: u8) -> Texture {
+    let session = crate::context::creation_session();
     unsafe {
         let _ret = bgfx_sys::bgfx_get_texture(handle.handle, attachment);
-        Texture { handle: _ret }
+        Texture {
+            handle: _ret,
+            session,
+            size: None,
+        }
     }
 }
 /// * `handle`:
@@ -4929,15 +5317,16 @@ pub fn get_texture(handle: &FrameBuffer, attachment: u8) -> Texture {
 /// * `info`:
 /// Uniform info.
 pub fn get_uniform_info(handle: &Uniform, info: &mut UniformInfo) {
-    unsafe {
-        let _info = std::mem::transmute(info);
-        bgfx_sys::bgfx_get_uniform_info(handle.handle, _info);
-    }
+    *info = uniform_info(handle.handle);
 }
 pub fn create_occlusion_query() -> OcclusionQuery {
+    let session = crate::context::creation_session();
     unsafe {
         let _ret = bgfx_sys::bgfx_create_occlusion_query();
-        OcclusionQuery { handle: _ret }
+        OcclusionQuery {
+            handle: _ret,
+            session,
+        }
     }
 }
 /// * `handle`:
@@ -5006,9 +5395,15 @@ pub fn set_view_scissor(id: ViewId, params: SetViewScissorArgs) {
 /// Depth clear value.
 /// * `stencil`:
 /// Stencil clear value.
-pub fn set_view_clear(id: ViewId, flags: u16, params: SetViewClearArgs) {
+pub fn set_view_clear(id: ViewId, flags: ClearFlags, params: SetViewClearArgs) {
     unsafe {
-        bgfx_sys::bgfx_set_view_clear(id, flags, params.rgba, params.depth, params.stencil);
+        bgfx_sys::bgfx_set_view_clear(
+            id,
+            flags.bits(),
+            params.rgba.to_rgba32(),
+            params.depth,
+            params.stencil,
+        );
     }
 }
 /// * `id`:
@@ -5038,15 +5433,25 @@ pub fn set_view_clear(id: ViewId, flags: u16, params: SetViewClearArgs) {
 /// Palette index for frame buffer attachment 7.
 pub fn set_view_clear_mrt(
     id: ViewId,
-    flags: u16,
+    flags: ClearFlags,
     depth: f32,
     stencil: u8,
     params: SetViewClearMrtArgs,
 ) {
     unsafe {
         bgfx_sys::bgfx_set_view_clear_mrt(
-            id, flags, depth, stencil, params.c_0, params.c_1, params.c_2, params.c_3, params.c_4,
-            params.c_5, params.c_6, params.c_7,
+            id,
+            flags.bits(),
+            depth,
+            stencil,
+            params.c_0,
+            params.c_1,
+            params.c_2,
+            params.c_3,
+            params.c_4,
+            params.c_5,
+            params.c_6,
+            params.c_7,
         );
     }
 }
@@ -5078,28 +5483,36 @@ pub fn reset_view(id: ViewId) {
 }
 /// * `for_thread`:
 /// Explicitly request an encoder for a worker thread.
-pub fn encoder_begin(for_thread: bool) -> &'static Encoder {
-    unsafe {
-        let _ret = bgfx_sys::bgfx_encoder_begin(for_thread);
-        std::mem::transmute(_ret)
-    }
+///
+/// # Safety
+///
+/// The encoder must not be used after it's ended with [encoder_end], or after the frame is
+/// advanced.
+#[deprecated(note = "the encoder can outlive the frame, use `Frame::encoder` instead")]
+pub unsafe fn encoder_begin(for_thread: bool) -> &'static Encoder {
+    let _ret = bgfx_sys::bgfx_encoder_begin(for_thread);
+    std::mem::transmute(_ret)
 }
 /// * `encoder`:
 /// Encoder.
-pub fn encoder_end(encoder: &Encoder) {
-    unsafe {
-        let _encoder = std::mem::transmute(encoder);
-        bgfx_sys::bgfx_encoder_end(_encoder);
-    }
+///
+/// # Safety
+///
+/// `encoder` must have been returned by [encoder_begin] and not be ended yet.
+#[deprecated(note = "use `Frame::encoder`, which ends the encoder on drop")]
+pub unsafe fn encoder_end(encoder: &Encoder) {
+    let _encoder = std::mem::transmute(encoder);
+    bgfx_sys::bgfx_encoder_end(_encoder);
 }
 /// * `handle`:
 /// Frame buffer handle. If handle is ]BGFX_INVALID_HANDLE] request will be
 /// made for main window back buffer.
 /// * `file_path`:
-/// Will be passed to `bgfx::uint8_t::screenShot` callback.
-pub fn request_screen_shot(handle: &FrameBuffer, file_path: *const i8) {
+/// Will be passed to [Callback::screen_shot].
+pub fn request_screen_shot(handle: &FrameBuffer, file_path: &str) {
     unsafe {
-        bgfx_sys::bgfx_request_screen_shot(handle.handle, file_path as _);
+        let file_path_ = CFixedString::from_str(file_path);
+        bgfx_sys::bgfx_request_screen_shot(handle.handle, file_path_.as_ptr());
     }
 }
 /// * `msecs`:
@@ -5206,9 +5619,9 @@ pub fn alloc_transform(transform: &mut Transform, num: u16) -> u32 {
 /// First index to render.
 /// * `num_indices`:
 /// Number of indices to render.
-pub fn set_index_buffer(handle: &IndexBuffer, first_index: u32, num_indices: u32) {
+pub fn set_index_buffer(handle: &impl AsRef<IndexBuffer>, first_index: u32, num_indices: u32) {
     unsafe {
-        bgfx_sys::bgfx_set_index_buffer(handle.handle, first_index, num_indices);
+        bgfx_sys::bgfx_set_index_buffer(handle.as_ref().handle, first_index, num_indices);
     }
 }
 /// * `handle`:
@@ -5242,9 +5655,19 @@ pub fn set_transient_index_buffer(tib: &TransientIndexBuffer, first_index: u32,
 /// First vertex to render.
 /// * `num_vertices`:
 /// Number of vertices to render.
-pub fn set_vertex_buffer(stream: u8, handle: &VertexBuffer, start_vertex: u32, num_vertices: u32) {
+pub fn set_vertex_buffer(
+    stream: u8,
+    handle: &impl AsRef<VertexBuffer>,
+    start_vertex: u32,
+    num_vertices: u32,
+) {
     unsafe {
-        bgfx_sys::bgfx_set_vertex_buffer(stream, handle.handle, start_vertex, num_vertices);
+        bgfx_sys::bgfx_set_vertex_buffer(
+            stream,
+            handle.as_ref().handle,
+            start_vertex,
+            num_vertices,
+        );
     }
 }
 /// * `stream`:
@@ -5436,15 +5859,15 @@ pub fn set_instance_count(num_instances: u32) {
 /// * `handle`:
 /// Texture handle.
 /// * `flags`:
-/// Texture sampling mode. Default value UINT32_MAX uses
+/// Texture sampling mode, see [Sampler]. [Sampler::Inherit] uses the
 ///   texture sampling settings from the texture.
 ///   - [SamplerFlags::[U/V/W]_[MIRROR/CLAMP]] - Mirror or clamp to edge wrap
 ///     mode.
 ///   - [SamplerFlags::[MIN/MAG/MIP]_[POINT/ANISOTROPIC]] - Point or anisotropic
 ///     sampling.
-pub fn set_texture(stage: u8, sampler: &Uniform, handle: &Texture, flags: u32) {
+pub fn set_texture(stage: u8, sampler: &Uniform, handle: &Texture, flags: impl Into<Sampler>) {
     unsafe {
-        bgfx_sys::bgfx_set_texture(stage, sampler.handle, handle.handle, flags);
+        bgfx_sys::bgfx_set_texture(stage, sampler.handle, handle.handle, flags.into().to_bits());
     }
 }
 /// * `id`:
@@ -5464,7 +5887,7 @@ pub fn touch(id: ViewId) {
 /// Which states to discard for next draw. See [DiscardFlags].
 pub fn submit(id: ViewId, program: &Program, params: SubmitArgs) {
     unsafe {
-        bgfx_sys::bgfx_submit(id, program.handle, params.depth, params.flags);
+        bgfx_sys::bgfx_submit(id, program.handle, params.depth, params.flags.bits());
     }
 }
 /// * `id`:
@@ -5489,9 +5912,10 @@ pub fn submit_occlusion_query(
             program.handle,
             occlusion_query.handle,
             params.depth,
-            params.flags,
+            params.flags.bits(),
         );
     }
+    crate::pending::query_submitted(occlusion_query.handle.idx);
 }
 /// * `id`:
 /// View id.
@@ -5521,7 +5945,7 @@ pub fn submit_indirect(
             params.start,
             params.num,
             params.depth,
-            params.flags,
+            params.flags.bits(),
         );
     }
 }
@@ -5562,7 +5986,7 @@ pub fn submit_indirect_count(
             params.num_index,
             params.num_max,
             params.depth,
-            params.flags,
+            params.flags.bits(),
         );
     }
 }
@@ -5656,7 +6080,7 @@ pub fn dispatch(id: ViewId, program: &Program, params: DispatchArgs) {
             params.num_x,
             params.num_y,
             params.num_z,
-            params.flags,
+            params.flags.bits(),
         );
     }
 }
@@ -5685,15 +6109,15 @@ pub fn dispatch_indirect(
             indirect_handle.handle,
             params.start,
             params.num,
-            params.flags,
+            params.flags.bits(),
         );
     }
 }
 /// * `flags`:
 /// Draw/compute states to discard.
-pub fn discard(flags: u8) {
+pub fn discard(flags: DiscardFlags) {
     unsafe {
-        bgfx_sys::bgfx_discard(flags);
+        bgfx_sys::bgfx_discard(flags.bits());
     }
 }
 /// * `id`:
@@ -5761,6 +6185,312 @@ pub fn blit(
 
 pub type ViewId = u16;
 
+/// Returns the interface of this bgfx instance. Pass it to `set_interface` in a module built
+/// with the `shared-api` feature, such as a plugin, so both use the same bgfx instance.
+pub fn get_interface() -> *const bgfx_sys::bgfx_interface_vtbl_t {
+    unsafe { bgfx_sys::bgfx_get_interface(bgfx_sys::BGFX_API_VERSION) }
+}
+
+/// Sets the palette color at `index`, from 0 to 15. Frame buffer attachments are cleared with
+/// palette colors by [set_view_clear_mrt]. See [Palette] to keep track of the used indices.
+///
+/// Panics if `index` is not less than [Palette::SIZE].
+pub fn set_palette_color(index: u8, color: Color) {
+    assert!(index < Palette::SIZE, "palette index must be less than 16");
+    unsafe {
+        bgfx_sys::bgfx_set_palette_color_rgba8(index, color.to_rgba32());
+    }
+}
+
+pub use crate::allocator::{Allocator, GlobalAllocAdapter};
+pub use crate::buffer::{Index, Pod, Transient, TypedIndexBuffer, TypedVertexBuffer};
+pub use crate::callback::Callback;
+pub use crate::color::{linear_to_srgb, srgb_to_linear, Color, Palette};
+pub use crate::context::{Bgfx, InitError};
+pub use crate::debug_draw::DebugDraw;
+#[cfg(feature = "egui")]
+pub use crate::egui_painter::EguiPainter;
+pub use crate::error::{BgfxError, CapsLimit};
+pub use crate::headless::{Headless, RgbaImage};
+#[cfg(feature = "imgui")]
+pub use crate::imgui_renderer::ImguiRenderer;
+pub use crate::pending::{OcclusionResult, OcclusionStatus, PendingRead};
+pub use crate::render_state::{
+    Blend, BlendEquation, BlendFactor, Cull, DepthTest, Primitive, RenderState, TargetBlend,
+};
+pub use crate::sampler::{AddressMode, CompareFunc, Filter, MipFilter, Sampler, SamplerDesc};
+pub use crate::shader::{
+    shader_dir, EmbeddedShader, ShaderBinary, ShaderBundle, ShaderBundleError, ShaderCodeFormat,
+    ShaderError, ShaderKind, ShaderUniform,
+};
+pub use crate::stencil_state::{StencilOp, StencilState, StencilTest};
+#[doc(hidden)]
+pub use crate::vertex::attrib_size as vertex_attrib_size;
+#[doc(hidden)]
+pub use crate::vertex::attrib_size_varies as vertex_attrib_size_varies;
+pub use crate::vertex::{Vertex, VertexAttribute};
+#[cfg(feature = "raw-window-handle")]
+pub use crate::window::WindowHandleError;
+#[cfg(feature = "derive")]
+pub use bgfx_rs_derive::Vertex;
+
+impl DynamicIndexBuffer {
+    /// Returns `false` if bgfx failed to create the dynamic index buffer.
+    pub fn is_valid(&self) -> bool {
+        self.handle.idx != u16::MAX
+    }
+}
+
+impl DynamicVertexBuffer {
+    /// Returns `false` if bgfx failed to create the dynamic vertex buffer.
+    pub fn is_valid(&self) -> bool {
+        self.handle.idx != u16::MAX
+    }
+}
+
+impl FrameBuffer {
+    /// Returns `false` if bgfx failed to create the frame buffer.
+    pub fn is_valid(&self) -> bool {
+        self.handle.idx != u16::MAX
+    }
+
+    /// Creates a frame buffer rendering into `textures`, such as a color and a depth texture.
+    /// The textures stay owned by the caller, so they are not destroyed with the frame buffer.
+    pub fn create_frame_buffer_from_textures(textures: &[&Texture]) -> FrameBuffer {
+        let session = crate::context::creation_session();
+        let handles: Vec<_> = textures.iter().map(|texture| texture.handle).collect();
+        unsafe {
+            let _ret = bgfx_sys::bgfx_create_frame_buffer_from_handles(
+                handles.len() as u8,
+                handles.as_ptr(),
+                false,
+            );
+            FrameBuffer {
+                handle: _ret,
+                session,
+            }
+        }
+    }
+}
+
+impl IndexBuffer {
+    /// Returns `false` if bgfx failed to create the index buffer.
+    pub fn is_valid(&self) -> bool {
+        self.handle.idx != u16::MAX
+    }
+}
+
+impl IndirectBuffer {
+    /// Returns `false` if bgfx failed to create the indirect buffer.
+    pub fn is_valid(&self) -> bool {
+        self.handle.idx != u16::MAX
+    }
+}
+
+impl OcclusionQuery {
+    /// Returns `false` if bgfx failed to create the occlusion query.
+    pub fn is_valid(&self) -> bool {
+        self.handle.idx != u16::MAX
+    }
+
+    /// Returns the result of the last submitted query once `frame` has returned the frame its
+    /// result is available in, like a [PendingRead].
+    pub fn poll(&self) -> OcclusionStatus {
+        if !crate::context::is_current(self.session) || !crate::pending::query_done(self.handle.idx)
+        {
+            return OcclusionStatus::Pending;
+        }
+        let mut num_pixels = 0;
+        match self.get_result(&mut num_pixels) {
+            OcclusionQueryResult::Invisible => OcclusionStatus::Ready(OcclusionResult {
+                visible: false,
+                num_pixels,
+            }),
+            OcclusionQueryResult::Visible => OcclusionStatus::Ready(OcclusionResult {
+                visible: true,
+                num_pixels,
+            }),
+            _ => OcclusionStatus::Pending,
+        }
+    }
+}
+
+impl Program {
+    /// Returns `false` if bgfx failed to create the program.
+    pub fn is_valid(&self) -> bool {
+        self.handle.idx != u16::MAX
+    }
+}
+
+impl Shader {
+    /// Returns `false` if bgfx failed to create the shader.
+    pub fn is_valid(&self) -> bool {
+        self.handle.idx != u16::MAX
+    }
+}
+
+impl Texture {
+    /// Returns `false` if bgfx failed to create the texture.
+    pub fn is_valid(&self) -> bool {
+        self.handle.idx != u16::MAX
+    }
+
+    /// Reads back the contents of mip level `mip`, into a buffer sized for the mip level from
+    /// the size and format the texture was created with. The texture must have been created
+    /// with [TextureFlags::READ_BACK], and the renderer must support
+    /// [CapsFlags::TEXTURE_READ_BACK].
+    ///
+    /// Returns `None` if `mip` is not a mip level of the texture, or if the size of the texture
+    /// isn't known, as for [Texture::create_texture_2d_scaled] and [FrameBuffer::get_texture].
+    /// Use [Texture::read_with_size] for those.
+    pub fn read(&self, mip: u8) -> Option<PendingRead> {
+        let size = self.size?.mip_size(mip)?;
+        Some(unsafe { self.read_with_size(mip, size) })
+    }
+
+    /// Reads back the contents of mip level `mip` into a buffer of `size` bytes. See
+    /// [Texture::read].
+    ///
+    /// # Safety
+    ///
+    /// bgfx writes the whole mip level to the buffer, so `size` must be at least the size of
+    /// the mip level in bytes, including all layers and cube map sides.
+    pub unsafe fn read_with_size(&self, mip: u8, size: usize) -> PendingRead {
+        let mut data = vec![0u8; size].into_boxed_slice();
+        let _ret = bgfx_sys::bgfx_read_texture(self.handle, data.as_mut_ptr() as _, mip);
+        PendingRead::new(data, _ret)
+    }
+}
+
+/// Size and format a texture was created with, to size the buffers of [Texture::read].
+#[derive(Clone, Copy, Debug)]
+struct TextureSize {
+    width: u16,
+    height: u16,
+    depth: u16,
+    num_layers: u16,
+    num_mips: u8,
+    cube_map: bool,
+    format: TextureFormat,
+}
+
+impl TextureSize {
+    fn new(
+        width: u16,
+        height: u16,
+        depth: u16,
+        num_layers: u16,
+        has_mips: bool,
+        cube_map: bool,
+        format: TextureFormat,
+    ) -> TextureSize {
+        let largest = width.max(height).max(depth).max(1);
+        TextureSize {
+            width,
+            height,
+            depth,
+            num_layers: num_layers.max(1),
+            num_mips: if has_mips {
+                (16 - largest.leading_zeros()) as u8
+            } else {
+                1
+            },
+            cube_map,
+            format,
+        }
+    }
+
+    /// Size parsed by [Texture::create_texture], or `None` if the texture data couldn't be
+    /// parsed.
+    fn from_info(info: &TextureInfo) -> Option<TextureSize> {
+        if info.storage_size == 0 {
+            return None;
+        }
+        Some(TextureSize {
+            width: info.width,
+            height: info.height,
+            depth: info.depth,
+            num_layers: info.num_layers.max(1),
+            num_mips: info.num_mips.max(1),
+            cube_map: info.cube_map,
+            format: info.format,
+        })
+    }
+
+    /// Returns the size of mip level `mip` in bytes, including all layers and cube map sides.
+    fn mip_size(&self, mip: u8) -> Option<usize> {
+        if mip >= self.num_mips {
+            return None;
+        }
+        let mut info = TextureInfo::new();
+        calc_texture_size(
+            &mut info,
+            (self.width >> mip).max(1),
+            (self.height >> mip).max(1),
+            (self.depth >> mip).max(1),
+            self.cube_map,
+            false,
+            self.num_layers,
+            self.format,
+        );
+        Some(info.storage_size as usize)
+    }
+}
+
+impl Uniform {
+    /// Returns `false` if bgfx failed to create the uniform.
+    pub fn is_valid(&self) -> bool {
+        self.handle.idx != u16::MAX
+    }
+}
+
+impl VertexBuffer {
+    /// Returns `false` if bgfx failed to create the vertex buffer.
+    pub fn is_valid(&self) -> bool {
+        self.handle.idx != u16::MAX
+    }
+}
+
+impl VertexLayout {
+    /// Returns `false` if bgfx failed to create the vertex layout.
+    pub fn is_valid(&self) -> bool {
+        self.handle.idx != u16::MAX
+    }
+}
+
+impl Init {
+    /// Installs a Rust [Callback] for bgfx to report fatal errors, traces, profiler events,
+    /// shader cache requests, screenshots and video capture to.
+    ///
+    /// The callback is shared between this `Init` and bgfx while bgfx is initialized with it,
+    /// and dropped once both are done with it: when this `Init` is dropped or gets another
+    /// callback, and after [shutdown]. The same `Init` can be used for another [init] after
+    /// [shutdown].
+    pub fn set_callback(&mut self, callback: Box<dyn Callback>) {
+        crate::callback::INTERFACES.release(self.callback);
+        self.callback = crate::callback::into_raw(callback);
+    }
+
+    /// Installs a Rust [Allocator] that bgfx uses for all of its internal allocations. Use
+    /// [GlobalAllocAdapter] to forward to a [std::alloc::GlobalAlloc].
+    ///
+    /// The allocator is shared between this `Init` and bgfx while bgfx is initialized with it,
+    /// and dropped once both are done with it: when this `Init` is dropped or gets another
+    /// allocator, and after [shutdown] has released all of the memory of bgfx. The same `Init`
+    /// can be used for another [init] after [shutdown].
+    pub fn set_allocator(&mut self, allocator: Box<dyn Allocator>) {
+        crate::allocator::INTERFACES.release(self.allocator);
+        self.allocator = crate::allocator::into_raw(allocator);
+    }
+}
+
+impl Drop for Init {
+    fn drop(&mut self) {
+        crate::callback::INTERFACES.release(self.callback);
+        crate::allocator::INTERFACES.release(self.allocator);
+    }
+}
+
 pub struct BuiltVertexLayout(VertexLayoutBuilder);
 
 impl VertexLayoutBuilder {
@@ -5832,14 +6562,232 @@ impl VertexLayoutBuilder {
     }
 }
 
-/// Returns the number of uniforms and uniform handles used inside a shader.
-///
-/// Notice that only non-predefined uniforms are returned.
+impl BuiltVertexLayout {
+    /// Returns the size of a single vertex in bytes.
+    pub fn stride(&self) -> u16 {
+        self.0.stride
+    }
+
+    /// Returns `true` if the layout contains `attrib`.
+    pub fn has(&self, attrib: Attrib) -> bool {
+        attrib != Attrib::Count && self.0.attributes[attrib as usize] != u16::MAX
+    }
+
+    /// Returns the offset of `attrib` within a vertex, or `None` if the layout doesn't contain
+    /// it.
+    pub fn offset(&self, attrib: Attrib) -> Option<u16> {
+        if self.has(attrib) {
+            Some(self.0.offset[attrib as usize])
+        } else {
+            None
+        }
+    }
+
+    /// Returns the number of whole vertices in `data`.
+    pub fn num_vertices(&self, data: &[u8]) -> u32 {
+        data.len().checked_div(self.0.stride as usize).unwrap_or(0) as u32
+    }
+
+    fn as_raw(&self) -> *const bgfx_sys::bgfx_vertex_layout_t {
+        &self.0 as *const VertexLayoutBuilder as *const _
+    }
+
+    fn check_index(&self, data: &[u8], index: u32) {
+        assert!(
+            index < self.num_vertices(data),
+            "vertex index {} out of range for {} bytes of vertex data",
+            index,
+            data.len()
+        );
+    }
+
+    /// Packs `input` into `attrib` of vertex `index` in `data`, converting it to the attribute
+    /// type of the layout. Does nothing if the layout doesn't contain `attrib`.
+    ///
+    /// Set `normalized` if the input values are normalized (in range -1.0 to 1.0) and should be
+    /// packed as such into attributes of a fixed point type.
+    ///
+    /// Panics if `data` doesn't hold vertex `index`.
+    pub fn pack(
+        &self,
+        attrib: Attrib,
+        input: &[f32; 4],
+        normalized: bool,
+        data: &mut [u8],
+        index: u32,
+    ) {
+        self.check_index(data, index);
+        if !self.has(attrib) {
+            return;
+        }
+        unsafe {
+            bgfx_sys::bgfx_vertex_pack(
+                input.as_ptr(),
+                normalized,
+                attrib as _,
+                self.as_raw(),
+                data.as_mut_ptr() as *mut c_void,
+                index,
+            );
+        }
+    }
+
+    /// Unpacks `attrib` of vertex `index` in `data` to floats. Components that are not part of
+    /// the attribute, or all of them if the layout doesn't contain `attrib`, are 0.0.
+    ///
+    /// [AttribType::Uint8] and [AttribType::Int16] attributes always unpack to normalized
+    /// values: in range 0.0 to 1.0, or -1.0 to 1.0 if they were added with [AddArgs::as_int].
+    ///
+    /// Panics if `data` doesn't hold vertex `index`.
+    pub fn unpack(&self, attrib: Attrib, data: &[u8], index: u32) -> [f32; 4] {
+        self.check_index(data, index);
+        let mut output = [0.0; 4];
+        if !self.has(attrib) {
+            return output;
+        }
+        unsafe {
+            bgfx_sys::bgfx_vertex_unpack(
+                output.as_mut_ptr(),
+                attrib as _,
+                self.as_raw(),
+                data.as_ptr() as *const c_void,
+                index,
+            );
+        }
+        output
+    }
+
+    /// Converts `data` from `src_layout` to this layout. Attributes that are missing from
+    /// `src_layout` are zeroed.
+    pub fn convert(&self, src_layout: &BuiltVertexLayout, data: &[u8]) -> Vec<u8> {
+        let num = src_layout.num_vertices(data);
+        let mut output = vec![0u8; num as usize * self.0.stride as usize];
+        unsafe {
+            bgfx_sys::bgfx_vertex_convert(
+                self.as_raw(),
+                output.as_mut_ptr() as *mut c_void,
+                src_layout.as_raw(),
+                data.as_ptr() as *const c_void,
+                num,
+            );
+        }
+        output
+    }
+
+    /// Finds vertices in `data` with the same position, within a distance of `epsilon`.
+    ///
+    /// Returns a remapping table with an entry for each vertex, holding the index of the vertex
+    /// it was welded to. Unique vertices are the ones that map to themselves, and each one is
+    /// the first vertex at its position.
+    ///
+    /// Unlike `bgfx::weldVertices` this doesn't use the bgfx allocator, so it can be called
+    /// without bgfx being initialized.
+    ///
+    /// Panics if the layout doesn't contain [Attrib::Position].
+    pub fn weld(&self, data: &[u8], epsilon: f32) -> Vec<u32> {
+        use std::collections::HashMap;
+
+        assert!(
+            self.has(Attrib::Position),
+            "vertex layout has no position to weld"
+        );
+        let num = self.num_vertices(data);
+        let epsilon_sq = epsilon * epsilon;
+        // Positions are bucketed into cells of `epsilon` size, so matches can only be in the
+        // same or a neighbouring cell. Without an epsilon only exact matches are welded.
+        let cell = |pos: &[f32; 4]| -> [i64; 3] {
+            let mut key = [0; 3];
+            for (k, p) in key.iter_mut().zip(pos) {
+                *k = if epsilon > 0.0 {
+                    (p / epsilon).floor() as i64
+                } else {
+                    // Adding 0.0 turns -0.0 into 0.0.
+                    (p + 0.0).to_bits() as i64
+                };
+            }
+            key
+        };
+        let range = if epsilon > 0.0 { -1..=1 } else { 0..=0 };
+
+        let mut cells: HashMap<[i64; 3], Vec<u32>> = HashMap::new();
+        let mut output = Vec::with_capacity(num as usize);
+        for index in 0..num {
+            let pos = self.unpack(Attrib::Position, data, index);
+            let key = cell(&pos);
+
+            let mut found = None;
+            'search: for x in range.clone() {
+                for y in range.clone() {
+                    for z in range.clone() {
+                        let neighbour = [
+                            key[0].wrapping_add(x),
+                            key[1].wrapping_add(y),
+                            key[2].wrapping_add(z),
+                        ];
+                        for &other in cells.get(&neighbour).into_iter().flatten() {
+                            let test = self.unpack(Attrib::Position, data, other);
+                            let dist_sq: f32 = (0..3).map(|i| (test[i] - pos[i]).powi(2)).sum();
+                            if dist_sq <= epsilon_sq {
+                                found = Some(other);
+                                break 'search;
+                            }
+                        }
+                    }
+                }
+            }
+
+            output.push(found.unwrap_or_else(|| {
+                cells.entry(key).or_default().push(index);
+                index
+            }));
+        }
+        output
+    }
+}
 
 impl Shader {
-    //pub fn get_uniforms(&self, uniforms: &mut [Uniform]) -> u16 {
-    //	unsafe { bgfx_sys::bgfx_get_shader_uniforms(self.handle, uniforms.as_ptr(), uniforms.len() as u16) }
-    //}
+    /// Returns the uniforms used inside the shader, for example to bind material parameters
+    /// by name using [Uniform::info].
+    ///
+    /// Notice that only non-predefined uniforms are returned.
+    pub fn uniforms(&self) -> Vec<Uniform> {
+        unsafe {
+            let num = bgfx_sys::bgfx_get_shader_uniforms(self.handle, std::ptr::null_mut(), 0);
+            let mut handles = vec![bgfx_sys::bgfx_uniform_handle_t { idx: u16::MAX }; num as usize];
+            let num = bgfx_sys::bgfx_get_shader_uniforms(self.handle, handles.as_mut_ptr(), num);
+            handles.truncate(num as usize);
+            handles
+                .into_iter()
+                .map(|handle| {
+                    // The shader holds its own reference to the uniforms, so take another one
+                    // for the returned handle by creating the uniform again.
+                    let info = uniform_info(handle);
+                    Uniform::create(&info.name, info.type_r, info.num)
+                })
+                .collect()
+        }
+    }
+}
+
+impl Uniform {
+    /// Returns the name, type and number of elements of the uniform.
+    pub fn info(&self) -> UniformInfo {
+        uniform_info(self.handle)
+    }
+}
+
+fn uniform_info(handle: bgfx_sys::bgfx_uniform_handle_t) -> UniformInfo {
+    unsafe {
+        let mut info = MaybeUninit::<bgfx_sys::bgfx_uniform_info_t>::zeroed().assume_init();
+        bgfx_sys::bgfx_get_uniform_info(handle, &mut info);
+        UniformInfo {
+            name: std::ffi::CStr::from_ptr(info.name.as_ptr())
+                .to_string_lossy()
+                .into_owned(),
+            type_r: std::mem::transmute::<u32, UniformType>(info.type_),
+            num: info.num,
+        }
+    }
 }
 
 /// bgfx-managed buffer of memory.
@@ -5884,6 +6832,16 @@ impl Memory {
         );
         Memory { handle }
     }
+
+    /// Returns the data bgfx will consume, which is empty for [Memory::new].
+    pub(crate) fn as_slice(&self) -> &[u8] {
+        match unsafe { self.handle.as_ref() } {
+            Some(memory) if !memory.data.is_null() => unsafe {
+                std::slice::from_raw_parts(memory.data, memory.size as usize)
+            },
+            _ => &[],
+        }
+    }
 }
 
 /// * `x`:
@@ -5938,6 +6896,86 @@ impl Encoder {
     }
 }
 
+/// Token for the frame that is currently being submitted. Encoders and [Transient] buffers
+/// borrow it, so the frame can't be advanced with [Frame::frame] while any [EncoderGuard] is
+/// still open or transient buffer is still alive.
+pub struct Frame {
+    _not_send: std::marker::PhantomData<*const ()>,
+}
+
+// Encoders can be requested from any thread, only advancing the frame is tied to the API thread.
+unsafe impl Sync for Frame {}
+
+impl Frame {
+    /// Creates the frame token.
+    ///
+    /// # Safety
+    ///
+    /// bgfx must be initialized and this must be called on the API thread. Only one `Frame` may
+    /// exist at a time, and [frame] and [encoder_begin] must not be used while it's alive.
+    pub unsafe fn new() -> Frame {
+        Frame {
+            _not_send: std::marker::PhantomData,
+        }
+    }
+
+    /// Begins submitting draw calls through a dedicated encoder, which is ended when the
+    /// returned guard is dropped. The guard can be moved to a worker thread.
+    ///
+    /// Returns `None` when all encoders are in use. See: [InitLimits::max_encoders]
+    pub fn encoder(&self) -> Option<EncoderGuard<'_>> {
+        unsafe {
+            let _ret = bgfx_sys::bgfx_encoder_begin(true);
+            std::ptr::NonNull::new(_ret as *mut Encoder).map(|encoder| EncoderGuard {
+                encoder,
+                frame: self,
+            })
+        }
+    }
+
+    /// Advances to next frame and returns the current frame number. When `capture` is set the
+    /// frame is captured with the graphics debugger.
+    pub fn frame(&mut self, capture: bool) -> u32 {
+        // The frame is borrowed mutably, so nothing borrows it anymore.
+        #[allow(deprecated)]
+        unsafe {
+            frame(capture)
+        }
+    }
+}
+
+/// Encoder returned by [Frame::encoder]. Ends the encoder when dropped and derefs to [Encoder]
+/// for submitting draw calls.
+pub struct EncoderGuard<'frame> {
+    encoder: std::ptr::NonNull<Encoder>,
+    frame: &'frame Frame,
+}
+
+unsafe impl Send for EncoderGuard<'_> {}
+
+impl<'frame> EncoderGuard<'frame> {
+    /// Returns the frame the encoder submits to, for allocating [Transient] buffers.
+    pub fn frame(&self) -> &'frame Frame {
+        self.frame
+    }
+}
+
+impl std::ops::Deref for EncoderGuard<'_> {
+    type Target = Encoder;
+
+    fn deref(&self) -> &Encoder {
+        unsafe { self.encoder.as_ref() }
+    }
+}
+
+impl Drop for EncoderGuard<'_> {
+    fn drop(&mut self) {
+        unsafe {
+            bgfx_sys::bgfx_encoder_end(self.encoder.as_ptr() as _);
+        }
+    }
+}
+
 impl Uniform {
     /// * `name`:
     /// Uniform name in shader.
@@ -5946,10 +6984,14 @@ impl Uniform {
     /// * `num`:
     /// Number of elements in array.
     pub fn create(name: &str, type_r: UniformType, num: u16) -> Uniform {
+        let session = crate::context::creation_session();
         unsafe {
             let name_ = CFixedString::from_str(name);
             let _ret = bgfx_sys::bgfx_create_uniform(name_.as_ptr(), type_r as _, num);
-            Uniform { handle: _ret }
+            Uniform {
+                handle: _ret,
+                session,
+            }
         }
     }
 
@@ -5970,10 +7012,14 @@ impl Uniform {
 /// * `num`:
 /// Number of elements in array.
 pub fn create_uniform(name: &str, type_r: UniformType, num: u16) -> Uniform {
+    let session = crate::context::creation_session();
     unsafe {
         let name_ = CFixedString::from_str(name);
         let _ret = bgfx_sys::bgfx_create_uniform(name_.as_ptr(), type_r as _, num);
-        Uniform { handle: _ret }
+        Uniform {
+            handle: _ret,
+            session,
+        }
     }
 }
 
@@ -6069,3 +7115,25 @@ pub fn state_blend_linear_burn() -> u64 {
     state_blend_func(StateBlendFlags::DST_COLOR, StateBlendFlags::INV_DST_COLOR)
         | state_blend_equation(StateBlendEquationFlags::SUB)
 }
+
+/// Converts flags that are combined with other flags into the type taking them, such as
+/// [TextureRtFlags] into [TextureFlags], keeping the bits as they are.
+macro_rules! impl_flags_from {
+    ($($from:ident => $to:ident,)*) => {
+        $(
+            impl From<$from> for $to {
+                fn from(flags: $from) -> $to {
+                    unsafe { $to::from_bits_unchecked(flags.bits()) }
+                }
+            }
+        )*
+    };
+}
+
+impl_flags_from! {
+    BufferComputeFormatFlags => BufferFlags,
+    BufferComputeTypeFlags => BufferFlags,
+    TextureRtFlags => TextureFlags,
+    TextureRtMsaaFlags => TextureFlags,
+    ResetMsaaFlags => ResetFlags,
+}
@@ -0,0 +1,349 @@
+use crate::bgfx::{
+    Encoder, StencilFlags, StencilOpFailSFlags, StencilOpFailZFlags, StencilOpPassZFlags,
+    StencilTestFlags,
+};
+use crate::flag_bits::FlagBits;
+use std::fmt;
+
+/// Stencil test function, passing if the reference value compares to the stored value, both
+/// masked with [StencilState::read_mask].
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum StencilTest {
+    Less,
+    LessEqual,
+    Equal,
+    GreaterEqual,
+    Greater,
+    NotEqual,
+    Never,
+    Always,
+}
+
+impl StencilTest {
+    const ALL: &'static [StencilTest] = &[
+        StencilTest::Less,
+        StencilTest::LessEqual,
+        StencilTest::Equal,
+        StencilTest::GreaterEqual,
+        StencilTest::Greater,
+        StencilTest::NotEqual,
+        StencilTest::Never,
+        StencilTest::Always,
+    ];
+
+    /// Returns the stencil flags of the test.
+    pub fn flags(self) -> StencilTestFlags {
+        match self {
+            StencilTest::Less => StencilTestFlags::LESS,
+            StencilTest::LessEqual => StencilTestFlags::LEQUAL,
+            StencilTest::Equal => StencilTestFlags::EQUAL,
+            StencilTest::GreaterEqual => StencilTestFlags::GEQUAL,
+            StencilTest::Greater => StencilTestFlags::GREATER,
+            StencilTest::NotEqual => StencilTestFlags::NOTEQUAL,
+            StencilTest::Never => StencilTestFlags::NEVER,
+            StencilTest::Always => StencilTestFlags::ALWAYS,
+        }
+    }
+}
+
+/// Operation on the stored stencil value.
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum StencilOp {
+    /// Set the value to zero.
+    Zero,
+    /// Keep the value.
+    Keep,
+    /// Replace the value with [StencilState::reference].
+    Replace,
+    /// Increment the value, wrapping to zero.
+    Incr,
+    /// Increment the value, saturating at the maximum.
+    IncrSat,
+    /// Decrement the value, wrapping to the maximum.
+    Decr,
+    /// Decrement the value, saturating at zero.
+    DecrSat,
+    /// Invert the bits of the value.
+    Invert,
+}
+
+impl StencilOp {
+    const ALL: &'static [StencilOp] = &[
+        StencilOp::Zero,
+        StencilOp::Keep,
+        StencilOp::Replace,
+        StencilOp::Incr,
+        StencilOp::IncrSat,
+        StencilOp::Decr,
+        StencilOp::DecrSat,
+        StencilOp::Invert,
+    ];
+
+    /// Returns the operation whose flags match.
+    fn find(matches: impl Fn(StencilOp) -> bool) -> Option<StencilOp> {
+        StencilOp::ALL.iter().copied().find(|&op| matches(op))
+    }
+
+    /// Returns the stencil flags of the operation when the stencil test fails.
+    pub fn fail_s_flags(self) -> StencilOpFailSFlags {
+        match self {
+            StencilOp::Zero => StencilOpFailSFlags::ZERO,
+            StencilOp::Keep => StencilOpFailSFlags::KEEP,
+            StencilOp::Replace => StencilOpFailSFlags::REPLACE,
+            StencilOp::Incr => StencilOpFailSFlags::INCR,
+            StencilOp::IncrSat => StencilOpFailSFlags::INCRSAT,
+            StencilOp::Decr => StencilOpFailSFlags::DECR,
+            StencilOp::DecrSat => StencilOpFailSFlags::DECRSAT,
+            StencilOp::Invert => StencilOpFailSFlags::INVERT,
+        }
+    }
+
+    /// Returns the stencil flags of the operation when the depth test fails.
+    pub fn fail_z_flags(self) -> StencilOpFailZFlags {
+        match self {
+            StencilOp::Zero => StencilOpFailZFlags::ZERO,
+            StencilOp::Keep => StencilOpFailZFlags::KEEP,
+            StencilOp::Replace => StencilOpFailZFlags::REPLACE,
+            StencilOp::Incr => StencilOpFailZFlags::INCR,
+            StencilOp::IncrSat => StencilOpFailZFlags::INCRSAT,
+            StencilOp::Decr => StencilOpFailZFlags::DECR,
+            StencilOp::DecrSat => StencilOpFailZFlags::DECRSAT,
+            StencilOp::Invert => StencilOpFailZFlags::INVERT,
+        }
+    }
+
+    /// Returns the stencil flags of the operation when both tests pass.
+    pub fn pass_z_flags(self) -> StencilOpPassZFlags {
+        match self {
+            StencilOp::Zero => StencilOpPassZFlags::ZERO,
+            StencilOp::Keep => StencilOpPassZFlags::KEEP,
+            StencilOp::Replace => StencilOpPassZFlags::REPLACE,
+            StencilOp::Incr => StencilOpPassZFlags::INCR,
+            StencilOp::IncrSat => StencilOpPassZFlags::INCRSAT,
+            StencilOp::Decr => StencilOpPassZFlags::DECR,
+            StencilOp::DecrSat => StencilOpPassZFlags::DECRSAT,
+            StencilOp::Invert => StencilOpPassZFlags::INVERT,
+        }
+    }
+}
+
+/// Stencil state of one side of the triangles, which converts to the stencil word passed to
+/// [Encoder::set_stencil], and back.
+///
+/// The default always passes and keeps the stored value, with a read mask of `0xff`:
+///
+/// ```ignore
+/// // Write 1 wherever the mesh is drawn.
+/// let mark = StencilState::default()
+///     .reference(1)
+///     .pass_z(StencilOp::Replace);
+/// encoder.set_stencil_state(&mark, None);
+///
+/// // Draw only where the stencil is 1.
+/// let masked = StencilState::default().test(StencilTest::Equal).reference(1);
+/// encoder.set_stencil_state(&masked, None);
+///
+/// assert_eq!(StencilState::from_bits(masked.to_bits()), Some(masked));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub struct StencilState {
+    /// Stencil test.
+    pub test: StencilTest,
+    /// Reference value of the test, and the value written by [StencilOp::Replace].
+    pub reference: u8,
+    /// Mask applied to the reference and stored value before testing.
+    pub read_mask: u8,
+    /// Operation when the stencil test fails.
+    pub fail_s: StencilOp,
+    /// Operation when the stencil test passes but the depth test fails.
+    pub fail_z: StencilOp,
+    /// Operation when both the stencil and depth tests pass.
+    pub pass_z: StencilOp,
+}
+
+impl Default for StencilState {
+    fn default() -> StencilState {
+        StencilState {
+            test: StencilTest::Always,
+            reference: 0,
+            read_mask: 0xff,
+            fail_s: StencilOp::Keep,
+            fail_z: StencilOp::Keep,
+            pass_z: StencilOp::Keep,
+        }
+    }
+}
+
+impl StencilState {
+    /// Sets the stencil test.
+    pub fn test(mut self, test: StencilTest) -> StencilState {
+        self.test = test;
+        self
+    }
+
+    /// Sets the reference value.
+    pub fn reference(mut self, reference: u8) -> StencilState {
+        self.reference = reference;
+        self
+    }
+
+    /// Sets the read mask.
+    pub fn read_mask(mut self, read_mask: u8) -> StencilState {
+        self.read_mask = read_mask;
+        self
+    }
+
+    /// Sets the operation when the stencil test fails.
+    pub fn fail_s(mut self, op: StencilOp) -> StencilState {
+        self.fail_s = op;
+        self
+    }
+
+    /// Sets the operation when the depth test fails.
+    pub fn fail_z(mut self, op: StencilOp) -> StencilState {
+        self.fail_z = op;
+        self
+    }
+
+    /// Sets the operation when both tests pass.
+    pub fn pass_z(mut self, op: StencilOp) -> StencilState {
+        self.pass_z = op;
+        self
+    }
+
+    /// Returns the stencil word to pass to [Encoder::set_stencil].
+    pub fn to_bits(&self) -> u32 {
+        ((self.reference as u32) << bgfx_sys::BGFX_STENCIL_FUNC_REF_SHIFT)
+            | ((self.read_mask as u32) << bgfx_sys::BGFX_STENCIL_FUNC_RMASK_SHIFT)
+            | self.test.flags().bits()
+            | self.fail_s.fail_s_flags().bits()
+            | self.fail_z.fail_z_flags().bits()
+            | self.pass_z.pass_z_flags().bits()
+    }
+
+    /// Decodes a stencil word as passed to [Encoder::set_stencil]. Returns `None` for
+    /// [StencilFlags::NONE], which disables the stencil test, or if any bits don't belong to a
+    /// valid setting.
+    pub fn from_bits(bits: u32) -> Option<StencilState> {
+        StencilState::decode_exact(bits)
+    }
+}
+
+impl FlagBits for StencilState {
+    type Bits = u32;
+
+    fn encode(&self) -> u32 {
+        self.to_bits()
+    }
+
+    fn decode(bits: u32) -> Option<StencilState> {
+        let test_bits = bits & StencilTestFlags::all().bits();
+        let fail_s_bits = bits & StencilOpFailSFlags::all().bits();
+        let fail_z_bits = bits & StencilOpFailZFlags::all().bits();
+        let pass_z_bits = bits & StencilOpPassZFlags::all().bits();
+
+        Some(StencilState {
+            test: StencilTest::ALL
+                .iter()
+                .copied()
+                .find(|test| test.flags().bits() == test_bits)?,
+            reference: ((bits & bgfx_sys::BGFX_STENCIL_FUNC_REF_MASK)
+                >> bgfx_sys::BGFX_STENCIL_FUNC_REF_SHIFT) as u8,
+            read_mask: ((bits & bgfx_sys::BGFX_STENCIL_FUNC_RMASK_MASK)
+                >> bgfx_sys::BGFX_STENCIL_FUNC_RMASK_SHIFT) as u8,
+            fail_s: StencilOp::find(|op| op.fail_s_flags().bits() == fail_s_bits)?,
+            fail_z: StencilOp::find(|op| op.fail_z_flags().bits() == fail_z_bits)?,
+            pass_z: StencilOp::find(|op| op.pass_z_flags().bits() == pass_z_bits)?,
+        })
+    }
+}
+
+impl From<StencilState> for u32 {
+    fn from(state: StencilState) -> u32 {
+        state.to_bits()
+    }
+}
+
+impl fmt::Display for StencilState {
+    /// Formats the state as `test: Equal, reference: 1, read_mask: 0xff, fail_s: Keep,
+    /// fail_z: Keep, pass_z: Replace`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "test: {:?}, reference: {}, read_mask: {:#04x}, fail_s: {:?}, fail_z: {:?}, pass_z: {:?}",
+            self.test, self.reference, self.read_mask, self.fail_s, self.fail_z, self.pass_z
+        )
+    }
+}
+
+impl Encoder {
+    /// Sets the stencil state for draw primitive, see [Encoder::set_stencil]. `front` applies
+    /// to front facing triangles, and `back` to back facing ones. With `None` the back state is
+    /// [StencilFlags::NONE], which makes bgfx apply `front` to both.
+    ///
+    /// Use [Encoder::set_stencil] with [StencilFlags::NONE] to disable the stencil test.
+    pub fn set_stencil_state(&self, front: &StencilState, back: Option<&StencilState>) {
+        let back = back.map_or(StencilFlags::NONE.bits(), StencilState::to_bits);
+        self.set_stencil(front.to_bits(), back);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::flag_bits::assert_round_trip as round_trip;
+
+    #[test]
+    fn default() {
+        let state = StencilState::default();
+        assert_eq!(
+            state.to_bits(),
+            StencilTestFlags::ALWAYS.bits()
+                | (0xff << bgfx_sys::BGFX_STENCIL_FUNC_RMASK_SHIFT)
+                | StencilOpFailSFlags::KEEP.bits()
+                | StencilOpFailZFlags::KEEP.bits()
+                | StencilOpPassZFlags::KEEP.bits()
+        );
+        round_trip(state);
+    }
+
+    #[test]
+    fn tests_and_values() {
+        for &test in StencilTest::ALL {
+            for (reference, read_mask) in [(0, 0), (1, 0xff), (0x80, 0x0f), (0xff, 0xaa)] {
+                round_trip(
+                    StencilState::default()
+                        .test(test)
+                        .reference(reference)
+                        .read_mask(read_mask),
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn ops() {
+        for &op in StencilOp::ALL {
+            round_trip(StencilState::default().fail_s(op).reference(3));
+            round_trip(StencilState::default().fail_z(op).read_mask(0x7f));
+            round_trip(StencilState::default().pass_z(op).reference(0xff));
+        }
+    }
+
+    #[test]
+    fn none_is_rejected() {
+        assert_eq!(StencilState::from_bits(StencilFlags::NONE.bits()), None);
+    }
+
+    #[test]
+    fn unknown_bits_are_rejected() {
+        let bits = StencilState::default().to_bits();
+        // Test 9 and operation 8 are the first values past the known ones.
+        let unknown_test =
+            (bits & !bgfx_sys::BGFX_STENCIL_TEST_MASK) | (9 << bgfx_sys::BGFX_STENCIL_TEST_SHIFT);
+        assert_eq!(StencilState::from_bits(unknown_test), None);
+        let unknown_op = (bits & !bgfx_sys::BGFX_STENCIL_OP_PASS_Z_MASK)
+            | (8 << bgfx_sys::BGFX_STENCIL_OP_PASS_Z_SHIFT);
+        assert_eq!(StencilState::from_bits(unknown_op), None);
+    }
+}
@@ -0,0 +1,401 @@
+//! Golden-image tests for render output. Requires the `testing` feature.
+//!
+//! [assert_golden] renders a closure with [Headless] and compares the result against a PNG on
+//! disk:
+//!
+//! ```ignore
+//! #[test]
+//! fn clear_color() {
+//!     bgfx_rs::testing::assert_golden("tests/golden/clear.png", 64, 64, 2, |_headless| {
+//!         bgfx::set_view_clear(0, ClearFlags::COLOR, SetViewClearArgs::default());
+//!         bgfx::touch(0);
+//!     });
+//! }
+//! ```
+//!
+//! Run the tests with the `BGFX_UPDATE_GOLDEN` environment variable set to write the rendered
+//! images as the new golden images. Comparison and PNG I/O work on [RgbaImage]s and don't need
+//! bgfx.
+
+use crate::bgfx::{Headless, RendererType, RgbaImage};
+use std::fmt;
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+/// Error loading or saving a PNG.
+#[derive(Debug)]
+pub enum ImageError {
+    /// The file could not be read or written.
+    Io(std::io::Error),
+    /// The file is not a valid PNG.
+    Decoding(png::DecodingError),
+    /// The image could not be encoded.
+    Encoding(png::EncodingError),
+}
+
+impl fmt::Display for ImageError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ImageError::Io(err) => write!(f, "{}", err),
+            ImageError::Decoding(err) => write!(f, "failed to decode PNG: {}", err),
+            ImageError::Encoding(err) => write!(f, "failed to encode PNG: {}", err),
+        }
+    }
+}
+
+impl std::error::Error for ImageError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ImageError::Io(err) => Some(err),
+            ImageError::Decoding(err) => Some(err),
+            ImageError::Encoding(err) => Some(err),
+        }
+    }
+}
+
+impl From<std::io::Error> for ImageError {
+    fn from(err: std::io::Error) -> ImageError {
+        ImageError::Io(err)
+    }
+}
+
+impl From<png::DecodingError> for ImageError {
+    fn from(err: png::DecodingError) -> ImageError {
+        ImageError::Decoding(err)
+    }
+}
+
+impl From<png::EncodingError> for ImageError {
+    fn from(err: png::EncodingError) -> ImageError {
+        ImageError::Encoding(err)
+    }
+}
+
+/// Loads a PNG as an RGBA image. Grayscale, RGB and paletted images are converted, and 16-bit
+/// channels are reduced to 8 bits.
+pub fn load_png<P: AsRef<Path>>(path: P) -> Result<RgbaImage, ImageError> {
+    let mut decoder = png::Decoder::new(BufReader::new(File::open(path)?));
+    decoder.set_transformations(png::Transformations::normalize_to_color8());
+    let mut reader = decoder.read_info()?;
+    let mut buf = vec![0; reader.output_buffer_size()];
+    let info = reader.next_frame(&mut buf)?;
+    buf.truncate(info.buffer_size());
+
+    let data = match info.color_type {
+        png::ColorType::Rgba => buf,
+        png::ColorType::Rgb => buf
+            .chunks_exact(3)
+            .flat_map(|c| [c[0], c[1], c[2], 255])
+            .collect(),
+        png::ColorType::GrayscaleAlpha => buf
+            .chunks_exact(2)
+            .flat_map(|c| [c[0], c[0], c[0], c[1]])
+            .collect(),
+        // Palettes are expanded to RGB or RGBA by the transformations.
+        png::ColorType::Grayscale | png::ColorType::Indexed => {
+            buf.iter().flat_map(|&c| [c, c, c, 255]).collect()
+        }
+    };
+    Ok(RgbaImage::new(info.width, info.height, data))
+}
+
+/// Saves `image` as an RGBA PNG.
+pub fn save_png<P: AsRef<Path>>(path: P, image: &RgbaImage) -> Result<(), ImageError> {
+    let file = BufWriter::new(File::create(path)?);
+    let mut encoder = png::Encoder::new(file, image.width, image.height);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(&image.data)?;
+    writer.finish()?;
+    Ok(())
+}
+
+/// Difference between two images, returned by [compare].
+#[derive(Clone, Debug)]
+pub enum Mismatch {
+    /// The images have different sizes.
+    Size {
+        /// Width and height of the expected image.
+        expected: (u32, u32),
+        /// Width and height of the actual image.
+        actual: (u32, u32),
+    },
+    /// Some pixels differ by more than the tolerance.
+    Pixels {
+        /// Number of pixels that differ.
+        count: usize,
+        /// Largest difference of any channel.
+        max_difference: u8,
+        /// Image of the differing pixels in red, over a faded copy of the expected image.
+        diff: RgbaImage,
+    },
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Mismatch::Size { expected, actual } => write!(
+                f,
+                "image is {}x{}, expected {}x{}",
+                actual.0, actual.1, expected.0, expected.1
+            ),
+            Mismatch::Pixels {
+                count,
+                max_difference,
+                ..
+            } => write!(
+                f,
+                "{} pixels differ, by up to {} in a channel",
+                count, max_difference
+            ),
+        }
+    }
+}
+
+/// Compares `actual` against `expected`, allowing each channel of a pixel to differ by up to
+/// `tolerance`.
+pub fn compare(expected: &RgbaImage, actual: &RgbaImage, tolerance: u8) -> Result<(), Mismatch> {
+    if (expected.width, expected.height) != (actual.width, actual.height) {
+        return Err(Mismatch::Size {
+            expected: (expected.width, expected.height),
+            actual: (actual.width, actual.height),
+        });
+    }
+
+    let mut count = 0;
+    let mut max_difference = 0;
+    let mut diff = Vec::with_capacity(expected.data.len());
+    for (e, a) in expected
+        .data
+        .chunks_exact(4)
+        .zip(actual.data.chunks_exact(4))
+    {
+        let difference = e.iter().zip(a).map(|(e, a)| e.abs_diff(*a)).max().unwrap();
+        max_difference = max_difference.max(difference);
+        if difference > tolerance {
+            count += 1;
+            diff.extend_from_slice(&[255, 0, 0, 255]);
+        } else {
+            let luma = ((e[0] as u32 * 2 + e[1] as u32 * 5 + e[2] as u32) / 8 / 4) as u8;
+            diff.extend_from_slice(&[luma, luma, luma, 255]);
+        }
+    }
+
+    if count == 0 {
+        Ok(())
+    } else {
+        Err(Mismatch::Pixels {
+            count,
+            max_difference,
+            diff: RgbaImage::new(expected.width, expected.height, diff),
+        })
+    }
+}
+
+/// bgfx can only be initialized once per process, so renders from parallel tests take turns.
+static RENDER_LOCK: Mutex<()> = Mutex::new(());
+
+/// Renders a frame of `width` by `height` with [Headless] and returns the image.
+///
+/// View 0 is bound to the frame buffer before `render` is called, and other views can be bound
+/// with [Headless::set_view]. Returns `None` if bgfx fell back to the `Noop` renderer, or the
+/// renderer can't read back textures.
+///
+/// Panics if bgfx fails to initialize, or is already initialized outside of this module.
+pub fn render_image<F>(width: u16, height: u16, render: F) -> Option<RgbaImage>
+where
+    F: FnOnce(&mut Headless),
+{
+    let _lock = RENDER_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+    let mut headless = Headless::init(width, height).expect("failed to initialize bgfx");
+    if headless.renderer_type() == RendererType::Noop {
+        return None;
+    }
+    headless.set_view(0);
+    render(&mut headless);
+    headless.read_rgba()
+}
+
+/// Returns `path` with `suffix` added to the file name, before the extension.
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    path.with_file_name(format!("{}.{}.png", stem, suffix))
+}
+
+/// Renders a frame with [render_image] and compares it against the PNG at `path`, allowing
+/// each channel to differ by up to `tolerance`.
+///
+/// On failure the rendered image and a diff image are written next to the golden image, with
+/// `.actual.png` and `.diff.png` in place of the extension, and the test panics. When the
+/// `BGFX_UPDATE_GOLDEN` environment variable is set, the rendered image is written to `path`
+/// instead.
+///
+/// The test is skipped with a message if there is no renderer that can read back images, such
+/// as on machines without a GPU where bgfx falls back to `Noop`.
+pub fn assert_golden<P, F>(path: P, width: u16, height: u16, tolerance: u8, render: F)
+where
+    P: AsRef<Path>,
+    F: FnOnce(&mut Headless),
+{
+    let path = path.as_ref();
+    let actual = match render_image(width, height, render) {
+        Some(image) => image,
+        None => {
+            eprintln!(
+                "skipping golden image test {}: no renderer can read back images",
+                path.display()
+            );
+            return;
+        }
+    };
+
+    if std::env::var_os("BGFX_UPDATE_GOLDEN").is_some() {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).expect("failed to create golden image directory");
+        }
+        save_png(path, &actual).expect("failed to write golden image");
+        return;
+    }
+
+    let expected = load_png(path).unwrap_or_else(|err| {
+        panic!(
+            "failed to load golden image {}: {} (set BGFX_UPDATE_GOLDEN to create it)",
+            path.display(),
+            err
+        )
+    });
+    if let Err(mismatch) = compare(&expected, &actual, tolerance) {
+        let actual_path = with_suffix(path, "actual");
+        save_png(&actual_path, &actual).expect("failed to write rendered image");
+        let mut message = format!(
+            "{} doesn't match the golden image: {}\nrendered image: {}",
+            path.display(),
+            mismatch,
+            actual_path.display()
+        );
+        if let Mismatch::Pixels { diff, .. } = &mismatch {
+            let diff_path = with_suffix(path, "diff");
+            save_png(&diff_path, diff).expect("failed to write diff image");
+            message += &format!("\ndiff image: {}", diff_path.display());
+        }
+        panic!("{}", message);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 2x2 image with a different color in each pixel.
+    fn image() -> RgbaImage {
+        RgbaImage::new(
+            2,
+            2,
+            vec![
+                255, 0, 0, 255, 0, 255, 0, 255, 0, 0, 255, 255, 16, 32, 64, 128,
+            ],
+        )
+    }
+
+    /// Path in the temporary directory, unique to this process.
+    fn temp_path(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("bgfx-rs-{}-{}", std::process::id(), name))
+    }
+
+    #[test]
+    fn compare_equal() {
+        assert!(compare(&image(), &image(), 0).is_ok());
+    }
+
+    #[test]
+    fn compare_size() {
+        let actual = RgbaImage::new(1, 2, vec![0; 8]);
+        match compare(&image(), &actual, 255) {
+            Err(Mismatch::Size { expected, actual }) => {
+                assert_eq!(expected, (2, 2));
+                assert_eq!(actual, (1, 2));
+            }
+            other => panic!("unexpected result {:?}", other),
+        }
+    }
+
+    #[test]
+    fn compare_tolerance() {
+        let mut actual = image();
+        actual.data[13] += 3;
+        actual.data[14] -= 2;
+        assert!(compare(&image(), &actual, 3).is_ok());
+        match compare(&image(), &actual, 2) {
+            Err(Mismatch::Pixels {
+                count,
+                max_difference,
+                ..
+            }) => {
+                assert_eq!(count, 1);
+                assert_eq!(max_difference, 3);
+            }
+            other => panic!("unexpected result {:?}", other),
+        }
+    }
+
+    #[test]
+    fn compare_diff_image() {
+        let mut actual = image();
+        actual.data[0] = 0;
+        let diff = match compare(&image(), &actual, 0) {
+            Err(Mismatch::Pixels { diff, .. }) => diff,
+            other => panic!("unexpected result {:?}", other),
+        };
+        assert_eq!((diff.width, diff.height), (2, 2));
+        // The differing pixel is red, the others are faded grays of the expected image.
+        assert_eq!(
+            diff.data,
+            [255, 0, 0, 255, 39, 39, 39, 255, 7, 7, 7, 255, 8, 8, 8, 255]
+        );
+    }
+
+    #[test]
+    fn png_round_trip() {
+        let path = temp_path("round_trip.png");
+        save_png(&path, &image()).unwrap();
+        let loaded = load_png(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(loaded.unwrap(), image());
+    }
+
+    #[test]
+    fn load_rgb_png() {
+        let path = temp_path("rgb.png");
+        let mut encoder = png::Encoder::new(File::create(&path).unwrap(), 2, 1);
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+        let mut writer = encoder.write_header().unwrap();
+        writer.write_image_data(&[1, 2, 3, 4, 5, 6]).unwrap();
+        writer.finish().unwrap();
+        let loaded = load_png(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(
+            loaded.unwrap(),
+            RgbaImage::new(2, 1, vec![1, 2, 3, 255, 4, 5, 6, 255])
+        );
+    }
+
+    #[test]
+    fn load_missing_png() {
+        assert!(matches!(
+            load_png(temp_path("missing.png")),
+            Err(ImageError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn suffix() {
+        assert_eq!(
+            with_suffix(Path::new("tests/golden/clear.png"), "diff"),
+            Path::new("tests/golden/clear.diff.png")
+        );
+    }
+}
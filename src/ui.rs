@@ -0,0 +1,128 @@
+use crate::bgfx::{
+    self, get_caps, AddArgs, Attrib, AttribType, BuiltVertexLayout, EmbeddedShader, Encoder, Pod,
+    Program, Sampler, ShaderBundle, ShaderBundleError, SubmitArgs, Texture, Uniform, UniformType,
+    VertexLayoutBuilder, ViewId, ViewMode,
+};
+use crate::math::{ortho, IDENTITY};
+use crate::shader::embedded_shaders;
+
+/// Shaders multiplying the vertex color with the texture, and its alpha with `u_params.w`.
+static SHADERS: &[EmbeddedShader] = embedded_shaders!("imgui");
+
+/// Vertex of the UI meshes drawn by the ImGui renderer and the egui painter.
+#[repr(C)]
+#[derive(Clone, Copy)]
+pub(crate) struct UiVertex {
+    pub(crate) pos: [f32; 2],
+    pub(crate) uv: [f32; 2],
+    pub(crate) col: [u8; 4],
+}
+
+unsafe impl Pod for UiVertex {}
+
+/// Program, uniforms and vertex layout for drawing textured UI meshes in screen space.
+pub(crate) struct UiPipeline {
+    program: Program,
+    sampler: Uniform,
+    params: Uniform,
+    layout: BuiltVertexLayout,
+}
+
+impl UiPipeline {
+    pub(crate) fn new() -> Result<UiPipeline, ShaderBundleError> {
+        let program = ShaderBundle::embedded(SHADERS).create_program("vs_imgui", "fs_imgui")?;
+        let layout = VertexLayoutBuilder::begin(bgfx::get_renderer_type())
+            .add(Attrib::Position, 2, AttribType::Float, AddArgs::default())
+            .add(Attrib::TexCoord0, 2, AttribType::Float, AddArgs::default())
+            .add(
+                Attrib::Color0,
+                4,
+                AttribType::Uint8,
+                AddArgs {
+                    normalized: true,
+                    as_int: false,
+                },
+            )
+            .end();
+        Ok(UiPipeline {
+            program,
+            sampler: Uniform::create("s_texColor", UniformType::Sampler, 1),
+            params: Uniform::create("u_params", UniformType::Vec4, 1),
+            layout,
+        })
+    }
+
+    /// Returns the layout of [UiVertex].
+    pub(crate) fn layout(&self) -> &BuiltVertexLayout {
+        &self.layout
+    }
+
+    /// Sets up `view` to draw in order into `size_px` framebuffer pixels, mapping the
+    /// `[left, top, right, bottom]` rectangle of UI coordinates onto them.
+    pub(crate) fn setup_view(&self, view: ViewId, size_px: [f32; 2], rect: [f32; 4]) {
+        let [left, top, right, bottom] = rect;
+        bgfx::set_view_mode(view, ViewMode::Sequential);
+        bgfx::set_view_rect(view, 0, 0, size_px[0] as u16, size_px[1] as u16);
+        bgfx::set_view_transform(
+            view,
+            &IDENTITY,
+            &ortho(left, right, bottom, top, get_caps().homogeneous_depth),
+        );
+    }
+
+    /// Submits a mesh drawn with `texture` to `view`, once its transient buffers are set.
+    pub(crate) fn submit(
+        &self,
+        encoder: &Encoder,
+        view: ViewId,
+        scissor: [u16; 4],
+        state: u64,
+        texture: &Texture,
+    ) {
+        let [x, y, width, height] = scissor;
+        encoder.set_scissor(x, y, width, height);
+        encoder.set_state(state, 0);
+        encoder.set_texture(0, &self.sampler, texture, Sampler::Inherit);
+        encoder.set_uniform(&self.params, &[0.0, 0.0, 0.0, 1.0], 1);
+        encoder.submit(view, &self.program, SubmitArgs::default());
+    }
+}
+
+/// Returns the scissor `[x, y, width, height]` of a clip rectangle, given as `[left, top, right,
+/// bottom]` in framebuffer pixels, clamped to a framebuffer of `size_px` pixels. Returns `None`
+/// if nothing of it is left to draw.
+pub(crate) fn scissor(clip_rect: [f32; 4], size_px: [f32; 2]) -> Option<[u16; 4]> {
+    let left = clip_rect[0].max(0.0);
+    let top = clip_rect[1].max(0.0);
+    let right = clip_rect[2].min(size_px[0]);
+    let bottom = clip_rect[3].min(size_px[1]);
+    if right <= left || bottom <= top {
+        return None;
+    }
+    Some([
+        left as u16,
+        top as u16,
+        (right - left) as u16,
+        (bottom - top) as u16,
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scissor_clamped_to_framebuffer() {
+        let size = [100.0, 50.0];
+        assert_eq!(
+            scissor([10.0, 5.0, 30.0, 25.0], size),
+            Some([10, 5, 20, 20])
+        );
+        assert_eq!(
+            scissor([-10.0, -5.0, 200.0, 80.0], size),
+            Some([0, 0, 100, 50])
+        );
+        assert_eq!(scissor([30.0, 5.0, 30.0, 25.0], size), None);
+        assert_eq!(scissor([120.0, 5.0, 150.0, 25.0], size), None);
+    }
+}
@@ -0,0 +1,94 @@
+use crate::bgfx::{AttribType, BuiltVertexLayout, RendererType};
+
+/// Vertex type with a matching [BuiltVertexLayout].
+///
+/// With the `derive` feature enabled this can be derived for `#[repr(C)]` structs, with the
+/// attribute of each field given by `#[attrib(...)]`:
+///
+/// ```ignore
+/// #[derive(Clone, Copy, Vertex)]
+/// #[repr(C, packed)]
+/// struct PosColorVertex {
+///     #[attrib(Position)]
+///     pos: [f32; 3],
+///     #[attrib(Color0, normalized)]
+///     abgr: u32,
+/// }
+///
+/// let layout = PosColorVertex::layout(bgfx::get_renderer_type());
+/// ```
+///
+/// The attribute type and number of elements come from the [VertexAttribute] implementation of
+/// the field type, and can be set explicitly with `ty` and `num`, such as
+/// `#[attrib(TexCoord0, ty = Half, num = 2)]` for a `[u16; 2]` field. `normalized` and
+/// `as_int` set the [AddArgs](crate::bgfx::AddArgs) of the attribute. Fields without
+/// `#[attrib]` are skipped as padding.
+///
+/// Deriving also implements [Pod](crate::bgfx::Pod), so the struct must be `Copy` and made of
+/// `Pod` fields. It fails to compile if the size of a field doesn't match the size bgfx uses for
+/// its attribute, such as a `[u8; 3]` field which bgfx pads to 4 bytes, or if the struct has
+/// padding between or after its fields. 3 element `Int16` and `Half` attributes are rejected,
+/// as their size depends on the renderer.
+pub trait Vertex: Sized {
+    /// Builds the layout of the vertex for `renderer_type`.
+    fn layout(renderer_type: RendererType) -> BuiltVertexLayout;
+}
+
+/// Type of a vertex field, used by `#[derive(Vertex)]` to add the attribute of a field.
+pub trait VertexAttribute {
+    /// Element type.
+    const TYPE: AttribType;
+    /// Number of elements 1, 2, 3 or 4.
+    const NUM: u8;
+}
+
+macro_rules! impl_vertex_attribute {
+    ($ty:ty, $attrib_type:ident) => {
+        impl VertexAttribute for $ty {
+            const TYPE: AttribType = AttribType::$attrib_type;
+            const NUM: u8 = 1;
+        }
+        impl_vertex_attribute!([$ty; 1], $attrib_type, 1);
+        impl_vertex_attribute!([$ty; 2], $attrib_type, 2);
+        impl_vertex_attribute!([$ty; 3], $attrib_type, 3);
+        impl_vertex_attribute!([$ty; 4], $attrib_type, 4);
+    };
+    ($ty:ty, $attrib_type:ident, $num:expr) => {
+        impl VertexAttribute for $ty {
+            const TYPE: AttribType = AttribType::$attrib_type;
+            const NUM: u8 = $num;
+        }
+    };
+}
+
+impl_vertex_attribute!(f32, Float);
+impl_vertex_attribute!(u8, Uint8);
+impl_vertex_attribute!(i16, Int16);
+// Packed colors such as ABGR.
+impl_vertex_attribute!(u32, Uint8, 4);
+
+/// Smallest size in bytes bgfx uses for an attribute, used to check the size of derived
+/// vertices at compile time. Apart from Direct3D 9, this is the size on every renderer for the
+/// attributes that [attrib_size_varies] accepts.
+#[doc(hidden)]
+pub const fn attrib_size(type_r: AttribType, num: u8) -> usize {
+    match type_r {
+        AttribType::Uint8 => match num {
+            1 => 1,
+            2 => 2,
+            _ => 4,
+        },
+        AttribType::Uint10 => 4,
+        AttribType::Int16 | AttribType::Half => 2 * num as usize,
+        AttribType::Float => 4 * num as usize,
+        AttribType::Count => 0,
+    }
+}
+
+/// Returns `true` if renderers give the attribute different sizes, so no field can match all of
+/// them. 3 `Int16` or `Half` elements are 6 bytes with OpenGL and Metal, but padded to 8 bytes
+/// with Direct3D 11, Direct3D 12 and Vulkan.
+#[doc(hidden)]
+pub const fn attrib_size_varies(type_r: AttribType, num: u8) -> bool {
+    matches!(type_r, AttribType::Int16 | AttribType::Half) && num == 3
+}
@@ -0,0 +1,150 @@
+use crate::bgfx::{
+    CreateFrameBufferFromNwhArgs, FrameBuffer, NativeWindowHandleType, PlatformData,
+};
+use core::ffi::c_void;
+use raw_window_handle::{
+    HasRawDisplayHandle, HasRawWindowHandle, RawDisplayHandle, RawWindowHandle,
+};
+use std::ffi::CString;
+use std::fmt;
+use std::sync::Mutex;
+
+/// Error returned by [PlatformData::from_window] and [FrameBuffer::from_window].
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum WindowHandleError {
+    /// The kind of window handle isn't supported by bgfx, such as `Orbital`. XCB windows are
+    /// unsupported too, as bgfx needs an Xlib display for X11.
+    UnsupportedWindow,
+    /// The display handle doesn't match the window handle, such as an Xlib window on a Wayland
+    /// display.
+    MismatchedDisplay,
+}
+
+impl fmt::Display for WindowHandleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WindowHandleError::UnsupportedWindow => write!(f, "unsupported window handle"),
+            WindowHandleError::MismatchedDisplay => {
+                write!(f, "display handle doesn't match the window handle")
+            }
+        }
+    }
+}
+
+impl std::error::Error for WindowHandleError {}
+
+/// Returns the CSS selector of the canvas with the raw handle `id`. Selectors are kept until the
+/// program exits, as bgfx may keep using them, and are created once per canvas.
+fn web_selector(id: u32) -> *const c_void {
+    static SELECTORS: Mutex<Vec<(u32, CString)>> = Mutex::new(Vec::new());
+    let mut selectors = SELECTORS.lock().unwrap();
+    if let Some((_, selector)) = selectors.iter().find(|(i, _)| *i == id) {
+        return selector.as_ptr() as *const c_void;
+    }
+    let selector = CString::new(format!("canvas[data-raw-handle=\"{}\"]", id)).unwrap();
+    // The string's buffer doesn't move when the vector grows.
+    let ptr = selector.as_ptr() as *const c_void;
+    selectors.push((id, selector));
+    ptr
+}
+
+/// Returns the native window handle bgfx expects for `handle`.
+fn native_window(handle: RawWindowHandle) -> Result<*const c_void, WindowHandleError> {
+    let nwh = match handle {
+        RawWindowHandle::Xlib(handle) => handle.window as *const c_void,
+        RawWindowHandle::Wayland(handle) => handle.surface,
+        RawWindowHandle::Win32(handle) => handle.hwnd,
+        // bgfx accepts either, the window is used if it's known.
+        RawWindowHandle::AppKit(handle) if !handle.ns_window.is_null() => handle.ns_window,
+        RawWindowHandle::AppKit(handle) => handle.ns_view,
+        RawWindowHandle::UiKit(handle) => handle.ui_view,
+        // bgfx selects the canvas by a CSS selector.
+        RawWindowHandle::Web(handle) => web_selector(handle.id),
+        _ => return Err(WindowHandleError::UnsupportedWindow),
+    };
+    Ok(nwh)
+}
+
+impl PlatformData {
+    /// Creates the platform data to initialize bgfx with `window`, from its
+    /// [raw-window-handle](https://crates.io/crates/raw-window-handle) handles. Requires the
+    /// `raw-window-handle` feature.
+    ///
+    /// On Wayland [PlatformData::type_r] is set to [NativeWindowHandleType::Wayland], with the
+    /// `wl_display` in [PlatformData::ndt] and the `wl_surface` in [PlatformData::nwh]. On X11
+    /// the Xlib display is set in [PlatformData::ndt]; XCB windows are unsupported.
+    ///
+    /// ```ignore
+    /// let mut init = Init::new();
+    /// init.platform_data = PlatformData::from_window(&window)?;
+    /// ```
+    pub fn from_window<W>(window: &W) -> Result<PlatformData, WindowHandleError>
+    where
+        W: HasRawWindowHandle + HasRawDisplayHandle,
+    {
+        let window_handle = window.raw_window_handle();
+        let ndt = match (window_handle, window.raw_display_handle()) {
+            (RawWindowHandle::Xlib(_), RawDisplayHandle::Xlib(display)) => display.display,
+            (RawWindowHandle::Wayland(_), RawDisplayHandle::Wayland(display)) => display.display,
+            (RawWindowHandle::Xlib(_), _) | (RawWindowHandle::Wayland(_), _) => {
+                return Err(WindowHandleError::MismatchedDisplay)
+            }
+            _ => std::ptr::null_mut(),
+        };
+
+        let mut pd = PlatformData::new();
+        pd.nwh = native_window(window_handle)?;
+        pd.ndt = ndt;
+        pd.type_r = match window_handle {
+            RawWindowHandle::Wayland(_) => NativeWindowHandleType::Wayland,
+            _ => NativeWindowHandleType::Default,
+        };
+        Ok(pd)
+    }
+}
+
+impl FrameBuffer {
+    /// Creates a frame buffer for an additional `window` with a back buffer of `width` by
+    /// `height`, through [FrameBuffer::create_frame_buffer_from_nwh]. Requires the
+    /// `raw-window-handle` feature.
+    pub fn from_window<W: HasRawWindowHandle>(
+        window: &W,
+        width: u16,
+        height: u16,
+        params: CreateFrameBufferFromNwhArgs,
+    ) -> Result<FrameBuffer, WindowHandleError> {
+        let nwh = native_window(window.raw_window_handle())?;
+        Ok(FrameBuffer::create_frame_buffer_from_nwh(
+            nwh, width, height, params,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use raw_window_handle::{WebWindowHandle, XcbWindowHandle};
+
+    #[test]
+    fn xcb_is_unsupported() {
+        let handle = RawWindowHandle::Xcb(XcbWindowHandle::empty());
+        assert_eq!(
+            native_window(handle),
+            Err(WindowHandleError::UnsupportedWindow)
+        );
+    }
+
+    #[test]
+    fn web_selector_is_reused() {
+        let mut handle = WebWindowHandle::empty();
+        handle.id = 7;
+        let first = native_window(RawWindowHandle::Web(handle)).unwrap();
+        let second = native_window(RawWindowHandle::Web(handle)).unwrap();
+        assert_eq!(first, second);
+        handle.id = 8;
+        assert_ne!(native_window(RawWindowHandle::Web(handle)).unwrap(), first);
+
+        let selector = unsafe { std::ffi::CStr::from_ptr(first as *const _) };
+        assert_eq!(selector.to_str(), Ok("canvas[data-raw-handle=\"7\"]"));
+    }
+}
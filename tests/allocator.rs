@@ -0,0 +1,48 @@
+#![cfg(not(feature = "shared-api"))]
+
+use bgfx::{Bgfx, BufferFlags, GlobalAllocAdapter, Init, Memory, RendererType};
+use bgfx_rs::bgfx;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicIsize, Ordering};
+use std::sync::Arc;
+
+/// Counts the blocks allocated through it that haven't been freed.
+#[derive(Clone, Default)]
+struct CountingAlloc {
+    live: Arc<AtomicIsize>,
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        self.live.fetch_add(1, Ordering::SeqCst);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        self.live.fetch_sub(1, Ordering::SeqCst);
+        System.dealloc(ptr, layout)
+    }
+}
+
+#[test]
+fn memory_copy_does_not_leak() {
+    let alloc = CountingAlloc::default();
+    let live = alloc.live.clone();
+
+    let mut init = Init::new();
+    init.type_r = RendererType::Noop;
+    init.set_allocator(Box::new(GlobalAllocAdapter::new(alloc)));
+    let mut ctx = Bgfx::init(&init).unwrap();
+    assert!(live.load(Ordering::SeqCst) > 0);
+
+    let indices: Vec<u16> = (0..1024).collect();
+    for _ in 0..16 {
+        let mem = Memory::copy(&indices);
+        let buffer = ctx.create_index_buffer(&mem, BufferFlags::NONE);
+        drop(buffer);
+        ctx.frame(false);
+    }
+
+    drop(ctx);
+    assert_eq!(live.load(Ordering::SeqCst), 0);
+}
@@ -0,0 +1,52 @@
+#![cfg(not(feature = "shared-api"))]
+
+use bgfx::{Bgfx, Init, InitError, Memory, RendererType, Texture, TextureFlags, TextureFormat};
+use bgfx_rs::bgfx;
+use std::panic::catch_unwind;
+
+fn create_texture() -> Texture {
+    Texture::create_texture_2d(
+        4,
+        4,
+        false,
+        1,
+        TextureFormat::RGBA8,
+        TextureFlags::NONE,
+        &Memory::new(),
+    )
+}
+
+#[test]
+fn one_session_at_a_time() {
+    // bgfx crashes when creating resources before init.
+    assert!(catch_unwind(create_texture).is_err());
+
+    let mut init = Init::new();
+    init.type_r = RendererType::Noop;
+    let ctx = Bgfx::init(&init).unwrap();
+    assert_eq!(Bgfx::init(&init).err(), Some(InitError::AlreadyInitialized));
+    #[allow(deprecated)]
+    let initialized = bgfx::init(&init);
+    assert!(!initialized);
+
+    let texture = ctx
+        .try_create_texture_2d(
+            4,
+            4,
+            false,
+            1,
+            TextureFormat::RGBA8,
+            TextureFlags::NONE,
+            &Memory::new(),
+        )
+        .unwrap();
+    drop(texture);
+    drop(ctx);
+    assert!(!Bgfx::is_initialized());
+    assert!(catch_unwind(create_texture).is_err());
+
+    // The context is released on shutdown, so bgfx can be initialized again.
+    let ctx = Bgfx::init(&init).unwrap();
+    assert!(create_texture().is_valid());
+    drop(ctx);
+}
@@ -0,0 +1,112 @@
+#![cfg(all(feature = "derive", not(feature = "shared-api")))]
+
+use bgfx::{
+    AddArgs, Attrib, AttribType, BuiltVertexLayout, Color, RendererType, Vertex,
+    VertexLayoutBuilder,
+};
+use bgfx_rs::bgfx;
+
+#[derive(Clone, Copy, Vertex)]
+#[repr(C)]
+struct PosColorVertex {
+    #[attrib(Position)]
+    pos: [f32; 3],
+    #[attrib(Color0, normalized)]
+    color: Color,
+}
+
+#[derive(Clone, Copy, Vertex)]
+#[repr(C, packed)]
+struct MixedVertex {
+    #[attrib(Position)]
+    pos: [f32; 3],
+    #[attrib(TexCoord0, ty = Half, num = 2)]
+    uv: [u16; 2],
+    // Skipped as padding.
+    _reserved: u32,
+    #[attrib(Normal, as_int)]
+    normal: [i16; 4],
+    #[attrib(Color1, normalized)]
+    abgr: u32,
+}
+
+const ATTRIBS: [Attrib; 8] = [
+    Attrib::Position,
+    Attrib::Normal,
+    Attrib::Tangent,
+    Attrib::Color0,
+    Attrib::Color1,
+    Attrib::Indices,
+    Attrib::TexCoord0,
+    Attrib::TexCoord1,
+];
+
+fn args(normalized: bool, as_int: bool) -> AddArgs {
+    AddArgs { normalized, as_int }
+}
+
+fn assert_same_layout(derived: &BuiltVertexLayout, expected: &BuiltVertexLayout) {
+    assert_eq!(derived.stride(), expected.stride());
+    for &attrib in &ATTRIBS {
+        assert_eq!(
+            derived.offset(attrib),
+            expected.offset(attrib),
+            "offset of {:?}",
+            attrib
+        );
+    }
+}
+
+#[test]
+fn color_vertex() {
+    let derived = PosColorVertex::layout(RendererType::Noop);
+    let expected = VertexLayoutBuilder::begin(RendererType::Noop)
+        .add(Attrib::Position, 3, AttribType::Float, args(false, false))
+        .add(Attrib::Color0, 4, AttribType::Uint8, args(true, false))
+        .end();
+
+    assert_same_layout(&derived, &expected);
+    assert_eq!(
+        derived.stride() as usize,
+        std::mem::size_of::<PosColorVertex>()
+    );
+    assert_eq!(derived.offset(Attrib::Color0), Some(12));
+
+    // The color is stored as ABGR, so it unpacks to RGBA.
+    let vertex = PosColorVertex {
+        pos: [0.0; 3],
+        color: Color::new(0xff, 0x00, 0x80, 0x40),
+    };
+    let bytes = unsafe {
+        std::slice::from_raw_parts(
+            &vertex as *const PosColorVertex as *const u8,
+            std::mem::size_of::<PosColorVertex>(),
+        )
+    };
+    let color = derived.unpack(Attrib::Color0, bytes, 0);
+    let expected = [1.0, 0.0, 128.0 / 255.0, 64.0 / 255.0];
+    for (c, e) in color.iter().zip(&expected) {
+        assert!((c - e).abs() < 1.0e-6, "{:?} != {:?}", color, expected);
+    }
+}
+
+#[test]
+fn mixed_vertex() {
+    let derived = MixedVertex::layout(RendererType::Noop);
+    let expected = VertexLayoutBuilder::begin(RendererType::Noop)
+        .add(Attrib::Position, 3, AttribType::Float, args(false, false))
+        .add(Attrib::TexCoord0, 2, AttribType::Half, args(false, false))
+        .skip(4)
+        .add(Attrib::Normal, 4, AttribType::Int16, args(false, true))
+        .add(Attrib::Color1, 4, AttribType::Uint8, args(true, false))
+        .end();
+
+    assert_same_layout(&derived, &expected);
+    assert_eq!(
+        derived.stride() as usize,
+        std::mem::size_of::<MixedVertex>()
+    );
+    assert_eq!(derived.offset(Attrib::TexCoord0), Some(12));
+    assert_eq!(derived.offset(Attrib::Normal), Some(20));
+    assert_eq!(derived.offset(Attrib::Color1), Some(28));
+}
@@ -0,0 +1,91 @@
+#![cfg(all(feature = "egui", not(feature = "shared-api")))]
+
+use bgfx::{Bgfx, EguiPainter, Init, Memory, RendererType, TextureFlags, TextureFormat};
+use bgfx_rs::bgfx;
+use egui::epaint::ImageDelta;
+use egui::{Color32, ColorImage, Pos2, Rect, TextureId, TextureOptions, TexturesDelta, Vec2};
+
+fn image(size: usize) -> ColorImage {
+    ColorImage::new([size, size], Color32::WHITE)
+}
+
+#[test]
+fn paint_with_noop_renderer() {
+    let mut init = Init::new();
+    init.type_r = RendererType::Noop;
+    let mut ctx = Bgfx::init(&init).unwrap();
+
+    let egui = egui::Context::default();
+    let mut painter = EguiPainter::new(1).unwrap();
+    let user_texture = ctx.create_texture_2d(
+        2,
+        2,
+        false,
+        1,
+        TextureFormat::RGBA8,
+        TextureFlags::NONE,
+        &Memory::new(),
+    );
+    let user = painter.register_user_texture(user_texture);
+
+    let input = egui::RawInput {
+        screen_rect: Some(Rect::from_min_size(Pos2::ZERO, Vec2::new(320.0, 240.0))),
+        ..Default::default()
+    };
+    let output = egui.run(input, |ctx| {
+        egui::CentralPanel::default().show(ctx, |ui| ui.label("Hello"));
+    });
+    let primitives = egui.tessellate(output.shapes);
+    assert!(!primitives.is_empty());
+
+    // The font atlas comes with the first frame.
+    let mut delta = output.textures_delta;
+    assert!(!delta.set.is_empty());
+    let font = delta.set[0].0;
+    let textured = TextureId::Managed(100);
+    let freed = TextureId::Managed(101);
+    delta
+        .set
+        .push((textured, ImageDelta::full(image(4), TextureOptions::LINEAR)));
+    delta
+        .set
+        .push((freed, ImageDelta::full(image(2), TextureOptions::NEAREST)));
+
+    let encoder = ctx.encoder().unwrap();
+    painter.paint(&encoder, &primitives, &delta, [320, 240], 1.0);
+    drop(encoder);
+    ctx.frame(false);
+
+    assert!(painter.texture(font).is_some());
+    assert!(painter.texture(textured).is_some());
+    assert!(painter.texture(freed).is_some());
+    assert!(painter.texture(user).is_some());
+
+    // Partial updates only change existing textures.
+    let missing = TextureId::Managed(102);
+    let delta = TexturesDelta {
+        set: vec![
+            (
+                textured,
+                ImageDelta::partial([1, 1], image(2), TextureOptions::LINEAR),
+            ),
+            (
+                missing,
+                ImageDelta::partial([0, 0], image(2), TextureOptions::LINEAR),
+            ),
+        ],
+        free: vec![freed],
+    };
+    let encoder = ctx.encoder().unwrap();
+    painter.paint(&encoder, &primitives, &delta, [320, 240], 1.0);
+    drop(encoder);
+    ctx.frame(false);
+
+    assert!(painter.texture(font).is_some());
+    assert!(painter.texture(textured).is_some());
+    assert!(painter.texture(freed).is_none());
+    assert!(painter.texture(missing).is_none());
+    assert!(painter.texture(user).is_some());
+
+    drop(painter);
+}
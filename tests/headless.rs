@@ -0,0 +1,48 @@
+#![cfg(not(feature = "shared-api"))]
+
+use bgfx::{ClearFlags, Color, Headless, RendererType, SetViewClearArgs, TextureFormat};
+use bgfx_rs::bgfx;
+
+#[test]
+fn texture_formats_match_bgfx() {
+    assert_eq!(
+        TextureFormat::ASTC12x12 as u32,
+        bgfx_sys::BGFX_TEXTURE_FORMAT_ASTC12X12
+    );
+    assert_eq!(
+        TextureFormat::RGBA8 as u32,
+        bgfx_sys::BGFX_TEXTURE_FORMAT_RGBA8
+    );
+    assert_eq!(
+        TextureFormat::D24S8 as u32,
+        bgfx_sys::BGFX_TEXTURE_FORMAT_D24S8
+    );
+    assert_eq!(
+        TextureFormat::Count as u32,
+        bgfx_sys::BGFX_TEXTURE_FORMAT_COUNT
+    );
+}
+
+#[test]
+fn read_rgba() {
+    let mut headless = Headless::init(32, 32).unwrap();
+    headless.set_view(0);
+    bgfx::set_view_clear(
+        0,
+        ClearFlags::COLOR | ClearFlags::DEPTH,
+        SetViewClearArgs {
+            rgba: Color::RED,
+            ..Default::default()
+        },
+    );
+    bgfx::touch(0);
+
+    // Containers without a GPU fall back to Noop, which reads back a blank image.
+    let image = headless.read_rgba().unwrap();
+    assert_eq!((image.width, image.height), (32, 32));
+    assert_eq!(image.data.len(), 32 * 32 * 4);
+    if headless.renderer_type() != RendererType::Noop {
+        assert_eq!(image.pixel(0, 0), [0xff, 0, 0, 0xff]);
+        assert_eq!(image.pixel(31, 31), [0xff, 0, 0, 0xff]);
+    }
+}
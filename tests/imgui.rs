@@ -0,0 +1,32 @@
+#![cfg(all(feature = "imgui", not(feature = "shared-api")))]
+
+use bgfx::{Bgfx, ImguiRenderer, Init, RendererType};
+use bgfx_rs::bgfx;
+
+#[test]
+fn render_with_noop_renderer() {
+    let mut init = Init::new();
+    init.type_r = RendererType::Noop;
+    let mut ctx = Bgfx::init(&init).unwrap();
+
+    let mut imgui = imgui::Context::create();
+    imgui.set_ini_filename(None);
+    imgui.io_mut().display_size = [320.0, 240.0];
+    let mut renderer = ImguiRenderer::new(&mut imgui, 1).unwrap();
+    let font_texture = imgui.fonts().tex_id;
+    assert!(renderer.textures().get(font_texture).is_some());
+
+    for i in 0..3 {
+        let ui = imgui.new_frame();
+        ui.window("Test").build(|| ui.text("Hello"));
+        let draw_data = imgui.render();
+        // New windows are hidden on their first frame while they're sized.
+        assert_eq!(draw_data.total_idx_count > 0, i > 0);
+        let encoder = ctx.encoder().unwrap();
+        renderer.render(&encoder, draw_data);
+        drop(encoder);
+        ctx.frame(false);
+    }
+
+    drop(renderer);
+}
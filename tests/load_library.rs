@@ -0,0 +1,34 @@
+#![cfg(feature = "shared-api")]
+
+use bgfx::LoadError;
+use bgfx_rs::bgfx;
+use std::mem::MaybeUninit;
+
+const MISSING: &str = "missing/libbgfx-shared-lib.so";
+
+#[test]
+fn load_failures() {
+    let err = unsafe { bgfx::load_library(MISSING) }.unwrap_err();
+    assert!(matches!(err, LoadError::Library(_)), "{}", err);
+    assert!(bgfx::get_interface().is_null());
+
+    // Initialize bgfx the way a host application would, and share it with this module.
+    let face = unsafe {
+        let mut init = MaybeUninit::<bgfx_sys::bgfx_init_t>::uninit();
+        bgfx_sys::bgfx_init_ctor(init.as_mut_ptr());
+        let mut init = init.assume_init();
+        init.type_ = bgfx_sys::BGFX_RENDERER_TYPE_NOOP;
+        assert!(bgfx_sys::bgfx_init(&init));
+        let face = bgfx_sys::bgfx_get_interface(bgfx_sys::BGFX_API_VERSION);
+        bgfx::set_interface(face);
+        face
+    };
+
+    // The interface of the initialized bgfx is kept.
+    let err = unsafe { bgfx::load_library(MISSING) }.unwrap_err();
+    assert!(matches!(err, LoadError::AlreadyInitialized), "{}", err);
+    assert_eq!(bgfx::get_interface(), face as *const _);
+
+    unsafe { bgfx::set_interface(std::ptr::null()) };
+    unsafe { bgfx_sys::bgfx_shutdown() };
+}
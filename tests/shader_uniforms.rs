@@ -0,0 +1,48 @@
+#![cfg(not(feature = "shared-api"))]
+
+use bgfx::{Bgfx, Init, RendererType, ShaderBundle, Uniform, UniformType};
+use bgfx_rs::bgfx;
+
+fn infos(uniforms: &[Uniform]) -> Vec<(String, UniformType, u16)> {
+    uniforms
+        .iter()
+        .map(|uniform| {
+            let info = uniform.info();
+            (info.name, info.type_r, info.num)
+        })
+        .collect()
+}
+
+#[test]
+fn uniforms_with_noop_renderer() {
+    let mut init = Init::new();
+    init.type_r = RendererType::Noop;
+    let mut ctx = Bgfx::init(&init).unwrap();
+
+    // bgfx parses the uniform table of shaders on the API side, even with Noop.
+    let shaders = ShaderBundle::from_dir("resources/examples/runtime/shaders");
+    let shader = shaders.create_shader("fs_bump").unwrap();
+    // Direct3D 11 shaders list samplers twice, once for the sampler and once for the texture.
+    let expected = vec![
+        ("u_lightPosRadius".to_owned(), UniformType::Vec4, 4),
+        ("u_lightRgbInnerR".to_owned(), UniformType::Vec4, 4),
+        ("s_texColor".to_owned(), UniformType::Sampler, 1),
+        ("s_texNormal".to_owned(), UniformType::Sampler, 1),
+        ("s_texColor".to_owned(), UniformType::Sampler, 1),
+        ("s_texNormal".to_owned(), UniformType::Sampler, 1),
+    ];
+    let uniforms = shader.uniforms();
+    assert_eq!(infos(&uniforms), expected);
+    ctx.frame(false);
+    assert_eq!(ctx.stats().num_uniforms, 4);
+
+    // The returned uniforms hold their own reference, so the shader keeps its uniforms.
+    drop(uniforms);
+    ctx.frame(false);
+    assert_eq!(ctx.stats().num_uniforms, 4);
+    assert_eq!(infos(&shader.uniforms()), expected);
+
+    drop(shader);
+    ctx.frame(false);
+    assert_eq!(ctx.stats().num_uniforms, 0);
+}
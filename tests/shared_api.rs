@@ -0,0 +1,61 @@
+#![cfg(feature = "shared-api")]
+
+use bgfx::{Bgfx, InitError, Memory, TextureFlags, TextureFormat};
+use bgfx_rs::bgfx;
+use std::mem::MaybeUninit;
+
+/// Initializes bgfx the way a host application would, without going through this crate.
+fn host_init() {
+    unsafe {
+        let mut init = MaybeUninit::<bgfx_sys::bgfx_init_t>::uninit();
+        bgfx_sys::bgfx_init_ctor(init.as_mut_ptr());
+        let mut init = init.assume_init();
+        init.type_ = bgfx_sys::BGFX_RENDERER_TYPE_NOOP;
+        assert!(bgfx_sys::bgfx_init(&init));
+    }
+}
+
+fn host_frame() {
+    let frame = unsafe { bgfx_sys::bgfx_frame(false) };
+    bgfx::set_host_frame(frame);
+}
+
+#[test]
+fn plugin_adopts_host_session() {
+    host_init();
+    unsafe { bgfx::set_interface(bgfx_sys::bgfx_get_interface(bgfx_sys::BGFX_API_VERSION)) };
+    assert_eq!(
+        Bgfx::init(&bgfx::Init::new()).err(),
+        Some(InitError::AlreadyInitialized)
+    );
+
+    host_frame();
+    let textures = bgfx::get_stats().num_textures;
+    let texture = bgfx::create_texture_2d(
+        4,
+        4,
+        false,
+        1,
+        TextureFormat::RGBA8,
+        TextureFlags::BLIT_DST | TextureFlags::READ_BACK,
+        &Memory::new(),
+    );
+    host_frame();
+    assert_eq!(bgfx::get_stats().num_textures, textures + 1);
+
+    let read = texture.read(0).unwrap();
+    assert!(!read.is_ready());
+    while unsafe { bgfx_sys::bgfx_frame(false) } < read.frame() {
+        assert!(!read.is_ready());
+    }
+    bgfx::set_host_frame(read.frame());
+    assert!(read.is_ready());
+    assert!(read.data().is_some());
+
+    drop(texture);
+    host_frame();
+    assert_eq!(bgfx::get_stats().num_textures, textures);
+
+    unsafe { bgfx::set_interface(std::ptr::null()) };
+    unsafe { bgfx_sys::bgfx_shutdown() };
+}
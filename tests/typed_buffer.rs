@@ -0,0 +1,58 @@
+#![cfg(not(feature = "shared-api"))]
+
+use bgfx::{
+    AddArgs, Attrib, AttribType, Bgfx, BufferFlags, BuiltVertexLayout, DiscardFlags, Init, Pod,
+    RendererType, TypedIndexBuffer, TypedVertexBuffer, Vertex, VertexLayoutBuilder,
+};
+use bgfx_rs::bgfx;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+#[derive(Clone, Copy)]
+#[repr(C)]
+struct PosVertex {
+    pos: [f32; 3],
+}
+
+unsafe impl Pod for PosVertex {}
+
+impl Vertex for PosVertex {
+    fn layout(renderer_type: RendererType) -> BuiltVertexLayout {
+        VertexLayoutBuilder::begin(renderer_type)
+            .add(Attrib::Position, 3, AttribType::Float, AddArgs::default())
+            .end()
+    }
+}
+
+#[test]
+fn set_typed_buffers_with_noop_renderer() {
+    let mut init = Init::new();
+    init.type_r = RendererType::Noop;
+    let mut ctx = Bgfx::init(&init).unwrap();
+
+    let vertices = [PosVertex { pos: [0.0; 3] }; 4];
+    let vertex_buffer = TypedVertexBuffer::create(&vertices, BufferFlags::NONE);
+    assert!(vertex_buffer.is_valid());
+    assert_eq!(vertex_buffer.len(), 4);
+
+    let index_buffer = TypedIndexBuffer::<u16>::create(&[0, 1, 2, 2, 1, 3], BufferFlags::NONE);
+    assert!(index_buffer.is_valid());
+    assert_eq!(index_buffer.len(), 6);
+
+    let encoder = ctx.encoder().unwrap();
+    encoder.set_typed_vertex_buffer(0, &vertex_buffer, ..);
+    encoder.set_typed_vertex_buffer(0, &vertex_buffer, 1..=3);
+    encoder.set_typed_index_buffer(&index_buffer, 3..);
+
+    let past_end = catch_unwind(AssertUnwindSafe(|| {
+        encoder.set_typed_vertex_buffer(0, &vertex_buffer, 2..5)
+    }));
+    assert!(past_end.is_err());
+    let past_end = catch_unwind(AssertUnwindSafe(|| {
+        encoder.set_typed_index_buffer(&index_buffer, ..7)
+    }));
+    assert!(past_end.is_err());
+
+    encoder.discard(DiscardFlags::ALL);
+    drop(encoder);
+    ctx.frame(false);
+}
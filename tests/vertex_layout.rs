@@ -0,0 +1,249 @@
+#![cfg(not(feature = "shared-api"))]
+
+use bgfx::{AddArgs, Attrib, AttribType, BuiltVertexLayout, RendererType, VertexLayoutBuilder};
+use bgfx_rs::bgfx;
+
+/// `normalized` and `as_int` of [AddArgs].
+type Args = (bool, bool);
+
+const NONE: Args = (false, false);
+const NORMALIZED: Args = (true, false);
+const NORMALIZED_INT: Args = (true, true);
+
+fn layout(attribs: &[(Attrib, u8, AttribType, Args)]) -> BuiltVertexLayout {
+    let mut builder = VertexLayoutBuilder::begin(RendererType::Noop);
+    for &(attrib, num, type_r, (normalized, as_int)) in attribs {
+        builder = builder.add(attrib, num, type_r, AddArgs { normalized, as_int });
+    }
+    builder.end()
+}
+
+fn assert_near(actual: [f32; 4], expected: [f32; 4], epsilon: f32) {
+    for (a, e) in actual.iter().zip(&expected) {
+        assert!(
+            (a - e).abs() <= epsilon,
+            "{:?} != {:?} (epsilon {})",
+            actual,
+            expected,
+            epsilon
+        );
+    }
+}
+
+/// Packs `input` into the second of two vertices and checks that it unpacks to `output`, and
+/// that the first vertex is left alone.
+fn round_trip(type_r: AttribType, args: Args, input: [f32; 4], output: [f32; 4], epsilon: f32) {
+    let layout = layout(&[
+        (Attrib::Position, 3, AttribType::Float, NONE),
+        (Attrib::TexCoord0, 4, type_r, args),
+    ]);
+    let mut data = vec![0u8; 2 * layout.stride() as usize];
+    let position = [1.0, 2.0, 3.0, 0.0];
+    layout.pack(Attrib::Position, &position, false, &mut data, 0);
+    let first = data[..layout.stride() as usize].to_vec();
+    layout.pack(Attrib::TexCoord0, &input, args.0, &mut data, 1);
+
+    assert_near(layout.unpack(Attrib::TexCoord0, &data, 1), output, epsilon);
+    assert_eq!(data[..layout.stride() as usize], first[..]);
+    assert_eq!(layout.unpack(Attrib::Position, &data, 0), position);
+}
+
+#[test]
+fn pack_unpack_uint8() {
+    let normalized = [0.0, 0.25, 0.5, 1.0];
+    round_trip(
+        AttribType::Uint8,
+        NORMALIZED,
+        normalized,
+        normalized,
+        1.0 / 255.0,
+    );
+    let signed = [-1.0, -0.5, 0.5, 1.0];
+    round_trip(
+        AttribType::Uint8,
+        NORMALIZED_INT,
+        signed,
+        signed,
+        1.0 / 127.0,
+    );
+    // Unnormalized values are packed as they are, but always unpack to 0.0-1.0.
+    let values = [0.0, 3.0, 128.0, 255.0];
+    round_trip(
+        AttribType::Uint8,
+        NONE,
+        values,
+        values.map(|v| v / 255.0),
+        0.0,
+    );
+}
+
+#[test]
+fn pack_unpack_int16() {
+    let signed = [-1.0, -0.5, 0.25, 1.0];
+    round_trip(
+        AttribType::Int16,
+        NORMALIZED_INT,
+        signed,
+        signed,
+        1.0 / 32767.0,
+    );
+    // Without `as_int` the signed range is unpacked to 0.0-1.0.
+    let values = [-32768.0, -300.0, 0.0, 32767.0];
+    let unpacked = values.map(|v| (v + 32768.0) / 65535.0);
+    round_trip(AttribType::Int16, NONE, values, unpacked, 1.0e-6);
+}
+
+#[test]
+fn pack_unpack_half() {
+    let values = [-2.0, 0.5, 0.1, 1024.0];
+    round_trip(AttribType::Half, NONE, values, values, 1.0e-3);
+}
+
+#[test]
+fn pack_unpack_float() {
+    let values = [-1.5e6, 0.1, 3.25, 1.0e-7];
+    round_trip(AttribType::Float, NONE, values, values, 0.0);
+}
+
+#[test]
+fn unpack_missing_attrib() {
+    let layout = layout(&[(Attrib::Position, 3, AttribType::Float, NONE)]);
+    let mut data = vec![0u8; layout.stride() as usize];
+    layout.pack(Attrib::Color0, &[1.0; 4], true, &mut data, 0);
+    assert_eq!(data, vec![0u8; layout.stride() as usize]);
+    assert_eq!(layout.unpack(Attrib::Color0, &data, 0), [0.0; 4]);
+}
+
+#[test]
+#[should_panic(expected = "vertex index 1 out of range")]
+fn pack_out_of_range() {
+    let layout = layout(&[(Attrib::Position, 3, AttribType::Float, NONE)]);
+    let mut data = vec![0u8; layout.stride() as usize];
+    layout.pack(Attrib::Position, &[0.0; 4], false, &mut data, 1);
+}
+
+#[test]
+fn convert() {
+    let src = layout(&[
+        (Attrib::Position, 3, AttribType::Float, NONE),
+        (Attrib::Color0, 4, AttribType::Uint8, NORMALIZED),
+    ]);
+    let dst = layout(&[
+        (Attrib::Color0, 4, AttribType::Float, NONE),
+        (Attrib::TexCoord0, 2, AttribType::Float, NONE),
+        (Attrib::Position, 3, AttribType::Half, NONE),
+    ]);
+
+    let mut data = vec![0u8; 3 * src.stride() as usize];
+    for i in 0..3 {
+        let f = i as f32;
+        src.pack(Attrib::Position, &[f, -f, 0.5, 0.0], false, &mut data, i);
+        src.pack(
+            Attrib::Color0,
+            &[1.0, 0.0, 0.2 * f, 1.0],
+            true,
+            &mut data,
+            i,
+        );
+    }
+
+    let converted = dst.convert(&src, &data);
+    assert_eq!(converted.len(), 3 * dst.stride() as usize);
+    assert_eq!(dst.num_vertices(&converted), 3);
+    for i in 0..3 {
+        let f = i as f32;
+        assert_near(
+            dst.unpack(Attrib::Position, &converted, i),
+            [f, -f, 0.5, 0.0],
+            0.0,
+        );
+        assert_near(
+            dst.unpack(Attrib::Color0, &converted, i),
+            [1.0, 0.0, 0.2 * f, 1.0],
+            1.0 / 255.0,
+        );
+        assert_eq!(dst.unpack(Attrib::TexCoord0, &converted, i), [0.0; 4]);
+    }
+}
+
+#[test]
+fn weld() {
+    let layout = layout(&[
+        (Attrib::Position, 3, AttribType::Float, NONE),
+        (Attrib::Color0, 4, AttribType::Uint8, NORMALIZED),
+    ]);
+    let positions = [
+        [0.0, 0.0, 0.0, 0.0],
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0005, 0.0, -0.0005, 0.0],
+        [1.0, 0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0, 0.0],
+    ];
+    let mut data = vec![0u8; positions.len() * layout.stride() as usize];
+    for (i, position) in positions.iter().enumerate() {
+        layout.pack(Attrib::Position, position, false, &mut data, i as u32);
+        // Only the position is compared, so differing colors don't prevent welding.
+        layout.pack(
+            Attrib::Color0,
+            &[i as f32 / 4.0; 4],
+            true,
+            &mut data,
+            i as u32,
+        );
+    }
+
+    assert_eq!(layout.weld(&data, 0.001), vec![0, 1, 0, 1, 4]);
+    assert_eq!(layout.weld(&data, 0.0), vec![0, 1, 2, 1, 4]);
+    assert_eq!(layout.weld(&[], 0.001), Vec::<u32>::new());
+}
+
+#[test]
+#[should_panic(expected = "no position to weld")]
+fn weld_without_position() {
+    let layout = layout(&[(Attrib::Color0, 4, AttribType::Uint8, NORMALIZED)]);
+    let data = vec![0u8; 2 * layout.stride() as usize];
+    layout.weld(&data, 0.001);
+}
+
+#[test]
+fn attrib_sizes_match_renderers() {
+    // Direct3D 9 pads small attributes to 4 bytes, so derived vertices aren't checked against it.
+    let renderers = [
+        RendererType::Agc,
+        RendererType::Direct3D11,
+        RendererType::Direct3D12,
+        RendererType::Gnm,
+        RendererType::Metal,
+        RendererType::Nvn,
+        RendererType::OpenGLES,
+        RendererType::OpenGL,
+        RendererType::Vulkan,
+        RendererType::WebGPU,
+    ];
+    let types = [
+        AttribType::Uint8,
+        AttribType::Uint10,
+        AttribType::Int16,
+        AttribType::Half,
+        AttribType::Float,
+    ];
+    for &type_r in &types {
+        for num in 1..=4 {
+            let strides: Vec<_> = renderers
+                .iter()
+                .map(|&renderer| {
+                    VertexLayoutBuilder::begin(renderer)
+                        .add(Attrib::Position, num, type_r, AddArgs::default())
+                        .end()
+                        .stride() as usize
+                })
+                .collect();
+            if bgfx::vertex_attrib_size_varies(type_r, num) {
+                assert!(strides.iter().any(|&s| s != strides[0]));
+            } else {
+                let size = bgfx::vertex_attrib_size(type_r, num);
+                assert!(strides.iter().all(|&s| s == size), "{:?} {}", type_r, num);
+            }
+        }
+    }
+}
@@ -17,10 +17,18 @@ struct PosColorVertex {
 
 static CANVAS_ID: &[u8; 7] = b"canvas\0";
 
-static VS_CUBES: &[u8] =
-    include_bytes!("../../resources/examples/runtime/shaders/essl/vs_cubes.bin");
-static FS_CUBES: &[u8] =
-    include_bytes!("../../resources/examples/runtime/shaders/essl/fs_cubes.bin");
+static SHADERS: &[EmbeddedShader] = &[
+    EmbeddedShader {
+        renderer: RendererType::OpenGLES,
+        name: "vs_cubes",
+        data: include_bytes!("../../resources/examples/runtime/shaders/essl/vs_cubes.bin"),
+    },
+    EmbeddedShader {
+        renderer: RendererType::OpenGLES,
+        name: "fs_cubes",
+        data: include_bytes!("../../resources/examples/runtime/shaders/essl/fs_cubes.bin"),
+    },
+];
 
 #[rustfmt::skip]
 static CUBE_VERTICES: [PosColorVertex; 8] = [
@@ -64,17 +72,8 @@ fn get_platform_data(_window: &Window) -> PlatformData {
     pd
 }
 
-fn create_shader_program() -> std::io::Result<Program> {
-    let vs_data = Memory::copy(&VS_CUBES);
-    let ps_data = Memory::copy(&FS_CUBES);
-
-    let vs_shader = bgfx::create_shader(&vs_data);
-    let ps_shader = bgfx::create_shader(&ps_data);
-
-    Ok(bgfx::create_program(&vs_shader, &ps_shader, false))
-}
-
 struct State {
+    ctx: Bgfx,
     vbh: VertexBuffer,
     ibh: IndexBuffer,
     shader_program: Program,
@@ -104,10 +103,10 @@ fn main_callback(state: &mut State) {
     let persp = Mat4::perspective_lh(60.0 * (std::f32::consts::PI / 180.0), aspect, 0.1, 100.0);
     let view = Mat4::look_at_lh(eye, at, up);
 
-    bgfx::set_view_rect(0, 0, 0, WIDTH as _, HEIGHT as _);
-    bgfx::touch(0);
+    state.ctx.set_view_rect(0, 0, 0, WIDTH as _, HEIGHT as _);
+    state.ctx.touch(0);
 
-    bgfx::set_view_transform(0, &view.to_cols_array(), &persp.to_cols_array());
+    state.ctx.set_view_transform(0, &view.to_cols_array(), &persp.to_cols_array());
 
     for yy in 0..11 {
         for xx in 0..11 {
@@ -119,19 +118,19 @@ fn main_callback(state: &mut State) {
             let rot = Mat4::from_euler(EulerRot::XYZ, xr, yr, 0.0);
             let transform = Mat4::from_translation(Vec3::new(x, y, 0.0)) * rot;
 
-            bgfx::set_transform(&transform.to_cols_array(), 1);
-            bgfx::set_vertex_buffer(0, &state.vbh, 0, std::u32::MAX);
-            bgfx::set_index_buffer(&state.ibh, 0, std::u32::MAX);
+            state.ctx.set_transform(&transform.to_cols_array(), 1);
+            state.ctx.set_vertex_buffer(0, &state.vbh, 0, std::u32::MAX);
+            state.ctx.set_index_buffer(&state.ibh, 0, std::u32::MAX);
 
-            bgfx::set_state(render_state, 0);
-            bgfx::submit(0, &state.shader_program, SubmitArgs::default());
+            state.ctx.set_state(render_state, 0);
+            state.ctx.submit(0, &state.shader_program, SubmitArgs::default());
         }
     }
 
-    bgfx::frame(false);
+    state.ctx.frame(false);
 }
 
-fn main() -> std::io::Result<()> {
+fn main() -> Result<(), Box<dyn std::error::Error>> {
     let mut glfw = glfw::init(glfw::FAIL_ON_ERRORS).unwrap();
     glfw.window_hint(glfw::WindowHint::ClientApi(glfw::ClientApiHint::NoApi));
 
@@ -151,19 +150,17 @@ fn main() -> std::io::Result<()> {
     init.type_r = RendererType::OpenGLES;
     init.resolution.width = WIDTH as u32;
     init.resolution.height = HEIGHT as u32;
-    init.resolution.reset = ResetFlags::VSYNC.bits();
+    init.resolution.reset = ResetFlags::VSYNC;
     init.platform_data = get_platform_data(&window);
 
-    if !bgfx::init(&init) {
-        panic!("failed to init bgfx");
-    }
+    let ctx = Bgfx::init(&init).expect("failed to init bgfx");
 
-    bgfx::set_debug(DebugFlags::TEXT.bits());
-    bgfx::set_view_clear(
+    ctx.set_debug(DebugFlags::TEXT);
+    ctx.set_view_clear(
         0,
-        ClearFlags::COLOR.bits() | ClearFlags::DEPTH.bits(),
+        ClearFlags::COLOR | ClearFlags::DEPTH,
         SetViewClearArgs {
-            rgba: 0x103030ff,
+            rgba: Color::rgb(0x10, 0x30, 0x30),
             ..Default::default()
         },
     );
@@ -184,15 +181,16 @@ fn main() -> std::io::Result<()> {
     let verts_mem = unsafe { Memory::reference(&CUBE_VERTICES) };
     let index_mem = unsafe { Memory::reference(&CUBE_INDICES) };
 
-    let vbh = bgfx::create_vertex_buffer(&verts_mem, &layout, BufferFlags::NONE.bits());
-    let ibh = bgfx::create_index_buffer(&index_mem, BufferFlags::NONE.bits());
+    let vbh = ctx.create_vertex_buffer(&verts_mem, &layout, BufferFlags::NONE);
+    let ibh = ctx.create_index_buffer(&index_mem, BufferFlags::NONE);
 
-    let shader_program = create_shader_program()?;
+    let shader_program = ShaderBundle::embedded(SHADERS).create_program("vs_cubes", "fs_cubes")?;
 
-    bgfx::reset(WIDTH as _, HEIGHT as _, ResetArgs::default());
-    bgfx::set_view_rect(0, 0, 0, WIDTH as _, HEIGHT as _);
+    ctx.reset(WIDTH as _, HEIGHT as _, ResetArgs::default());
+    ctx.set_view_rect(0, 0, 0, WIDTH as _, HEIGHT as _);
 
     let state = Box::new(State {
+        ctx,
         vbh,
         ibh,
         shader_program,